@@ -87,6 +87,10 @@ impl EpochState {
         &self.protocol_config
     }
 
+    pub fn executor(&self) -> &Arc<dyn Executor + Send + Sync> {
+        &self.executor
+    }
+
     pub fn execute_transaction(
         &self,
         store: &dyn SimulatorStore,
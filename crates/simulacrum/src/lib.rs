@@ -18,6 +18,7 @@ use anyhow::{anyhow, Result};
 use fastcrypto::traits::Signer;
 use rand::rngs::OsRng;
 use sui_config::{genesis, transaction_deny_config::TransactionDenyConfig};
+use sui_json_rpc_types::SuiMoveValue;
 use sui_protocol_config::ProtocolVersion;
 use sui_storage::blob::{Blob, BlobEncoding};
 use sui_swarm_config::genesis_config::AccountConfig;
@@ -26,10 +27,12 @@ use sui_swarm_config::network_config_builder::ConfigBuilder;
 use sui_types::base_types::{AuthorityName, ObjectID, VersionNumber};
 use sui_types::crypto::AuthoritySignature;
 use sui_types::digests::ConsensusCommitDigest;
+use sui_types::dynamic_field::{DynamicFieldInfo, DynamicFieldName, DynamicFieldType};
 use sui_types::object::Object;
 use sui_types::storage::{ObjectStore, ReadStore, RestStateReader};
 use sui_types::sui_system_state::epoch_start_sui_system_state::EpochStartSystemState;
 use sui_types::transaction::EndOfEpochTransactionKind;
+use sui_types::type_resolver::{into_struct_layout, LayoutResolver};
 use sui_types::{
     base_types::SuiAddress,
     committee::Committee,
@@ -576,6 +579,108 @@ impl<T: Send + Sync, V: store::SimulatorStore + Send + Sync> RestStateReader for
             .to_owned()
             .into())
     }
+
+    fn get_dynamic_fields(
+        &self,
+        parent: ObjectID,
+        cursor: Option<ObjectID>,
+        limit: usize,
+    ) -> sui_types::storage::error::Result<Vec<(ObjectID, DynamicFieldInfo)>> {
+        let mut resolver = self
+            .epoch_state
+            .executor()
+            .type_layout_resolver(Box::new(&self.store));
+
+        dynamic_fields_page(
+            &self.store,
+            self.store().dynamic_fields(parent),
+            cursor,
+            limit,
+            resolver.as_mut(),
+        )
+    }
+}
+
+/// Page through `fields`, the dynamic fields of some parent object as returned by
+/// [`SimulatorStore::dynamic_fields`], ordered by field object id like a fullnode's index.
+///
+/// If `cursor` is provided the page starts with the field immediately after it.
+pub fn dynamic_fields_page(
+    store: &dyn ObjectStore,
+    fields: impl Iterator<Item = Object>,
+    cursor: Option<ObjectID>,
+    limit: usize,
+    resolver: &mut dyn LayoutResolver,
+) -> sui_types::storage::error::Result<Vec<(ObjectID, DynamicFieldInfo)>> {
+    let mut fields = fields
+        .filter(|field| cursor.map_or(true, |cursor| field.id() > cursor))
+        .collect::<Vec<_>>();
+    fields.sort_by_key(|field| field.id());
+    fields.truncate(limit);
+
+    fields
+        .iter()
+        .map(|field| {
+            let info = dynamic_field_info(store, field, resolver)
+                .map_err(sui_types::storage::error::Error::custom)?;
+            Ok((field.id(), info))
+        })
+        .collect()
+}
+
+/// Describe the dynamic field `field`, mirroring how a fullnode's index describes it.
+fn dynamic_field_info(
+    store: &dyn ObjectStore,
+    field: &Object,
+    resolver: &mut dyn LayoutResolver,
+) -> Result<DynamicFieldInfo> {
+    let move_object = field
+        .data
+        .try_as_move()
+        .ok_or_else(|| anyhow!("dynamic field {} is not a move object", field.id()))?;
+
+    let layout =
+        into_struct_layout(resolver.get_annotated_layout(&move_object.type_().clone().into())?)?;
+    let move_struct = move_object.to_move_struct(&layout)?;
+
+    let (name_value, type_, object_id) = DynamicFieldInfo::parse_move_object(&move_struct)?;
+    let name_type = move_object.type_().try_extract_field_name(&type_)?;
+    let bcs_name = bcs::to_bytes(&name_value.clone().undecorate())?;
+    let name = DynamicFieldName {
+        type_: name_type,
+        value: SuiMoveValue::from(name_value).to_json_value(),
+    };
+
+    Ok(match type_ {
+        DynamicFieldType::DynamicObject => {
+            let object = store
+                .get_object_by_key(&object_id, field.version())?
+                .ok_or_else(|| {
+                    anyhow!(
+                        "object {object_id} of dynamic field {} not found",
+                        field.id()
+                    )
+                })?;
+            DynamicFieldInfo {
+                name,
+                bcs_name,
+                type_,
+                object_type: object.data.type_().unwrap().to_string(),
+                object_id,
+                version: object.version(),
+                digest: object.digest(),
+            }
+        }
+        DynamicFieldType::DynamicField => DynamicFieldInfo {
+            name,
+            bcs_name,
+            type_,
+            object_type: move_object.clone().into_type().into_type_params()[1].to_string(),
+            object_id: field.id(),
+            version: field.version(),
+            digest: field.digest(),
+        },
+    })
 }
 
 impl Simulacrum {
@@ -656,6 +761,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn dynamic_fields() {
+        let chain = Simulacrum::new();
+        let parent = sui_types::SUI_SYSTEM_STATE_OBJECT_ID;
+
+        // The system state object wraps its versioned inner state in a dynamic field
+        let fields = chain.get_dynamic_fields(parent, None, 10).unwrap();
+        assert_eq!(fields.len(), 1);
+        let (field_id, info) = &fields[0];
+        assert_eq!(*field_id, info.object_id);
+        assert_eq!(info.type_, DynamicFieldType::DynamicField);
+
+        // The cursor is exclusive, so paging on from the last field yields nothing
+        assert!(chain
+            .get_dynamic_fields(parent, Some(*field_id), 10)
+            .unwrap()
+            .is_empty());
+        assert!(chain
+            .get_dynamic_fields(parent, None, 0)
+            .unwrap()
+            .is_empty());
+    }
+
     #[test]
     fn simple() {
         let steps = 10;
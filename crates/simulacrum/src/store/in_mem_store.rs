@@ -134,6 +134,13 @@ impl InMemoryStore {
                 move |object| matches!(object.owner, Owner::AddressOwner(addr) if addr == owner),
             )
     }
+
+    pub fn dynamic_fields(&self, parent: ObjectID) -> impl Iterator<Item = &Object> {
+        self.live_objects
+            .iter()
+            .flat_map(|(id, version)| self.get_object_at_version(id, *version))
+            .filter(move |object| super::is_dynamic_field_of(object, parent))
+    }
 }
 
 impl InMemoryStore {
@@ -448,6 +455,10 @@ impl SimulatorStore for InMemoryStore {
         Box::new(self.owned_objects(owner).cloned())
     }
 
+    fn dynamic_fields(&self, parent: ObjectID) -> Box<dyn Iterator<Item = Object> + '_> {
+        Box::new(self.dynamic_fields(parent).cloned())
+    }
+
     fn insert_checkpoint(&mut self, checkpoint: VerifiedCheckpoint) {
         self.insert_checkpoint(checkpoint)
     }
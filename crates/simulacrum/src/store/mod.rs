@@ -92,6 +92,9 @@ pub trait SimulatorStore:
 
     fn owned_objects(&self, owner: SuiAddress) -> Box<dyn Iterator<Item = Object> + '_>;
 
+    /// The live dynamic field objects owned by the object `parent`, in no particular order.
+    fn dynamic_fields(&self, parent: ObjectID) -> Box<dyn Iterator<Item = Object> + '_>;
+
     fn insert_checkpoint(&mut self, checkpoint: VerifiedCheckpoint);
 
     fn insert_checkpoint_contents(&mut self, contents: CheckpointContents);
@@ -167,3 +170,13 @@ pub trait SimulatorStore:
         Ok((input_objects.into(), receiving_objects.into()))
     }
 }
+
+/// Whether `object` is a dynamic field of the object `parent`, as listed by
+/// [`SimulatorStore::dynamic_fields`].
+pub fn is_dynamic_field_of(object: &Object, parent: ObjectID) -> bool {
+    matches!(object.owner, sui_types::object::Owner::ObjectOwner(owner) if owner == parent.into())
+        && object
+            .data
+            .try_as_move()
+            .is_some_and(|object| object.type_().is_dynamic_field())
+}
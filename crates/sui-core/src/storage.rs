@@ -5,10 +5,12 @@ use parking_lot::Mutex;
 use std::sync::Arc;
 use sui_types::storage::ObjectStore;
 
+use sui_types::base_types::ObjectID;
 use sui_types::base_types::TransactionDigest;
 use sui_types::committee::Committee;
 use sui_types::committee::EpochId;
 use sui_types::digests::TransactionEventsDigest;
+use sui_types::dynamic_field::DynamicFieldInfo;
 use sui_types::effects::{TransactionEffects, TransactionEvents};
 use sui_types::error::SuiError;
 use sui_types::messages_checkpoint::CheckpointContentsDigest;
@@ -497,4 +499,20 @@ impl RestStateReader for RestReadStore {
             .get_chain_identifier()
             .ok_or_else(|| StorageError::missing("unable to query chain identifier"))
     }
+
+    fn get_dynamic_fields(
+        &self,
+        parent: ObjectID,
+        cursor: Option<ObjectID>,
+        limit: usize,
+    ) -> sui_types::storage::error::Result<Vec<(ObjectID, DynamicFieldInfo)>> {
+        self.state
+            .get_dynamic_fields(parent, cursor, limit)
+            .map_err(|e| match e {
+                // Nodes running without indexes can't list dynamic fields, which isn't a failure
+                // of the store itself.
+                SuiError::IndexStoreNotAvailable => StorageError::missing(e),
+                e => StorageError::custom(e),
+            })
+    }
 }
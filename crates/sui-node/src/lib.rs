@@ -1932,6 +1932,9 @@ pub async fn build_http_server(
         );
 
         rest_service.with_metrics(RestMetrics::new(prometheus_registry));
+        // `/system/self` only reports the node as a validator while its address is in the
+        // active set
+        rest_service.with_validator_address(config.sui_address().into());
 
         if let Some(transaction_orchestrator) = transaction_orchestrator {
             rest_service.with_executor(transaction_orchestrator.clone())
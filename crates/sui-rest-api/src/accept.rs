@@ -8,28 +8,68 @@ use mime::Mime;
 // include type information
 // "application/x.sui.<type>+bcs"
 pub const APPLICATION_BCS: &str = "application/bcs";
+pub const APPLICATION_CBOR: &str = "application/cbor";
+pub const APPLICATION_NDJSON: &str = "application/x-ndjson";
 
 /// `Accept` header, defined in [RFC7231](http://tools.ietf.org/html/rfc7231#section-5.3.2)
 #[derive(Debug, Clone)]
 pub struct Accept(pub Vec<Mime>);
 
+/// Parse the media ranges of all `Accept` headers, ordered by preference.
+///
+/// Malformed segments, like empty tokens or unparsable quality values, are ignored rather than
+/// rejecting the whole header, as are media ranges with a quality of 0, which mark them as not
+/// acceptable. Only the most preferred occurrence of duplicated media ranges is kept.
 fn parse_accept(headers: &HeaderMap) -> Vec<Mime> {
     let mut items = headers
         .get_all(header::ACCEPT)
         .iter()
         .filter_map(|hval| hval.to_str().ok())
         .flat_map(|s| s.split(',').map(str::trim))
+        .filter(|item| !item.is_empty())
         .filter_map(|item| {
             let mime: Mime = item.parse().ok()?;
-            let q = mime
-                .get_param("q")
-                .and_then(|value| Some((value.as_str().parse::<f32>().ok()? * 1000.0) as i32))
-                .unwrap_or(1000);
-            Some((mime, q))
+            let q = match mime.get_param("q") {
+                Some(value) => {
+                    let q = value.as_str().trim().parse::<f32>().ok()?;
+                    if !(0.0..=1.0).contains(&q) {
+                        return None;
+                    }
+                    (q * 1000.0) as i32
+                }
+                None => 1000,
+            };
+            (q > 0).then_some((mime, q))
         })
         .collect::<Vec<_>>();
     items.sort_by(|(_, qa), (_, qb)| qb.cmp(qa));
-    items.into_iter().map(|(mime, _)| mime).collect()
+
+    let mut mimes: Vec<Mime> = Vec::with_capacity(items.len());
+    for (mime, _) in items {
+        if !mimes
+            .iter()
+            .any(|seen| seen.essence_str() == mime.essence_str())
+        {
+            mimes.push(mime);
+        }
+    }
+    mimes
+}
+
+impl Accept {
+    /// The most preferred of the `supported` formats, or `None` if none of them is acceptable.
+    ///
+    /// Negotiating against the formats a particular endpoint can produce means that a client
+    /// preferring a format the endpoint doesn't support gets its next preference instead.
+    pub fn negotiate(&self, supported: &[AcceptFormat]) -> Option<AcceptFormat> {
+        // Media ranges are compared without their parameters, e.g. `application/bcs;q=0.9`
+        self.0.iter().find_map(|mime| {
+            supported
+                .iter()
+                .copied()
+                .find(|format| mime.essence_str() == format.mime_type())
+        })
+    }
 }
 
 #[axum::async_trait]
@@ -51,6 +91,18 @@ where
 pub enum AcceptFormat {
     Json,
     Bcs,
+    Cbor,
+}
+
+impl AcceptFormat {
+    /// The mime type used to request this format via the `Accept` header.
+    pub const fn mime_type(self) -> &'static str {
+        match self {
+            Self::Json => crate::APPLICATION_JSON,
+            Self::Bcs => APPLICATION_BCS,
+            Self::Cbor => APPLICATION_CBOR,
+        }
+    }
 }
 
 #[axum::async_trait]
@@ -66,16 +118,141 @@ where
     ) -> Result<Self, Self::Rejection> {
         let accept = Accept::from_request_parts(parts, s).await?;
 
-        for mime in accept.0 {
-            if mime.as_ref() == APPLICATION_BCS {
-                return Ok(Self::Bcs);
+        // JSON is served to clients which don't accept any of the formats, e.g. `text/html`
+        Ok(accept
+            .negotiate(&[Self::Json, Self::Bcs, Self::Cbor])
+            .unwrap_or(Self::Json))
+    }
+}
+
+/// The requested format of an endpoint which can only produce JSON and BCS, negotiated against
+/// [`RESPOND_FORMATS`] rather than every [`AcceptFormat`].
+///
+/// A client preferring CBOR is served its next preference rather than JSON regardless, e.g. BCS
+/// for `Accept: application/cbor, application/bcs;q=0.5`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AcceptJsonOrBcs {
+    Json,
+    Bcs,
+}
+
+impl From<AcceptJsonOrBcs> for AcceptFormat {
+    fn from(value: AcceptJsonOrBcs) -> Self {
+        match value {
+            AcceptJsonOrBcs::Json => Self::Json,
+            AcceptJsonOrBcs::Bcs => Self::Bcs,
+        }
+    }
+}
+
+#[axum::async_trait]
+impl<S> axum::extract::FromRequestParts<S> for AcceptJsonOrBcs
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(
+        parts: &mut http::request::Parts,
+        s: &S,
+    ) -> Result<Self, Self::Rejection> {
+        let accept = Accept::from_request_parts(parts, s).await?;
+
+        match accept.negotiate(RESPOND_FORMATS) {
+            Some(AcceptFormat::Bcs) => Ok(Self::Bcs),
+            _ => Ok(Self::Json),
+        }
+    }
+}
+
+/// The requested format isn't one which the endpoint is able to produce, responding with
+/// `406 Not Acceptable`.
+#[derive(Debug)]
+pub struct UnsupportedAcceptFormatError {
+    supported: &'static [AcceptFormat],
+}
+
+impl UnsupportedAcceptFormatError {
+    pub fn new(supported: &'static [AcceptFormat]) -> Self {
+        Self { supported }
+    }
+}
+
+impl std::fmt::Display for UnsupportedAcceptFormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid accept type, supported formats: ")?;
+
+        for (i, format) in self.supported.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
             }
+            write!(f, "{}", format.mime_type())?;
         }
 
-        Ok(Self::Json)
+        Ok(())
     }
 }
 
+impl std::error::Error for UnsupportedAcceptFormatError {}
+
+impl From<UnsupportedAcceptFormatError> for crate::RestError {
+    fn from(value: UnsupportedAcceptFormatError) -> Self {
+        Self::new(http::StatusCode::NOT_ACCEPTABLE, value.to_string())
+    }
+}
+
+/// The formats which [`respond`] is able to produce.
+pub const RESPOND_FORMATS: &[AcceptFormat] = &[AcceptFormat::Json, AcceptFormat::Bcs];
+
+/// Render `value` as JSON or BCS according to the requested `format`, responding with
+/// `406 Not Acceptable` if any other format was requested.
+///
+/// This is the uniform way for endpoints without format specific requirements to support BCS.
+pub fn respond<T: serde::Serialize>(
+    format: AcceptFormat,
+    value: T,
+) -> crate::Result<axum::response::Response> {
+    use axum::response::IntoResponse;
+
+    match format {
+        AcceptFormat::Json => Ok(axum::Json(value).into_response()),
+        AcceptFormat::Bcs => Ok(crate::response::Bcs(value).into_response()),
+        AcceptFormat::Cbor => Err(UnsupportedAcceptFormatError::new(RESPOND_FORMATS).into()),
+    }
+}
+
+/// Render `value` as JSON, BCS or CBOR according to the requested `format`.
+///
+/// This is the counterpart of [`respond`] for endpoints which are able to produce every
+/// [`AcceptFormat`], so never respond with `406 Not Acceptable`.
+pub fn respond_with_cbor<T: serde::Serialize>(
+    format: AcceptFormat,
+    value: T,
+) -> axum::response::Response {
+    use axum::response::IntoResponse;
+
+    match format {
+        AcceptFormat::Json => axum::Json(value).into_response(),
+        AcceptFormat::Bcs => crate::response::Bcs(value).into_response(),
+        AcceptFormat::Cbor => crate::response::Cbor(value).into_response(),
+    }
+}
+
+/// Middleware which replaces the `Accept` header of a request with the mime type of `format`.
+///
+/// This backs routes which select their format through a file-extension suffix (e.g.
+/// `/system.bcs`) rather than content negotiation.
+pub async fn override_accept<B>(
+    axum::extract::State(format): axum::extract::State<AcceptFormat>,
+    mut request: http::Request<B>,
+) -> http::Request<B> {
+    request.headers_mut().insert(
+        header::ACCEPT,
+        http::HeaderValue::from_static(format.mime_type()),
+    );
+    request
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -122,5 +299,386 @@ mod tests {
             .unwrap();
         let accept = AcceptFormat::from_request(req, &()).await.unwrap();
         assert_eq!(accept, AcceptFormat::Json);
+
+        let req = Request::builder()
+            .header(header::ACCEPT, "application/json;q=0.5, application/cbor")
+            .body(())
+            .unwrap();
+        let accept = AcceptFormat::from_request(req, &()).await.unwrap();
+        assert_eq!(accept, AcceptFormat::Cbor);
+    }
+
+    #[tokio::test]
+    async fn test_negotiate_supported_formats() {
+        let cases = [
+            // Endpoints which can't produce CBOR fall back to the client's next preference
+            (
+                "application/cbor, application/json;q=0.5",
+                AcceptFormat::Cbor,
+                AcceptFormat::Json,
+            ),
+            (
+                "application/cbor, application/bcs;q=0.5",
+                AcceptFormat::Cbor,
+                AcceptFormat::Bcs,
+            ),
+            // A preference for JSON is honored over a less preferred binary format
+            (
+                "application/json, application/bcs;q=0.5",
+                AcceptFormat::Json,
+                AcceptFormat::Json,
+            ),
+            // A quality of 0 rules a format out
+            (
+                "application/bcs;q=0, */*",
+                AcceptFormat::Json,
+                AcceptFormat::Json,
+            ),
+        ];
+
+        for (value, expected, expected_json_or_bcs) in cases {
+            let request = || {
+                Request::builder()
+                    .header(header::ACCEPT, value)
+                    .body(())
+                    .unwrap()
+            };
+            let accept = AcceptFormat::from_request(request(), &()).await.unwrap();
+            assert_eq!(accept, expected, "{value}");
+            let accept = AcceptJsonOrBcs::from_request(request(), &()).await.unwrap();
+            assert_eq!(AcceptFormat::from(accept), expected_json_or_bcs, "{value}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_accept_malformed_segments() {
+        // Empty tokens are skipped
+        let req = Request::builder()
+            .header(header::ACCEPT, "application/json,,, */*")
+            .body(())
+            .unwrap();
+        let accept = Accept::from_request(req, &()).await.unwrap();
+        assert_eq!(
+            accept.0,
+            &[
+                Mime::from_str("application/json").unwrap(),
+                Mime::from_str("*/*").unwrap()
+            ]
+        );
+
+        // As is stray whitespace, malformed entries and malformed quality values, while
+        // duplicates only keep their most preferred occurrence
+        let req = Request::builder()
+            .header(
+                header::ACCEPT,
+                "  ,\t application/json ;q=0.9 ,  , not a mime, application/bcs;q=nope,\
+                 application/json ,text/html;q=0.5\t,",
+            )
+            .body(())
+            .unwrap();
+        let accept = Accept::from_request(req, &()).await.unwrap();
+        assert_eq!(
+            accept.0,
+            &[
+                Mime::from_str("application/json").unwrap(),
+                Mime::from_str("text/html;q=0.5").unwrap(),
+            ]
+        );
+
+        for accept in [
+            "application/json,,, */*",
+            ",,,",
+            "   ",
+            " application/json , , application/json;q=0.2 ,",
+            "application/bcs;q=2, application/cbor;q=-1, application/json",
+        ] {
+            let req = Request::builder()
+                .header(header::ACCEPT, accept)
+                .body(())
+                .unwrap();
+            let format = AcceptFormat::from_request(req, &()).await.unwrap();
+            assert_eq!(format, AcceptFormat::Json, "{accept:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_override_accept() {
+        use axum::{middleware::map_request_with_state, routing::get, Router};
+        use tower::ServiceExt;
+
+        async fn format(accept: AcceptFormat) -> &'static str {
+            accept.mime_type()
+        }
+
+        let router = Router::new()
+            .route("/system", get(format))
+            .route(
+                "/system.json",
+                get(format).layer(map_request_with_state(AcceptFormat::Json, override_accept)),
+            )
+            .route(
+                "/system.bcs",
+                get(format).layer(map_request_with_state(AcceptFormat::Bcs, override_accept)),
+            );
+
+        for (uri, accept, expected) in [
+            ("/system", APPLICATION_BCS, APPLICATION_BCS),
+            ("/system.json", APPLICATION_BCS, crate::APPLICATION_JSON),
+            ("/system.json", APPLICATION_CBOR, crate::APPLICATION_JSON),
+            ("/system.bcs", crate::APPLICATION_JSON, APPLICATION_BCS),
+            ("/system.bcs", APPLICATION_CBOR, APPLICATION_BCS),
+        ] {
+            let request = Request::builder()
+                .uri(uri)
+                .header(header::ACCEPT, accept)
+                .body(axum::body::Body::empty())
+                .unwrap();
+            let response = router.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), http::StatusCode::OK);
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            assert_eq!(body, expected, "{uri} with accept {accept}");
+        }
+
+        let request = Request::builder()
+            .uri("/system.yaml")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), http::StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_accept_format() {
+        use axum::response::IntoResponse;
+
+        let response = crate::RestError::from(UnsupportedAcceptFormatError::new(&[
+            AcceptFormat::Json,
+            AcceptFormat::Cbor,
+        ]))
+        .into_response();
+        assert_eq!(response.status(), http::StatusCode::NOT_ACCEPTABLE);
+
+        assert_eq!(
+            response.headers()[http::header::CONTENT_TYPE],
+            crate::APPLICATION_PROBLEM_JSON
+        );
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let problem: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            problem,
+            serde_json::json!({
+                "type": "about:blank",
+                "title": "Not Acceptable",
+                "status": 406,
+                "detail": "invalid accept type, supported formats: application/json, application/cbor",
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_respond() {
+        use axum::response::IntoResponse;
+
+        let value = vec![1u64, 2, 3];
+
+        let response = respond(AcceptFormat::Json, &value).unwrap();
+        assert_eq!(response.status(), http::StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            crate::APPLICATION_JSON
+        );
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        assert_eq!(serde_json::from_slice::<Vec<u64>>(&body).unwrap(), value);
+
+        let response = respond(AcceptFormat::Bcs, &value).unwrap();
+        assert_eq!(response.status(), http::StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], APPLICATION_BCS);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        assert_eq!(bcs::from_bytes::<Vec<u64>>(&body).unwrap(), value);
+
+        let response = respond(AcceptFormat::Cbor, &value)
+            .unwrap_err()
+            .into_response();
+        assert_eq!(response.status(), http::StatusCode::NOT_ACCEPTABLE);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let problem: crate::ProblemDetails = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            problem.detail.as_deref(),
+            Some("invalid accept type, supported formats: application/json, application/bcs")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_respond_with_cbor() {
+        let value = vec![1u64, 2, 3];
+
+        for format in [AcceptFormat::Json, AcceptFormat::Bcs, AcceptFormat::Cbor] {
+            let response = respond_with_cbor(format, &value);
+            assert_eq!(response.status(), http::StatusCode::OK);
+            assert_eq!(response.headers()[header::CONTENT_TYPE], format.mime_type());
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let decoded: Vec<u64> = match format {
+                AcceptFormat::Json => serde_json::from_slice(&body).unwrap(),
+                AcceptFormat::Bcs => bcs::from_bytes(&body).unwrap(),
+                AcceptFormat::Cbor => ciborium::de::from_reader(body.as_ref()).unwrap(),
+            };
+            assert_eq!(decoded, value);
+        }
+    }
+
+    mod proptests {
+        use super::*;
+        use axum::extract::FromRequestParts;
+        use proptest::prelude::*;
+
+        /// Fragments which headers are assembled from, biased towards the syntax of media ranges
+        /// so that generated headers exercise more of the parser than entirely random input.
+        fn accept_header() -> impl Strategy<Value = Vec<u8>> {
+            let fragment = prop_oneof![
+                Just(b"application/json".to_vec()),
+                Just(APPLICATION_BCS.as_bytes().to_vec()),
+                Just(APPLICATION_CBOR.as_bytes().to_vec()),
+                Just(b"*/*".to_vec()),
+                Just(b";q=".to_vec()),
+                Just(b",".to_vec()),
+                Just(b" ".to_vec()),
+                "[0-9.eE+-]{1,6}".prop_map(String::into_bytes),
+                "[ -~\t]{0,12}".prop_map(String::into_bytes),
+                prop::collection::vec(any::<u8>(), 0..8),
+            ];
+
+            prop::collection::vec(fragment, 0..16).prop_map(|fragments| fragments.concat())
+        }
+
+        fn headers(values: &[Vec<u8>]) -> HeaderMap {
+            let mut headers = HeaderMap::new();
+            for value in values {
+                // Bytes which can't appear in a header at all are rejected before reaching us
+                if let Ok(value) = http::HeaderValue::from_bytes(value) {
+                    headers.append(header::ACCEPT, value);
+                }
+            }
+            headers
+        }
+
+        /// Media ranges of generated `Accept` headers, along with the format each one names.
+        const RANGES: &[(&str, Option<AcceptFormat>)] = &[
+            ("application/json", Some(AcceptFormat::Json)),
+            (APPLICATION_BCS, Some(AcceptFormat::Bcs)),
+            (APPLICATION_CBOR, Some(AcceptFormat::Cbor)),
+            ("*/*", None),
+            ("text/html", None),
+        ];
+
+        /// Indices into [`RANGES`], each with an optional quality in thousandths, e.g. `500` for
+        /// `q=0.500`. The bounds of the quality are generated more often than other values.
+        fn media_ranges() -> impl Strategy<Value = Vec<(usize, Option<u16>)>> {
+            let quality = prop_oneof![Just(0), Just(1000), 0..=1000u16];
+
+            prop::collection::vec((0..RANGES.len(), prop::option::of(quality)), 0..6)
+        }
+
+        fn render(ranges: &[(usize, Option<u16>)]) -> String {
+            ranges
+                .iter()
+                .map(|(range, quality)| match quality {
+                    Some(q) => format!("{};q={}.{:03}", RANGES[*range].0, q / 1000, q % 1000),
+                    None => RANGES[*range].0.to_owned(),
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+
+        /// The quality the client gave `format`, the highest of those of the media ranges naming
+        /// it, which default to 1.
+        fn quality(ranges: &[(usize, Option<u16>)], format: AcceptFormat) -> Option<u16> {
+            ranges
+                .iter()
+                .filter(|(range, _)| RANGES[*range].1 == Some(format))
+                .map(|(_, quality)| quality.unwrap_or(1000))
+                .max()
+        }
+
+        /// Check that `format`, negotiated out of `supported` for `ranges`, is the one the client
+        /// prefers the most.
+        fn check_negotiated(
+            ranges: &[(usize, Option<u16>)],
+            supported: &[AcceptFormat],
+            format: AcceptFormat,
+        ) -> Result<(), TestCaseError> {
+            prop_assert!(supported.contains(&format));
+
+            let best = supported
+                .iter()
+                .filter_map(|supported| quality(ranges, *supported))
+                .max()
+                .filter(|best| *best > 0);
+            match best {
+                // One the client offered, with the highest quality, so never one with `q=0`
+                Some(best) => prop_assert_eq!(quality(ranges, format), Some(best)),
+                // Clients which accept none of the formats are served JSON
+                None => prop_assert_eq!(format, AcceptFormat::Json),
+            }
+
+            Ok(())
+        }
+
+        fn request_parts(headers: HeaderMap) -> http::request::Parts {
+            let mut parts = http::Request::builder().body(()).unwrap().into_parts().0;
+            parts.headers = headers;
+            parts
+        }
+
+        proptest! {
+            #[test]
+            fn parse_arbitrary_accept(values in prop::collection::vec(accept_header(), 0..3)) {
+                let headers = headers(&values);
+                let mimes = parse_accept(&headers);
+
+                for (i, mime) in mimes.iter().enumerate() {
+                    // Every media range appears at most once
+                    prop_assert!(mimes[..i]
+                        .iter()
+                        .all(|seen| seen.essence_str() != mime.essence_str()));
+
+                    // And none of them is marked as not acceptable
+                    if let Some(q) = mime.get_param("q") {
+                        prop_assert!(q.as_str().trim().parse::<f32>().unwrap() > 0.0);
+                    }
+                }
+
+                // Negotiating a format never fails, whatever the header
+                let mut parts = request_parts(headers);
+                futures::executor::block_on(AcceptFormat::from_request_parts(&mut parts, &()))
+                    .unwrap();
+            }
+
+            #[test]
+            fn negotiate_preferred_format(ranges in media_ranges()) {
+                let mut headers = HeaderMap::new();
+                headers.insert(header::ACCEPT, render(&ranges).parse().unwrap());
+
+                let mut parts = request_parts(headers);
+                let format =
+                    futures::executor::block_on(AcceptFormat::from_request_parts(&mut parts, &()))
+                        .unwrap();
+                check_negotiated(
+                    &ranges,
+                    &[AcceptFormat::Json, AcceptFormat::Bcs, AcceptFormat::Cbor],
+                    format,
+                )?;
+
+                let format: AcceptFormat = futures::executor::block_on(
+                    AcceptJsonOrBcs::from_request_parts(&mut parts, &()),
+                )
+                .unwrap()
+                .into();
+                check_negotiated(&ranges, RESPOND_FORMATS, format)?;
+                // So endpoints only producing JSON and BCS are always able to respond
+                prop_assert!(respond(format, ()).is_ok());
+            }
+        }
     }
 }
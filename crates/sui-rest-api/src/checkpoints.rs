@@ -8,7 +8,7 @@ use sui_sdk2::types::{
 use sui_types::storage::ReadStore;
 use tap::Pipe;
 
-use crate::{accept::AcceptFormat, response::ResponseContent, Result};
+use crate::{accept::AcceptJsonOrBcs, response::ResponseContent, Result};
 
 pub const GET_LATEST_CHECKPOINT_PATH: &str = "/checkpoints";
 pub const GET_CHECKPOINT_PATH: &str = "/checkpoints/:checkpoint";
@@ -16,7 +16,7 @@ pub const GET_FULL_CHECKPOINT_PATH: &str = "/checkpoints/:checkpoint/full";
 
 pub async fn get_full_checkpoint<S: ReadStore>(
     Path(checkpoint_id): Path<CheckpointId>,
-    accept: AcceptFormat,
+    accept: AcceptJsonOrBcs,
     State(state): State<S>,
 ) -> Result<ResponseContent<CheckpointData>> {
     let verified_summary = match checkpoint_id {
@@ -34,28 +34,28 @@ pub async fn get_full_checkpoint<S: ReadStore>(
         .into();
 
     match accept {
-        AcceptFormat::Json => ResponseContent::Json(checkpoint_data),
-        AcceptFormat::Bcs => ResponseContent::Bcs(checkpoint_data),
+        AcceptJsonOrBcs::Json => ResponseContent::Json(checkpoint_data),
+        AcceptJsonOrBcs::Bcs => ResponseContent::Bcs(checkpoint_data),
     }
     .pipe(Ok)
 }
 
 pub async fn get_latest_checkpoint<S: ReadStore>(
-    accept: AcceptFormat,
+    accept: AcceptJsonOrBcs,
     State(state): State<S>,
 ) -> Result<ResponseContent<SignedCheckpointSummary>> {
     let summary = state.get_latest_checkpoint()?.into_inner().into();
 
     match accept {
-        AcceptFormat::Json => ResponseContent::Json(summary),
-        AcceptFormat::Bcs => ResponseContent::Bcs(summary),
+        AcceptJsonOrBcs::Json => ResponseContent::Json(summary),
+        AcceptJsonOrBcs::Bcs => ResponseContent::Bcs(summary),
     }
     .pipe(Ok)
 }
 
 pub async fn get_checkpoint<S: ReadStore>(
     Path(checkpoint_id): Path<CheckpointId>,
-    accept: AcceptFormat,
+    accept: AcceptJsonOrBcs,
     State(state): State<S>,
 ) -> Result<ResponseContent<SignedCheckpointSummary>> {
     let summary = match checkpoint_id {
@@ -67,8 +67,8 @@ pub async fn get_checkpoint<S: ReadStore>(
     .into();
 
     match accept {
-        AcceptFormat::Json => ResponseContent::Json(summary),
-        AcceptFormat::Bcs => ResponseContent::Bcs(summary),
+        AcceptJsonOrBcs::Json => ResponseContent::Json(summary),
+        AcceptJsonOrBcs::Bcs => ResponseContent::Bcs(summary),
     }
     .pipe(Ok)
 }
@@ -1,7 +1,7 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{accept::AcceptFormat, reader::StateReader, response::ResponseContent, Result};
+use crate::{accept::AcceptJsonOrBcs, reader::StateReader, response::ResponseContent, Result};
 use axum::extract::{Path, State};
 use sui_sdk2::types::{EpochId, ValidatorCommittee};
 use sui_types::storage::ReadStore;
@@ -10,17 +10,22 @@ use tap::Pipe;
 pub const GET_LATEST_COMMITTEE_PATH: &str = "/committee";
 
 pub async fn get_latest_committee(
-    accept: AcceptFormat,
+    accept: AcceptJsonOrBcs,
     State(state): State<StateReader>,
 ) -> Result<ResponseContent<ValidatorCommittee>> {
-    let current_epoch = state.inner().get_latest_checkpoint()?.epoch();
     let committee = state
-        .get_committee(current_epoch)?
-        .ok_or_else(|| CommitteeNotFoundError::new(current_epoch))?;
+        .spawn_read(|state| {
+            let current_epoch = state.inner().get_latest_checkpoint()?.epoch();
+
+            state
+                .get_committee(current_epoch)?
+                .ok_or_else(|| crate::RestError::from(CommitteeNotFoundError::new(current_epoch)))
+        })
+        .await?;
 
     match accept {
-        AcceptFormat::Json => ResponseContent::Json(committee),
-        AcceptFormat::Bcs => ResponseContent::Bcs(committee),
+        AcceptJsonOrBcs::Json => ResponseContent::Json(committee),
+        AcceptJsonOrBcs::Bcs => ResponseContent::Bcs(committee),
     }
     .pipe(Ok)
 }
@@ -29,16 +34,20 @@ pub const GET_COMMITTEE_PATH: &str = "/committee/:epoch";
 
 pub async fn get_committee(
     Path(epoch): Path<EpochId>,
-    accept: AcceptFormat,
+    accept: AcceptJsonOrBcs,
     State(state): State<StateReader>,
 ) -> Result<ResponseContent<ValidatorCommittee>> {
     let committee = state
-        .get_committee(epoch)?
-        .ok_or_else(|| CommitteeNotFoundError::new(epoch))?;
+        .spawn_read(move |state| {
+            state
+                .get_committee(epoch)?
+                .ok_or_else(|| crate::RestError::from(CommitteeNotFoundError::new(epoch)))
+        })
+        .await?;
 
     match accept {
-        AcceptFormat::Json => ResponseContent::Json(committee),
-        AcceptFormat::Bcs => ResponseContent::Bcs(committee),
+        AcceptJsonOrBcs::Json => ResponseContent::Json(committee),
+        AcceptJsonOrBcs::Bcs => ResponseContent::Bcs(committee),
     }
     .pipe(Ok)
 }
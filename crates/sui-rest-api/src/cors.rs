@@ -0,0 +1,117 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::http::{header, HeaderName, HeaderValue, Method};
+use tower_http::cors::{AllowOrigin, Any, CorsLayer};
+
+/// Cross-Origin Resource Sharing policy applied to the REST service, allowing it to be called
+/// directly from browser-based clients like explorers.
+#[derive(Clone, Debug)]
+pub struct CorsConfig {
+    /// Origins which are permitted to make requests. `None` permits any origin.
+    pub allowed_origins: Option<Vec<HeaderValue>>,
+    /// Methods which are permitted in cross-origin requests.
+    pub allowed_methods: Vec<Method>,
+    /// Request headers which are permitted in cross-origin requests.
+    pub allowed_headers: Vec<HeaderName>,
+}
+
+impl Default for CorsConfig {
+    /// A read-only policy permitting `GET` and `HEAD` requests from any origin.
+    fn default() -> Self {
+        Self {
+            allowed_origins: None,
+            allowed_methods: vec![Method::GET, Method::HEAD],
+            allowed_headers: vec![header::ACCEPT, header::CONTENT_TYPE],
+        }
+    }
+}
+
+impl CorsConfig {
+    pub fn layer(&self) -> CorsLayer {
+        let allow_origin = match &self.allowed_origins {
+            Some(origins) => AllowOrigin::list(origins.iter().cloned()),
+            None => Any.into(),
+        };
+
+        CorsLayer::new()
+            .allow_origin(allow_origin)
+            .allow_methods(self.allowed_methods.clone())
+            .allow_headers(self.allowed_headers.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+        routing::get,
+        Router,
+    };
+    use tower::ServiceExt;
+
+    use super::*;
+
+    fn preflight(origin: &'static str) -> Request<Body> {
+        Request::builder()
+            .method(Method::OPTIONS)
+            .uri("/system")
+            .header(header::ORIGIN, origin)
+            .header(header::ACCESS_CONTROL_REQUEST_METHOD, "GET")
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn default_preflight() {
+        let app = Router::new()
+            .route("/system", get(|| async { "ok" }))
+            .layer(CorsConfig::default().layer());
+
+        let response = app
+            .oneshot(preflight("https://explorer.example"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let headers = response.headers();
+        assert_eq!(headers[header::ACCESS_CONTROL_ALLOW_ORIGIN], "*");
+        let methods = headers[header::ACCESS_CONTROL_ALLOW_METHODS]
+            .to_str()
+            .unwrap();
+        assert!(methods.contains("GET"));
+        assert!(methods.contains("HEAD"));
+        assert!(!methods.contains("POST"));
+    }
+
+    #[tokio::test]
+    async fn restricted_origins() {
+        let config = CorsConfig {
+            allowed_origins: Some(vec![HeaderValue::from_static("https://explorer.example")]),
+            ..Default::default()
+        };
+        let app = Router::new()
+            .route("/system", get(|| async { "ok" }))
+            .layer(config.layer());
+
+        let response = app
+            .clone()
+            .oneshot(preflight("https://explorer.example"))
+            .await
+            .unwrap();
+        assert_eq!(
+            response.headers()[header::ACCESS_CONTROL_ALLOW_ORIGIN],
+            "https://explorer.example"
+        );
+
+        let response = app
+            .oneshot(preflight("https://other.example"))
+            .await
+            .unwrap();
+        assert!(response
+            .headers()
+            .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+            .is_none());
+    }
+}
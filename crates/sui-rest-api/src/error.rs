@@ -5,6 +5,7 @@ use axum::http::StatusCode;
 
 pub type Result<T, E = RestError> = std::result::Result<T, E>;
 
+#[derive(Debug)]
 pub struct RestError {
     status: StatusCode,
     message: Option<String>,
@@ -19,22 +20,66 @@ impl RestError {
     }
 }
 
+/// An RFC 7807 problem document, the body of every error response.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ProblemDetails {
+    /// URI identifying the problem type. Always `about:blank`, meaning the problem is fully
+    /// described by its status code.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// Short summary of the problem, the canonical reason phrase of the status code.
+    pub title: String,
+    /// The HTTP status code of the response.
+    pub status: u16,
+    /// Explanation specific to this occurrence of the problem.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// Path of the request which resulted in the problem.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    /// Id of the request which resulted in the problem, as echoed in the `x-request-id` header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
 // Tell axum how to convert `AppError` into a response.
 impl axum::response::IntoResponse for RestError {
     fn into_response(self) -> axum::response::Response {
-        match self.message {
-            Some(message) => (self.status, message).into_response(),
-            None => self.status.into_response(),
-        }
+        let request = crate::request_id::current_request();
+        let problem = ProblemDetails {
+            type_: "about:blank".to_owned(),
+            title: self
+                .status
+                .canonical_reason()
+                .unwrap_or("Unknown Error")
+                .to_owned(),
+            status: self.status.as_u16(),
+            detail: self.message,
+            instance: request.as_ref().map(|request| request.path.clone()),
+            request_id: request.map(|request| request.id),
+        };
+
+        (
+            self.status,
+            [(
+                axum::http::header::CONTENT_TYPE,
+                axum::http::HeaderValue::from_static(crate::APPLICATION_PROBLEM_JSON),
+            )],
+            serde_json::to_vec(&problem).expect("problem documents are serializable"),
+        )
+            .into_response()
     }
 }
 
+/// Failures of the store are classified by [`StateReaderError`](crate::reader::StateReaderError)
+/// for every endpoint, not only those under `/system`: reading objects, checkpoints, the committee
+/// or the node info responds with `404` for data which is missing, e.g. because it was pruned,
+/// and `503` when the database can't be read at the moment, as well as `500` otherwise.
+///
+/// Transaction execution doesn't read from the store, so isn't affected.
 impl From<sui_types::storage::error::Error> for RestError {
     fn from(value: sui_types::storage::error::Error) -> Self {
-        Self {
-            status: StatusCode::INTERNAL_SERVER_ERROR,
-            message: Some(value.to_string()),
-        }
+        crate::reader::StateReaderError::from(value).into()
     }
 }
 
@@ -3,22 +3,29 @@
 
 use std::borrow::Cow;
 
-use crate::{accept::AcceptFormat, response::ResponseContent};
+use crate::{accept::AcceptJsonOrBcs, response::ResponseContent};
 use crate::{RestService, Result};
 use axum::extract::State;
 use sui_types::digests::ChainIdentifier;
 use tap::Pipe;
 
 pub async fn node_info(
-    accept: AcceptFormat,
+    accept: AcceptJsonOrBcs,
     State(state): State<RestService>,
 ) -> Result<ResponseContent<NodeInfo>> {
-    let latest_checkpoint = state.reader.inner().get_latest_checkpoint()?;
-    let lowest_available_checkpoint = state.reader.inner().get_lowest_available_checkpoint()?;
-    let lowest_available_checkpoint_objects = state
-        .reader
-        .inner()
-        .get_lowest_available_checkpoint_objects()?;
+    let (latest_checkpoint, lowest_available_checkpoint, lowest_available_checkpoint_objects) =
+        state
+            .reader
+            .spawn_read(|state| {
+                let store = state.inner();
+
+                Ok::<_, crate::RestError>((
+                    store.get_latest_checkpoint()?,
+                    store.get_lowest_available_checkpoint()?,
+                    store.get_lowest_available_checkpoint_objects()?,
+                ))
+            })
+            .await?;
 
     let response = NodeInfo {
         checkpoint_height: latest_checkpoint.sequence_number,
@@ -32,8 +39,8 @@ pub async fn node_info(
     };
 
     match accept {
-        AcceptFormat::Json => ResponseContent::Json(response),
-        AcceptFormat::Bcs => ResponseContent::Bcs(response),
+        AcceptJsonOrBcs::Json => ResponseContent::Json(response),
+        AcceptJsonOrBcs::Bcs => ResponseContent::Bcs(response),
     }
     .pipe(Ok)
 }
@@ -11,31 +11,41 @@ mod checkpoints;
 pub mod client;
 mod committee;
 pub mod content_type;
+mod cors;
 mod error;
 mod health;
 mod info;
 mod metrics;
 mod objects;
+mod rate_limit;
 mod reader;
+mod request_id;
 mod response;
+mod server_error;
 mod system;
+mod timeout;
 pub mod transactions;
 pub mod types;
 
+pub use accept::APPLICATION_CBOR;
 pub use client::Client;
-pub use error::{RestError, Result};
+pub use cors::CorsConfig;
+pub use error::{ProblemDetails, RestError, Result};
 pub use metrics::RestMetrics;
 use mysten_network::callback::CallbackLayer;
+pub use rate_limit::RateLimitConfig;
 use reader::StateReader;
 use std::sync::Arc;
 pub use sui_types::full_checkpoint_content::{CheckpointData, CheckpointTransaction};
 use sui_types::storage::{ReadStore, RestStateReader};
 use tap::Pipe;
+pub use timeout::RequestTimeoutConfig;
 pub use transactions::{ExecuteTransactionQueryParameters, TransactionExecutor};
 
 pub const TEXT_PLAIN_UTF_8: &str = "text/plain; charset=utf-8";
 pub const APPLICATION_BCS: &str = "application/bcs";
 pub const APPLICATION_JSON: &str = "application/json";
+pub const APPLICATION_PROBLEM_JSON: &str = "application/problem+json";
 
 #[derive(Clone)]
 pub struct RestService {
@@ -44,6 +54,13 @@ pub struct RestService {
     chain_id: sui_types::digests::ChainIdentifier,
     software_version: &'static str,
     metrics: Option<Arc<RestMetrics>>,
+    cors: Option<CorsConfig>,
+    validator_address: Option<sui_sdk2::types::Address>,
+    stale_system_state_fallback: Option<system::StaleSystemStateFallback>,
+    system_state_gzip_cache: system::SystemStateGzipCache,
+    rate_limit: Option<RateLimitConfig>,
+    request_timeout: Option<RequestTimeoutConfig>,
+    max_exchange_rate_history_epochs: u64,
 }
 
 impl axum::extract::FromRef<RestService> for StateReader {
@@ -52,6 +69,18 @@ impl axum::extract::FromRef<RestService> for StateReader {
     }
 }
 
+impl axum::extract::FromRef<RestService> for Option<system::StaleSystemStateFallback> {
+    fn from_ref(input: &RestService) -> Self {
+        input.stale_system_state_fallback.clone()
+    }
+}
+
+impl axum::extract::FromRef<RestService> for system::SystemStateGzipCache {
+    fn from_ref(input: &RestService) -> Self {
+        input.system_state_gzip_cache.clone()
+    }
+}
+
 impl RestService {
     pub fn new(reader: Arc<dyn RestStateReader>, software_version: &'static str) -> Self {
         let chain_id = reader.get_chain_identifier().unwrap();
@@ -61,6 +90,13 @@ impl RestService {
             chain_id,
             software_version,
             metrics: None,
+            cors: None,
+            validator_address: None,
+            stale_system_state_fallback: None,
+            system_state_gzip_cache: system::SystemStateGzipCache::default(),
+            rate_limit: None,
+            request_timeout: None,
+            max_exchange_rate_history_epochs: system::DEFAULT_MAX_EXCHANGE_RATE_HISTORY_EPOCHS,
         }
     }
 
@@ -76,6 +112,40 @@ impl RestService {
         self.metrics = Some(Arc::new(metrics));
     }
 
+    /// Handle cross-origin requests, including preflight requests, according to `cors`.
+    pub fn with_cors(&mut self, cors: CorsConfig) {
+        self.cors = Some(cors);
+    }
+
+    /// Address of the validator operated by this node, if it is a validator. The node is only
+    /// reported as a validator while the address is in the active set.
+    pub fn with_validator_address(&mut self, address: sui_sdk2::types::Address) {
+        self.validator_address = Some(address);
+    }
+
+    /// Serve the last successfully read system state, marked as stale, from `/system` when
+    /// reading the current system state fails, rather than an error.
+    pub fn with_stale_system_state_fallback(&mut self) {
+        self.stale_system_state_fallback = Some(Default::default());
+    }
+
+    /// Limit the rate of read requests from each client IP according to `rate_limit`.
+    pub fn with_rate_limit(&mut self, rate_limit: RateLimitConfig) {
+        self.rate_limit = Some(rate_limit);
+    }
+
+    /// Time out requests according to `request_timeout`, letting clients override the default
+    /// timeout with the `x-request-timeout-ms` header.
+    pub fn with_request_timeout(&mut self, request_timeout: RequestTimeoutConfig) {
+        self.request_timeout = Some(request_timeout);
+    }
+
+    /// Maximum number of epochs of exchange rate history which can be requested at once. Defaults
+    /// to 300.
+    pub fn with_max_exchange_rate_history_epochs(&mut self, epochs: u64) {
+        self.max_exchange_rate_history_epochs = epochs;
+    }
+
     pub fn chain_id(&self) -> sui_types::digests::ChainIdentifier {
         self.chain_id
     }
@@ -84,9 +154,20 @@ impl RestService {
         self.software_version
     }
 
+    pub fn max_exchange_rate_history_epochs(&self) -> u64 {
+        self.max_exchange_rate_history_epochs
+    }
+
+    pub fn validator_address(&self) -> Option<sui_sdk2::types::Address> {
+        self.validator_address
+    }
+
     pub fn into_router(self) -> Router {
         let executor = self.executor.clone();
         let metrics = self.metrics.clone();
+        let cors = self.cors.clone();
+        let rate_limit = self.rate_limit.clone();
+        let request_timeout = self.request_timeout.clone();
         let store = self.reader.inner().clone();
 
         Router::new()
@@ -96,11 +177,16 @@ impl RestService {
                 get(committee::get_latest_committee),
             )
             .route(committee::GET_COMMITTEE_PATH, get(committee::get_committee))
-            .route(
-                system::GET_SYSTEM_STATE_SUMMARY_PATH,
-                get(system::get_system_state_summary),
-            )
+            .pipe(|router| {
+                system::routes()
+                    .into_iter()
+                    .fold(router, |router, (path, route)| router.route(path, route))
+            })
+            .fallback(system::system_fallback)
             .with_state(self.clone())
+            .layer(axum::middleware::from_fn(
+                server_error::structured_server_errors,
+            ))
             .merge(rest_router(store))
             .pipe(|router| {
                 if let Some(executor) = executor {
@@ -109,10 +195,31 @@ impl RestService {
                     router
                 }
             })
+            .pipe(|router| {
+                if let Some(request_timeout) = request_timeout {
+                    router.layer(axum::middleware::from_fn_with_state(
+                        request_timeout,
+                        timeout::request_timeout,
+                    ))
+                } else {
+                    router
+                }
+            })
             .layer(axum::middleware::map_response_with_state(
                 self,
                 response::append_info_headers,
             ))
+            .pipe(|router| {
+                if let Some(rate_limit) = rate_limit {
+                    router.layer(axum::middleware::from_fn_with_state(
+                        rate_limit::RateLimiter::new(rate_limit),
+                        rate_limit::rate_limit,
+                    ))
+                } else {
+                    router
+                }
+            })
+            .layer(axum::middleware::from_fn(request_id::request_id))
             .pipe(|router| {
                 if let Some(metrics) = metrics {
                     router.layer(CallbackLayer::new(
@@ -122,6 +229,14 @@ impl RestService {
                     router
                 }
             })
+            .pipe(|router| {
+                if let Some(cors) = cors {
+                    router.layer(cors.layer())
+                } else {
+                    router
+                }
+            })
+            .pipe(system::tolerate_trailing_slash)
     }
 
     pub async fn start_service(self, socket_address: std::net::SocketAddr, base: Option<String>) {
@@ -132,7 +247,7 @@ impl RestService {
         }
 
         axum::Server::bind(&socket_address)
-            .serve(app.into_make_service())
+            .serve(app.into_make_service_with_connect_info::<std::net::SocketAddr>())
             .await
             .unwrap();
     }
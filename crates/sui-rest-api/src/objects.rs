@@ -1,7 +1,7 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{accept::AcceptFormat, response::ResponseContent, Result};
+use crate::{accept::AcceptJsonOrBcs, response::ResponseContent, Result};
 use axum::extract::{Path, State};
 use sui_sdk2::types::{Object, ObjectId, Version};
 use sui_types::storage::ReadStore;
@@ -11,7 +11,7 @@ pub const GET_OBJECT_PATH: &str = "/objects/:object_id";
 
 pub async fn get_object<S: ReadStore>(
     Path(object_id): Path<ObjectId>,
-    accept: AcceptFormat,
+    accept: AcceptJsonOrBcs,
     State(state): State<S>,
 ) -> Result<ResponseContent<Object>> {
     let object = state
@@ -20,8 +20,8 @@ pub async fn get_object<S: ReadStore>(
         .into();
 
     match accept {
-        AcceptFormat::Json => ResponseContent::Json(object),
-        AcceptFormat::Bcs => ResponseContent::Bcs(object),
+        AcceptJsonOrBcs::Json => ResponseContent::Json(object),
+        AcceptJsonOrBcs::Bcs => ResponseContent::Bcs(object),
     }
     .pipe(Ok)
 }
@@ -30,7 +30,7 @@ pub const GET_OBJECT_WITH_VERSION_PATH: &str = "/objects/:object_id/version/:ver
 
 pub async fn get_object_with_version<S: ReadStore>(
     Path((object_id, version)): Path<(ObjectId, Version)>,
-    accept: AcceptFormat,
+    accept: AcceptJsonOrBcs,
     State(state): State<S>,
 ) -> Result<ResponseContent<Object>> {
     let object = state
@@ -39,8 +39,8 @@ pub async fn get_object_with_version<S: ReadStore>(
         .into();
 
     match accept {
-        AcceptFormat::Json => ResponseContent::Json(object),
-        AcceptFormat::Bcs => ResponseContent::Bcs(object),
+        AcceptJsonOrBcs::Json => ResponseContent::Json(object),
+        AcceptJsonOrBcs::Bcs => ResponseContent::Bcs(object),
     }
     .pipe(Ok)
 }
@@ -0,0 +1,294 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    net::{IpAddr, SocketAddr},
+    num::NonZeroUsize,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use axum::{
+    extract::{ConnectInfo, State},
+    http::{header, HeaderValue, Method, Request, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::RestError;
+
+/// Header to which each proxy appends the address of the peer it is forwarding a request for.
+const X_FORWARDED_FOR: &str = "x-forwarded-for";
+
+/// Number of clients tracked, beyond which the least recently seen client's bucket is evicted.
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+/// Per-client IP token-bucket rate limit applied to read (`GET` and `HEAD`) requests.
+#[derive(Clone, Debug)]
+pub struct RateLimitConfig {
+    /// Sustained number of requests per second permitted for each client.
+    pub requests_per_second: u32,
+    /// Number of requests a client can make in a burst, above the sustained rate.
+    pub burst_size: u32,
+    /// Number of trusted proxies in front of the service which append to the `x-forwarded-for`
+    /// header. Clients are identified by the address the outermost trusted proxy appended, i.e.
+    /// the entry this many from the right, as entries further left are chosen by the client.
+    ///
+    /// With the default of 0 the header is ignored and clients are identified by the address of
+    /// the connection.
+    pub trusted_proxy_hops: usize,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            requests_per_second: 10,
+            burst_size: 50,
+            trusted_proxy_hops: 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct RateLimiter {
+    config: RateLimitConfig,
+    buckets: Arc<Mutex<lru::LruCache<IpAddr, Bucket>>>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(config: RateLimitConfig) -> Self {
+        Self {
+            config,
+            buckets: Arc::new(Mutex::new(lru::LruCache::new(
+                NonZeroUsize::new(MAX_TRACKED_CLIENTS).unwrap(),
+            ))),
+        }
+    }
+
+    /// Take a token from the bucket of `client`, or return how long until one is available.
+    fn check(&self, client: IpAddr, now: Instant) -> Result<(), Duration> {
+        let rate = f64::from(self.config.requests_per_second.max(1));
+        let capacity = f64::from(self.config.burst_size.max(1));
+        let mut buckets = self.buckets.lock().unwrap();
+
+        if !buckets.contains(&client) {
+            buckets.put(
+                client,
+                Bucket {
+                    tokens: capacity,
+                    last_refill: now,
+                },
+            );
+        }
+        let bucket = buckets.get_mut(&client).expect("bucket was just inserted");
+        let elapsed = now.saturating_duration_since(bucket.last_refill);
+        bucket.tokens = (bucket.tokens + elapsed.as_secs_f64() * rate).min(capacity);
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / rate))
+        }
+    }
+
+    /// The address identifying the client of `request`, or `None` if it can't be determined.
+    fn client_address<B>(&self, request: &Request<B>) -> Option<IpAddr> {
+        let hops = self.config.trusted_proxy_hops;
+        let forwarded_for = (hops > 0)
+            .then(|| request.headers().get(X_FORWARDED_FOR))
+            .flatten()
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.rsplit(',').nth(hops - 1))
+            .and_then(|address| address.trim().parse().ok());
+
+        forwarded_for.or_else(|| {
+            request
+                .extensions()
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(address)| address.ip())
+        })
+    }
+}
+
+pub(crate) async fn rate_limit<B>(
+    State(limiter): State<RateLimiter>,
+    request: Request<B>,
+    next: Next<B>,
+) -> Response {
+    if !matches!(*request.method(), Method::GET | Method::HEAD) {
+        return next.run(request).await;
+    }
+
+    // Without a connection address, which the server is expected to provide, there's no way to
+    // tell clients apart, so rather than making them share a bucket they aren't limited
+    let Some(client) = limiter.client_address(&request) else {
+        tracing::warn!("unable to rate limit request without a client address");
+        return next.run(request).await;
+    };
+    match limiter.check(client, Instant::now()) {
+        Ok(()) => next.run(request).await,
+        Err(retry_after) => {
+            let mut response = RestError::new(StatusCode::TOO_MANY_REQUESTS, "rate limit exceeded")
+                .into_response();
+            // Retry-After is in whole seconds, so round up
+            let retry_after_secs =
+                retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(retry_after_secs));
+            response
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::Body, routing::get, Router};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    fn app(config: RateLimitConfig) -> Router {
+        Router::new()
+            .route("/system", get(|| async { "ok" }).post(|| async { "ok" }))
+            .layer(axum::middleware::from_fn_with_state(
+                RateLimiter::new(config),
+                rate_limit,
+            ))
+    }
+
+    fn request(method: Method, forwarded_for: &str) -> Request<Body> {
+        Request::builder()
+            .method(method)
+            .uri("/system")
+            .header(X_FORWARDED_FOR, forwarded_for)
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn burst_beyond_limit() {
+        let app = app(RateLimitConfig {
+            requests_per_second: 1,
+            burst_size: 3,
+            trusted_proxy_hops: 1,
+        });
+
+        let mut statuses = Vec::new();
+        for _ in 0..5 {
+            let response = app
+                .clone()
+                .oneshot(request(Method::GET, "192.168.0.1, 10.0.0.1"))
+                .await
+                .unwrap();
+            if response.status() == StatusCode::TOO_MANY_REQUESTS {
+                let retry_after = response.headers()[header::RETRY_AFTER].to_str().unwrap();
+                assert_eq!(retry_after, "1");
+            }
+            statuses.push(response.status());
+        }
+        assert_eq!(
+            statuses,
+            [
+                StatusCode::OK,
+                StatusCode::OK,
+                StatusCode::OK,
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::TOO_MANY_REQUESTS,
+            ]
+        );
+
+        // Other clients have their own bucket
+        let response = app
+            .clone()
+            .oneshot(request(Method::GET, "10.0.0.2"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // Only reads are limited
+        let response = app
+            .oneshot(request(Method::POST, "10.0.0.1"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[test]
+    fn refill() {
+        let limiter = RateLimiter::new(RateLimitConfig {
+            requests_per_second: 2,
+            burst_size: 2,
+            trusted_proxy_hops: 0,
+        });
+        let client = IpAddr::V4(std::net::Ipv4Addr::LOCALHOST);
+        let start = Instant::now();
+
+        assert!(limiter.check(client, start).is_ok());
+        assert!(limiter.check(client, start).is_ok());
+        assert_eq!(
+            limiter.check(client, start),
+            Err(Duration::from_millis(500))
+        );
+        assert!(limiter
+            .check(client, start + Duration::from_millis(500))
+            .is_ok());
+    }
+
+    #[test]
+    fn untrusted_forwarded_for() {
+        let connected = |forwarded_for| {
+            let mut request = request(Method::GET, forwarded_for);
+            request
+                .extensions_mut()
+                .insert(ConnectInfo(SocketAddr::from(([10, 0, 0, 9], 443))));
+            request
+        };
+        let address = |octet| Some(IpAddr::from([10, 0, 0, octet]));
+
+        // Without trusted proxies the header is ignored
+        let limiter = RateLimiter::new(RateLimitConfig::default());
+        assert_eq!(limiter.client_address(&connected("10.0.0.1")), address(9));
+
+        // Entries to the left of those appended by trusted proxies are chosen by the client
+        let limiter = RateLimiter::new(RateLimitConfig {
+            trusted_proxy_hops: 2,
+            ..Default::default()
+        });
+        assert_eq!(
+            limiter.client_address(&connected("10.0.0.1, 10.0.0.2, 10.0.0.3")),
+            address(2)
+        );
+        // Requests which didn't pass through all the proxies fall back to the connection
+        assert_eq!(limiter.client_address(&connected("10.0.0.1")), address(9));
+        assert_eq!(
+            limiter.client_address(&connected("not-an-address")),
+            address(9)
+        );
+
+        // There's no address at all to identify the client by without a connection
+        assert_eq!(
+            limiter.client_address(&request(Method::GET, "10.0.0.1")),
+            None
+        );
+    }
+
+    #[test]
+    fn bounded_clients() {
+        let limiter = RateLimiter::new(RateLimitConfig::default());
+        let start = Instant::now();
+
+        for i in 0..MAX_TRACKED_CLIENTS as u32 + 10 {
+            assert!(limiter.check(IpAddr::from(i.to_be_bytes()), start).is_ok());
+        }
+        assert_eq!(limiter.buckets.lock().unwrap().len(), MAX_TRACKED_CLIENTS);
+    }
+}
@@ -1,10 +1,12 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use sui_sdk2::types::{EpochId, ValidatorCommittee};
+use sui_sdk2::types::{CheckpointSequenceNumber, EpochId, ValidatorCommittee};
 use sui_sdk2::types::{Object, ObjectId, Version};
+use sui_types::base_types::ObjectID;
 use sui_types::storage::error::{Error as StorageError, Result};
 use sui_types::storage::ObjectStore;
 use sui_types::storage::RestStateReader;
@@ -23,6 +25,28 @@ impl StateReader {
         &self.inner
     }
 
+    /// Run `read` on the blocking thread pool, as reads of the state are synchronous.
+    ///
+    /// Handlers read through this so that the task serving their request isn't blocked while a
+    /// read is in progress, leaving it free to respond once the request times out. A read can't be
+    /// interrupted though, so one whose request timed out still runs to completion.
+    pub async fn spawn_read<T, F>(&self, read: F) -> T
+    where
+        F: FnOnce(&Self) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let reader = self.clone();
+        let span = tracing::Span::current();
+
+        match tokio::task::spawn_blocking(move || span.in_scope(|| read(&reader))).await {
+            Ok(output) => output,
+            // Let a panicking read fail the request as if it had been made on this task
+            Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+            // Blocking tasks are only cancelled when the runtime shuts down
+            Err(e) => panic!("state read was cancelled: {e}"),
+        }
+    }
+
     #[allow(unused)]
     pub fn get_object(&self, object_id: ObjectId) -> Result<Option<Object>> {
         self.inner
@@ -47,13 +71,837 @@ impl StateReader {
             .map(|maybe| maybe.map(|committee| (*committee).clone().into()))
     }
 
-    pub fn get_system_state_summary(&self) -> Result<super::system::SystemStateSummary> {
+    pub fn get_system_state_summary(
+        &self,
+    ) -> Result<super::system::SystemStateSummary, crate::RestError> {
         use sui_types::sui_system_state::SuiSystemStateTrait;
 
-        let system_state = sui_types::sui_system_state::get_sui_system_state(self.inner())
-            .map_err(StorageError::custom)?;
-        let summary = system_state.into_sui_system_state_summary().into();
+        let system_state = self.read_objects(sui_types::sui_system_state::get_sui_system_state)?;
+        let summary = system_state.into_sui_system_state_summary().try_into()?;
 
         Ok(summary)
     }
+
+    /// Read the current system state summary like [`Self::get_system_state_summary`], but leaving
+    /// out active validators which can't be parsed rather than failing.
+    ///
+    /// Returns the addresses of the validators which were left out along with the summary.
+    pub fn get_system_state_summary_skipping_malformed_validators(
+        &self,
+    ) -> Result<
+        (
+            super::system::SystemStateSummary,
+            Vec<sui_sdk2::types::Address>,
+        ),
+        crate::RestError,
+    > {
+        use sui_types::sui_system_state::SuiSystemStateTrait;
+
+        let system_state = self.read_objects(sui_types::sui_system_state::get_sui_system_state)?;
+
+        Ok(
+            super::system::SystemStateSummary::skipping_malformed_validators(
+                system_state.into_sui_system_state_summary(),
+            ),
+        )
+    }
+
+    /// Reconstruct the system state summary as it was at the start of `epoch`.
+    ///
+    /// This is the state produced by the end-of-epoch checkpoint of the preceding epoch (or the
+    /// genesis checkpoint for epoch 0). Returns `None` if that checkpoint, or the objects it
+    /// wrote, have been pruned.
+    pub fn get_system_state_summary_at_epoch(
+        &self,
+        epoch: EpochId,
+    ) -> Result<Option<super::system::SystemStateSummary>, crate::RestError> {
+        use sui_types::sui_system_state::SuiSystemStateTrait;
+
+        let Some(checkpoint) = self.find_epoch_start_checkpoint(epoch)? else {
+            return Ok(None);
+        };
+        let Some(checkpoint) = self.inner.get_checkpoint_by_sequence_number(checkpoint)? else {
+            return Ok(None);
+        };
+        let Some(contents) = self
+            .inner
+            .get_checkpoint_contents_by_digest(&checkpoint.content_digest)?
+        else {
+            return Ok(None);
+        };
+        let checkpoint_data = self.inner.get_checkpoint_data(checkpoint, contents)?;
+
+        let objects = CheckpointObjects(
+            checkpoint_data
+                .output_objects()
+                .into_iter()
+                .map(|object| (object.id(), object.clone()))
+                .collect(),
+        );
+        let system_state = sui_types::sui_system_state::get_sui_system_state(&objects)
+            .map_err(StorageError::custom)?;
+
+        Ok(Some(
+            system_state.into_sui_system_state_summary().try_into()?,
+        ))
+    }
+
+    /// Find the checkpoint which wrote the system state `epoch` started with.
+    fn find_epoch_start_checkpoint(
+        &self,
+        epoch: EpochId,
+    ) -> Result<Option<CheckpointSequenceNumber>> {
+        let lowest = self.inner.get_lowest_available_checkpoint_objects()?;
+        if epoch == 0 {
+            return Ok((lowest == 0).then_some(0));
+        }
+
+        let checkpoint_epoch = |sequence_number| {
+            self.inner
+                .get_checkpoint_by_sequence_number(sequence_number)?
+                .map(|checkpoint| checkpoint.epoch())
+                .ok_or_else(|| {
+                    StorageError::missing(format!("missing checkpoint {sequence_number}"))
+                })
+        };
+
+        // Binary search for the last checkpoint of the preceding epoch, which is the last
+        // checkpoint with an epoch lower than `epoch`
+        let (mut low, mut high) = (lowest, self.inner.get_latest_checkpoint_sequence_number()?);
+        if checkpoint_epoch(low)? >= epoch {
+            return Ok(None);
+        }
+        while low < high {
+            let mid = low + (high - low + 1) / 2;
+            if checkpoint_epoch(mid)? < epoch {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+
+        let is_epoch_end = self
+            .inner
+            .get_checkpoint_by_sequence_number(low)?
+            .is_some_and(|checkpoint| {
+                checkpoint.epoch() + 1 == epoch && checkpoint.is_last_checkpoint_of_epoch()
+            });
+
+        Ok(is_epoch_end.then_some(low))
+    }
+
+    /// Fetch the dynamic field object holding the inner system state, along with the version of
+    /// the inner system state it holds, e.g. `2` for `SuiSystemStateInnerV2`.
+    pub fn get_system_state_inner_object(
+        &self,
+    ) -> Result<Option<(u64, sui_types::object::Object)>> {
+        use sui_types::sui_system_state::SuiSystemStateWrapper;
+
+        let Some(wrapper) = self
+            .inner
+            .get_object(&sui_types::SUI_SYSTEM_STATE_OBJECT_ID)?
+        else {
+            return Ok(None);
+        };
+        let wrapper: SuiSystemStateWrapper = wrapper
+            .data
+            .try_as_move()
+            .ok_or_else(|| StorageError::custom("system state wrapper must be a Move object"))
+            .and_then(|object| {
+                bcs::from_bytes(object.contents()).map_err(StorageError::serialization)
+            })?;
+
+        let field_id = sui_types::dynamic_field::derive_dynamic_field_id(
+            wrapper.id.id.bytes,
+            &sui_types::TypeTag::U64,
+            &bcs::to_bytes(&wrapper.version).map_err(StorageError::serialization)?,
+        )
+        .map_err(StorageError::serialization)?;
+
+        Ok(self
+            .inner
+            .get_object(&field_id)?
+            .map(|object| (wrapper.version, object)))
+    }
+
+    /// Resolve the validators which will join the active set at the end of the current epoch.
+    pub fn get_pending_active_validators(&self) -> Result<Vec<super::system::ValidatorSummary>> {
+        use sui_types::sui_system_state::SuiSystemStateTrait;
+
+        let validators = self
+            .read_objects(|store| {
+                sui_types::sui_system_state::get_sui_system_state(store)?
+                    .get_pending_active_validators(store)
+            })?
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()
+            .map_err(StorageError::custom)?;
+
+        Ok(validators)
+    }
+
+    /// Fetch the exchange rates of a staking pool for each of the given epochs, as the number of
+    /// pool tokens per SUI.
+    ///
+    /// Epochs without an entry in the `exchange_rates_id` table, for example because the pool
+    /// wasn't active yet, are omitted, while failing to read an entry which does exist is an error.
+    pub fn get_pool_exchange_rates(
+        &self,
+        exchange_rates_id: ObjectId,
+        epochs: std::ops::RangeInclusive<EpochId>,
+    ) -> Result<Vec<(EpochId, f64)>> {
+        use sui_types::sui_system_state::PoolTokenExchangeRate;
+
+        let mut exchange_rates = Vec::new();
+        for epoch in epochs {
+            if !self.has_table_entry(exchange_rates_id, &epoch)? {
+                continue;
+            }
+
+            let rate: PoolTokenExchangeRate = self.read_objects(|store| {
+                sui_types::dynamic_field::get_dynamic_field_from_store(
+                    store,
+                    exchange_rates_id.into(),
+                    &epoch,
+                )
+            })?;
+            exchange_rates.push((epoch, rate.rate()));
+        }
+
+        Ok(exchange_rates)
+    }
+
+    /// Look up the address of the validator owning the staking pool `staking_pool_id` in the
+    /// `staking_pool_mappings_id` table, which covers active and pending validators.
+    pub fn get_staking_pool_validator_address(
+        &self,
+        staking_pool_mappings_id: ObjectId,
+        staking_pool_id: ObjectId,
+    ) -> Result<Option<sui_sdk2::types::Address>> {
+        use sui_types::base_types::SuiAddress;
+        use sui_types::id::ID;
+
+        let key = ID::new(staking_pool_id.into());
+        if !self.has_table_entry(staking_pool_mappings_id, &key)? {
+            return Ok(None);
+        }
+
+        let address: SuiAddress = self.read_objects(|store| {
+            sui_types::dynamic_field::get_dynamic_field_from_store(
+                store,
+                staking_pool_mappings_id.into(),
+                &key,
+            )
+        })?;
+
+        Ok(Some(address.into()))
+    }
+
+    /// Look up the validator which owned the staking pool `staking_pool_id` in the
+    /// `inactive_pools_id` table.
+    pub fn get_inactive_pool_validator(
+        &self,
+        inactive_pools_id: ObjectId,
+        staking_pool_id: ObjectId,
+    ) -> Result<Option<super::system::ValidatorSummary>> {
+        use sui_types::id::ID;
+
+        let key = ID::new(staking_pool_id.into());
+        if !self.has_table_entry(inactive_pools_id, &key)? {
+            return Ok(None);
+        }
+
+        let validator = self.read_objects(|store| {
+            sui_types::sui_system_state::get_validator_from_table(
+                store,
+                inactive_pools_id.into(),
+                &key,
+            )
+        })?;
+
+        validator.try_into().map(Some).map_err(StorageError::custom)
+    }
+
+    /// Whether the table `table_id` has an entry for `key`.
+    fn has_table_entry<K>(&self, table_id: ObjectId, key: &K) -> Result<bool>
+    where
+        K: sui_types::MoveTypeTagTrait + serde::Serialize,
+    {
+        let field_id = sui_types::dynamic_field::derive_dynamic_field_id(
+            ObjectID::from(table_id),
+            &K::get_type_tag(),
+            &bcs::to_bytes(key).map_err(StorageError::serialization)?,
+        )
+        .map_err(StorageError::serialization)?;
+
+        Ok(self.inner.get_object(&field_id)?.is_some())
+    }
+
+    /// Fetch a page of the inactive staking pools stored in the table `inactive_pools_id`.
+    ///
+    /// Each entry is keyed by the object id of its dynamic field in the table, which is what
+    /// `cursor` refers to.
+    pub fn get_inactive_pools(
+        &self,
+        inactive_pools_id: ObjectId,
+        cursor: Option<ObjectId>,
+        limit: usize,
+    ) -> Result<Vec<(ObjectId, super::system::InactivePool)>> {
+        use sui_types::id::ID;
+
+        self.inner
+            .get_dynamic_fields(inactive_pools_id.into(), cursor.map(Into::into), limit)?
+            .into_iter()
+            .map(|(field_id, info)| {
+                let staking_pool_id: ObjectID =
+                    bcs::from_bytes(&info.bcs_name).map_err(StorageError::serialization)?;
+                let validator = self.read_objects(|store| {
+                    sui_types::sui_system_state::get_validator_from_table(
+                        store,
+                        inactive_pools_id.into(),
+                        &ID::new(staking_pool_id),
+                    )
+                })?;
+
+                Ok((
+                    field_id.into(),
+                    super::system::InactivePool {
+                        staking_pool_id: staking_pool_id.into(),
+                        validator: validator.try_into().map_err(StorageError::custom)?,
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    /// Fetch a page of the preactive validators stored in the table `validator_candidates_id`.
+    ///
+    /// Like [`Self::get_inactive_pools`], each entry is keyed by the object id of its dynamic
+    /// field in the table, which is what `cursor` refers to.
+    pub fn get_validator_candidates(
+        &self,
+        validator_candidates_id: ObjectId,
+        cursor: Option<ObjectId>,
+        limit: usize,
+    ) -> Result<Vec<(ObjectId, super::system::ValidatorSummary)>> {
+        use sui_types::base_types::SuiAddress;
+
+        self.inner
+            .get_dynamic_fields(
+                validator_candidates_id.into(),
+                cursor.map(Into::into),
+                limit,
+            )?
+            .into_iter()
+            .map(|(field_id, info)| {
+                let address: SuiAddress =
+                    bcs::from_bytes(&info.bcs_name).map_err(StorageError::serialization)?;
+                let validator = self.read_objects(|store| {
+                    sui_types::sui_system_state::get_validator_from_table(
+                        store,
+                        validator_candidates_id.into(),
+                        &address,
+                    )
+                })?;
+
+                Ok((
+                    field_id.into(),
+                    validator.try_into().map_err(StorageError::custom)?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Whether `field_id` is a dynamic field of the table `table_id`, e.g. to check that a
+    /// pagination cursor refers to an entry of the table being paginated.
+    pub fn is_table_field(&self, table_id: ObjectId, field_id: ObjectId) -> Result<bool> {
+        use sui_types::object::Owner;
+
+        let table_owner = Owner::ObjectOwner(ObjectID::from(table_id).into());
+
+        Ok(self
+            .inner
+            .get_object(&field_id.into())?
+            .is_some_and(|object| *object.owner() == table_owner))
+    }
+
+    /// Run `read`, one of the `sui_system_state` or `dynamic_field` functions which report
+    /// failures of the store as a [`SuiError`](sui_types::error::SuiError), against the store.
+    ///
+    /// If the store itself failed, its error is returned rather than the `SuiError`, which would
+    /// lose its kind, so that e.g. a transient database error is still reported as such.
+    fn read_objects<T>(
+        &self,
+        read: impl FnOnce(&dyn ObjectStore) -> Result<T, sui_types::error::SuiError>,
+    ) -> Result<T> {
+        let store = ErrorRecordingStore {
+            inner: self.inner(),
+            error: Default::default(),
+        };
+
+        read(&store).map_err(|error| {
+            store
+                .error
+                .take()
+                .unwrap_or_else(|| StorageError::custom(error))
+        })
+    }
+}
+
+/// An [`ObjectStore`] keeping the first error returned by the store it wraps.
+struct ErrorRecordingStore<'a> {
+    inner: &'a dyn ObjectStore,
+    error: std::cell::Cell<Option<StorageError>>,
+}
+
+impl ErrorRecordingStore<'_> {
+    fn record<T>(&self, result: Result<T>) -> Result<T> {
+        result.map_err(|error| {
+            // The caller only sees the error's message, the original is kept to be returned
+            let message = error.to_string();
+            let first = self.error.take().unwrap_or(error);
+            self.error.set(Some(first));
+            StorageError::custom(message)
+        })
+    }
+}
+
+impl ObjectStore for ErrorRecordingStore<'_> {
+    fn get_object(&self, object_id: &ObjectID) -> Result<Option<sui_types::object::Object>> {
+        self.record(self.inner.get_object(object_id))
+    }
+
+    fn get_object_by_key(
+        &self,
+        object_id: &ObjectID,
+        version: sui_types::base_types::VersionNumber,
+    ) -> Result<Option<sui_types::object::Object>> {
+        self.record(self.inner.get_object_by_key(object_id, version))
+    }
+}
+
+/// The objects written by a checkpoint, used to read the system state as of that checkpoint.
+struct CheckpointObjects(HashMap<ObjectID, sui_types::object::Object>);
+
+impl ObjectStore for CheckpointObjects {
+    fn get_object(&self, object_id: &ObjectID) -> Result<Option<sui_types::object::Object>> {
+        Ok(self.0.get(object_id).cloned())
+    }
+
+    fn get_object_by_key(
+        &self,
+        object_id: &ObjectID,
+        version: sui_types::base_types::VersionNumber,
+    ) -> Result<Option<sui_types::object::Object>> {
+        Ok(self
+            .0
+            .get(object_id)
+            .filter(|object| object.version() == version)
+            .cloned())
+    }
+}
+
+/// A failure reading from the [`StateReader`], classified by how a client should react to it.
+#[derive(Debug)]
+pub enum StateReaderError {
+    /// The requested data doesn't exist, or has already been pruned. Responds with `404`.
+    NotFound(StorageError),
+    /// The store can't be read at the moment, but may be soon, so the request can be retried.
+    /// Responds with `503`.
+    Unavailable(StorageError),
+    /// Any other failure, for example data which can't be deserialized. Responds with `500`.
+    Internal(StorageError),
+}
+
+impl StateReaderError {
+    pub fn status(&self) -> axum::http::StatusCode {
+        use axum::http::StatusCode;
+
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Unavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+            Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl std::fmt::Display for StateReaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound(e) | Self::Unavailable(e) | Self::Internal(e) => {
+                std::fmt::Display::fmt(e, f)
+            }
+        }
+    }
+}
+
+impl std::error::Error for StateReaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::NotFound(e) | Self::Unavailable(e) | Self::Internal(e) => Some(e),
+        }
+    }
+}
+
+impl From<StorageError> for StateReaderError {
+    fn from(error: StorageError) -> Self {
+        use std::error::Error;
+        use sui_types::storage::error::Kind;
+        use typed_store_error::TypedStoreError;
+
+        if error.kind() == Kind::Missing {
+            return Self::NotFound(error);
+        }
+
+        // Database errors, rather than errors in what was read from it, are transient
+        let transient = matches!(
+            error
+                .source()
+                .and_then(|source| source.downcast_ref::<TypedStoreError>()),
+            Some(TypedStoreError::RocksDBError(_) | TypedStoreError::RetryableTransactionError)
+        );
+        if transient {
+            Self::Unavailable(error)
+        } else {
+            Self::Internal(error)
+        }
+    }
+}
+
+impl From<StateReaderError> for crate::RestError {
+    fn from(error: StateReaderError) -> Self {
+        Self::new(error.status(), error.to_string())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use sui_types::base_types::TransactionDigest;
+    use sui_types::committee::Committee;
+    use sui_types::digests::{
+        ChainIdentifier, CheckpointContentsDigest, CheckpointDigest, TransactionEventsDigest,
+    };
+    use sui_types::dynamic_field::{DynamicFieldInfo, Field};
+    use sui_types::effects::{TransactionEffects, TransactionEvents};
+    use sui_types::id::UID;
+    use sui_types::messages_checkpoint::{
+        CertifiedCheckpointSummary, CheckpointContents, CheckpointSequenceNumber,
+        CheckpointSummary, FullCheckpointContents, VerifiedCheckpoint,
+    };
+    use sui_types::object::{MoveObject, Owner};
+    use sui_types::storage::ReadStore;
+    use sui_types::sui_system_state::SuiSystemStateWrapper;
+    use sui_types::transaction::VerifiedTransaction;
+    use sui_types::SUI_SYSTEM_STATE_OBJECT_ID;
+    use tower::ServiceExt;
+    use typed_store_error::TypedStoreError;
+
+    /// An in-memory [`RestStateReader`] holding the system state object, with the inner state of
+    /// [`crate::system::tests::test_inner_state`], and a single genesis checkpoint.
+    pub(crate) struct TestReader {
+        objects: HashMap<ObjectID, sui_types::object::Object>,
+        checkpoint: VerifiedCheckpoint,
+        failure: Option<fn() -> StorageError>,
+        delay: std::time::Duration,
+    }
+
+    impl TestReader {
+        pub(crate) fn new() -> Self {
+            let inner = crate::system::tests::test_inner_state();
+            let wrapper = SuiSystemStateWrapper {
+                id: UID::new(SUI_SYSTEM_STATE_OBJECT_ID),
+                version: inner.system_state_version,
+            };
+            let field_id = sui_types::dynamic_field::derive_dynamic_field_id(
+                SUI_SYSTEM_STATE_OBJECT_ID,
+                &sui_types::TypeTag::U64,
+                &bcs::to_bytes(&wrapper.version).unwrap(),
+            )
+            .unwrap();
+            let field = Field {
+                id: UID::new(field_id),
+                name: wrapper.version,
+                value: inner,
+            };
+
+            let field_type = DynamicFieldInfo::dynamic_field_type(
+                sui_types::TypeTag::U64,
+                sui_types::parse_sui_type_tag("0x3::sui_system_state_inner::SuiSystemStateInnerV2")
+                    .unwrap(),
+            );
+            let objects = [
+                test_object(
+                    SuiSystemStateWrapper::type_().into(),
+                    Owner::Shared {
+                        initial_shared_version: 1.into(),
+                    },
+                    &wrapper,
+                ),
+                test_object(
+                    field_type.into(),
+                    Owner::ObjectOwner(SUI_SYSTEM_STATE_OBJECT_ID.into()),
+                    &field,
+                ),
+            ]
+            .into_iter()
+            .map(|object| (object.id(), object))
+            .collect();
+
+            let (committee, keys) = Committee::new_simple_test_committee();
+            let summary = CheckpointSummary {
+                epoch: committee.epoch,
+                sequence_number: 0,
+                network_total_transactions: 0,
+                content_digest: CheckpointContentsDigest::new([0; 32]),
+                previous_digest: None,
+                epoch_rolling_gas_cost_summary: Default::default(),
+                timestamp_ms: 1_700_000_000_000,
+                checkpoint_commitments: vec![],
+                end_of_epoch_data: None,
+                version_specific_data: vec![],
+            };
+            let checkpoint = VerifiedCheckpoint::new_unchecked(
+                CertifiedCheckpointSummary::new_from_keypairs_for_testing(
+                    summary, &keys, &committee,
+                ),
+            );
+
+            Self {
+                objects,
+                checkpoint,
+                failure: None,
+                delay: std::time::Duration::ZERO,
+            }
+        }
+
+        /// Fail every read of an object, or of a checkpoint other than the latest one, with the
+        /// error returned by `failure`.
+        ///
+        /// The latest checkpoint is still served, as every response reports it in its headers.
+        pub(crate) fn failing(mut self, failure: fn() -> StorageError) -> Self {
+            self.failure = Some(failure);
+            self
+        }
+
+        /// Block the thread for `delay` on every read of an object, or of a checkpoint other than
+        /// the latest one, before responding.
+        pub(crate) fn slow(mut self, delay: std::time::Duration) -> Self {
+            self.delay = delay;
+            self
+        }
+
+        fn read<T>(&self, value: impl FnOnce() -> T) -> Result<T> {
+            std::thread::sleep(self.delay);
+            match self.failure {
+                Some(failure) => Err(failure()),
+                None => Ok(value()),
+            }
+        }
+    }
+
+    /// A Move object of type `type_` holding the BCS bytes of `contents`, which start with its id.
+    fn test_object<T: serde::Serialize>(
+        type_: sui_types::base_types::MoveObjectType,
+        owner: Owner,
+        contents: &T,
+    ) -> sui_types::object::Object {
+        // Safety: none of the objects served by `TestReader` are coins
+        let object = unsafe {
+            MoveObject::new_from_execution_with_limit(
+                type_,
+                false,
+                1.into(),
+                bcs::to_bytes(contents).unwrap(),
+                u64::MAX,
+            )
+        }
+        .unwrap();
+
+        sui_types::object::Object::new_move(object, owner, TransactionDigest::genesis_marker())
+    }
+
+    impl ObjectStore for TestReader {
+        fn get_object(&self, object_id: &ObjectID) -> Result<Option<sui_types::object::Object>> {
+            self.read(|| self.objects.get(object_id).cloned())
+        }
+
+        fn get_object_by_key(
+            &self,
+            object_id: &ObjectID,
+            version: sui_types::base_types::VersionNumber,
+        ) -> Result<Option<sui_types::object::Object>> {
+            Ok(self
+                .get_object(object_id)?
+                .filter(|object| object.version() == version))
+        }
+    }
+
+    impl ReadStore for TestReader {
+        fn get_committee(&self, _epoch: EpochId) -> Result<Option<Arc<Committee>>> {
+            unimplemented!()
+        }
+
+        fn get_latest_checkpoint(&self) -> Result<VerifiedCheckpoint> {
+            Ok(self.checkpoint.clone())
+        }
+
+        fn get_highest_verified_checkpoint(&self) -> Result<VerifiedCheckpoint> {
+            self.get_latest_checkpoint()
+        }
+
+        fn get_highest_synced_checkpoint(&self) -> Result<VerifiedCheckpoint> {
+            self.get_latest_checkpoint()
+        }
+
+        fn get_lowest_available_checkpoint(&self) -> Result<CheckpointSequenceNumber> {
+            Ok(0)
+        }
+
+        fn get_checkpoint_by_digest(
+            &self,
+            digest: &CheckpointDigest,
+        ) -> Result<Option<VerifiedCheckpoint>> {
+            self.read(|| {
+                Some(self.checkpoint.clone()).filter(|checkpoint| checkpoint.digest() == digest)
+            })
+        }
+
+        fn get_checkpoint_by_sequence_number(
+            &self,
+            sequence_number: CheckpointSequenceNumber,
+        ) -> Result<Option<VerifiedCheckpoint>> {
+            self.read(|| {
+                Some(self.checkpoint.clone())
+                    .filter(|checkpoint| *checkpoint.sequence_number() == sequence_number)
+            })
+        }
+
+        fn get_checkpoint_contents_by_digest(
+            &self,
+            _digest: &CheckpointContentsDigest,
+        ) -> Result<Option<CheckpointContents>> {
+            unimplemented!()
+        }
+
+        fn get_checkpoint_contents_by_sequence_number(
+            &self,
+            _sequence_number: CheckpointSequenceNumber,
+        ) -> Result<Option<CheckpointContents>> {
+            unimplemented!()
+        }
+
+        fn get_transaction(
+            &self,
+            _tx_digest: &TransactionDigest,
+        ) -> Result<Option<Arc<VerifiedTransaction>>> {
+            unimplemented!()
+        }
+
+        fn get_transaction_effects(
+            &self,
+            _tx_digest: &TransactionDigest,
+        ) -> Result<Option<TransactionEffects>> {
+            unimplemented!()
+        }
+
+        fn get_events(
+            &self,
+            _event_digest: &TransactionEventsDigest,
+        ) -> Result<Option<TransactionEvents>> {
+            unimplemented!()
+        }
+
+        fn get_full_checkpoint_contents_by_sequence_number(
+            &self,
+            _sequence_number: CheckpointSequenceNumber,
+        ) -> Result<Option<FullCheckpointContents>> {
+            unimplemented!()
+        }
+
+        fn get_full_checkpoint_contents(
+            &self,
+            _digest: &CheckpointContentsDigest,
+        ) -> Result<Option<FullCheckpointContents>> {
+            unimplemented!()
+        }
+    }
+
+    impl RestStateReader for TestReader {
+        fn get_transaction_checkpoint(
+            &self,
+            _digest: &TransactionDigest,
+        ) -> Result<Option<CheckpointSequenceNumber>> {
+            unimplemented!()
+        }
+
+        fn get_lowest_available_checkpoint_objects(&self) -> Result<CheckpointSequenceNumber> {
+            Ok(0)
+        }
+
+        fn get_chain_identifier(&self) -> Result<ChainIdentifier> {
+            Ok(CheckpointDigest::new([1; 32]).into())
+        }
+
+        fn get_dynamic_fields(
+            &self,
+            _parent: ObjectID,
+            _cursor: Option<ObjectID>,
+            _limit: usize,
+        ) -> Result<Vec<(ObjectID, DynamicFieldInfo)>> {
+            unimplemented!()
+        }
+    }
+
+    #[tokio::test]
+    async fn error_status_codes() {
+        let failures: [(fn() -> StorageError, StatusCode); 3] = [
+            (|| StorageError::missing("pruned"), StatusCode::NOT_FOUND),
+            (
+                || TypedStoreError::RocksDBError("busy".to_owned()).into(),
+                StatusCode::SERVICE_UNAVAILABLE,
+            ),
+            (
+                || StorageError::serialization("invalid object"),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+        ];
+
+        // Endpoints reading the system state, through functions reporting the failure as a
+        // `SuiError`, as well as those reading from the store directly
+        let uris = [
+            "/system".to_owned(),
+            "/system/epoch".to_owned(),
+            "/system/validators".to_owned(),
+            "/system/total-stake".to_owned(),
+            format!("/objects/{SUI_SYSTEM_STATE_OBJECT_ID}"),
+            "/checkpoints/0".to_owned(),
+        ];
+
+        for (failure, status) in failures {
+            let app = crate::RestService::new_without_version(Arc::new(
+                TestReader::new().failing(failure),
+            ))
+            .into_router();
+
+            for uri in &uris {
+                let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+                let response = app.clone().oneshot(request).await.unwrap();
+                assert_eq!(response.status(), status, "{uri}");
+
+                let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+                let problem: crate::ProblemDetails = serde_json::from_slice(&body).unwrap();
+                assert_eq!(problem.status, status.as_u16());
+            }
+        }
+
+        // Errors other than those of the database itself aren't worth retrying
+        let error = StateReaderError::from(StorageError::custom("invalid dynamic field"));
+        assert!(matches!(error, StateReaderError::Internal(_)));
+    }
 }
@@ -0,0 +1,113 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::{
+    extract::OriginalUri,
+    http::{HeaderValue, Request},
+    middleware::Next,
+    response::Response,
+};
+use tracing::Instrument;
+
+use crate::types::X_REQUEST_ID;
+
+tokio::task_local! {
+    static REQUEST: RequestContext;
+}
+
+/// Identifying details of a request, made available to error responses.
+#[derive(Clone, Debug)]
+pub(crate) struct RequestContext {
+    pub id: String,
+    pub path: String,
+}
+
+/// Context of the request currently being served, if any.
+pub(crate) fn current_request() -> Option<RequestContext> {
+    REQUEST.try_with(Clone::clone).ok()
+}
+
+/// Tag each request with an id, used to correlate logs across services.
+///
+/// The id provided by the client in the `x-request-id` header is used if present, otherwise a new
+/// one is generated. Either way it is echoed back in the `x-request-id` response header.
+pub async fn request_id<B>(request: Request<B>, next: Next<B>) -> Response {
+    let request_id = request
+        .headers()
+        .get(X_REQUEST_ID)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| !value.is_empty())
+        .map(ToOwned::to_owned)
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+    // Use the path as requested by the client, before any nesting prefix was stripped
+    let path = request
+        .extensions()
+        .get::<OriginalUri>()
+        .map(|uri| uri.path().to_owned())
+        .unwrap_or_else(|| request.uri().path().to_owned());
+    let context = RequestContext {
+        id: request_id.clone(),
+        path,
+    };
+
+    let span = tracing::info_span!("request", request_id = %request_id);
+    let mut response = REQUEST
+        .scope(context, next.run(request))
+        .instrument(span)
+        .await;
+
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(X_REQUEST_ID, value);
+    }
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::Body, http::StatusCode, routing::get, Router};
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::RestError;
+
+    fn app() -> Router {
+        Router::new()
+            .route(
+                "/missing",
+                get(|| async { Err::<(), _>(RestError::new(StatusCode::NOT_FOUND, "not found")) }),
+            )
+            .layer(axum::middleware::from_fn(request_id))
+    }
+
+    #[tokio::test]
+    async fn provided_id_is_echoed() {
+        let request = Request::builder()
+            .uri("/missing")
+            .header(X_REQUEST_ID, "my-request-id")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.headers()[X_REQUEST_ID], "my-request-id");
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let problem: crate::error::ProblemDetails = serde_json::from_slice(&body).unwrap();
+        assert_eq!(problem.detail.as_deref(), Some("not found"));
+        assert_eq!(problem.instance.as_deref(), Some("/missing"));
+        assert_eq!(problem.request_id.as_deref(), Some("my-request-id"));
+    }
+
+    #[tokio::test]
+    async fn id_is_generated() {
+        let request = Request::builder()
+            .uri("/missing")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app().oneshot(request).await.unwrap();
+        let request_id = response.headers()[X_REQUEST_ID].to_str().unwrap();
+        assert!(uuid::Uuid::parse_str(request_id).is_ok());
+    }
+}
@@ -15,11 +15,13 @@ use crate::{
         X_SUI_LOWEST_AVAILABLE_CHECKPOINT, X_SUI_LOWEST_AVAILABLE_CHECKPOINT_OBJECTS,
         X_SUI_TIMESTAMP_MS,
     },
-    RestService, APPLICATION_BCS, TEXT_PLAIN_UTF_8,
+    RestService, APPLICATION_BCS, APPLICATION_CBOR, TEXT_PLAIN_UTF_8,
 };
 
 pub struct Bcs<T>(pub T);
 
+pub struct Cbor<T>(pub T);
+
 pub enum ResponseContent<T, J = T> {
     Bcs(T),
     Json(J),
@@ -32,10 +34,52 @@ where
     fn into_response(self) -> axum::response::Response {
         match bcs::to_bytes(&self.0) {
             Ok(buf) => (
+                [
+                    (
+                        axum::http::header::CONTENT_TYPE,
+                        axum::http::HeaderValue::from_static(APPLICATION_BCS),
+                    ),
+                    // Binary bodies are always sent with an explicit length rather than chunked
+                    (
+                        axum::http::header::CONTENT_LENGTH,
+                        axum::http::HeaderValue::from(buf.len()),
+                    ),
+                ],
+                buf,
+            )
+                .into_response(),
+            Err(err) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
                 [(
                     axum::http::header::CONTENT_TYPE,
-                    axum::http::HeaderValue::from_static(APPLICATION_BCS),
+                    axum::http::HeaderValue::from_static(TEXT_PLAIN_UTF_8),
                 )],
+                err.to_string(),
+            )
+                .into_response(),
+        }
+    }
+}
+
+impl<T> axum::response::IntoResponse for Cbor<T>
+where
+    T: serde::Serialize,
+{
+    fn into_response(self) -> axum::response::Response {
+        let mut buf = Vec::new();
+        match ciborium::ser::into_writer(&self.0, &mut buf) {
+            Ok(()) => (
+                [
+                    (
+                        axum::http::header::CONTENT_TYPE,
+                        axum::http::HeaderValue::from_static(APPLICATION_CBOR),
+                    ),
+                    // Binary bodies are always sent with an explicit length rather than chunked
+                    (
+                        axum::http::header::CONTENT_LENGTH,
+                        axum::http::HeaderValue::from(buf.len()),
+                    ),
+                ],
                 buf,
             )
                 .into_response(),
@@ -187,3 +231,30 @@ pub async fn append_info_headers(
 
     (headers, response)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::{test_summary, test_validator};
+    use axum::http::header;
+
+    #[tokio::test]
+    async fn binary_content_length() {
+        let summary = test_summary(vec![test_validator(1), test_validator(2)]);
+        let bytes = bcs::to_bytes(&summary).unwrap();
+
+        let response = Bcs(summary.clone()).into_response();
+        assert_eq!(response.headers()[header::CONTENT_TYPE], APPLICATION_BCS);
+        assert_eq!(
+            response.headers()[header::CONTENT_LENGTH],
+            bytes.len().to_string()
+        );
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        assert_eq!(body.as_ref(), bytes.as_slice());
+
+        let response = Cbor(summary.epoch).into_response();
+        let length = response.headers()[header::CONTENT_LENGTH].clone();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        assert_eq!(length, body.len().to_string());
+    }
+}
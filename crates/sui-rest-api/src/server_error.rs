@@ -0,0 +1,157 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::Any;
+use std::panic::AssertUnwindSafe;
+
+use axum::{
+    body::HttpBody,
+    http::{header, Request, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use futures::FutureExt;
+
+use crate::{RestError, APPLICATION_PROBLEM_JSON};
+
+/// Make sure every server error has a problem document as its body, like those of a
+/// [`RestError`].
+///
+/// A handler which panics, e.g. because of an unexpected failure of the state reader, is answered
+/// with a `500 Internal Server Error` rather than the connection being dropped, and server errors
+/// which didn't come from a `RestError`, such as failures to serialize a JSON body, have their
+/// plain text body moved into the `detail` of a problem document.
+pub async fn structured_server_errors<B>(request: Request<B>, next: Next<B>) -> Response {
+    let response = match AssertUnwindSafe(next.run(request)).catch_unwind().await {
+        Ok(response) => response,
+        Err(panic) => {
+            tracing::error!("request handler panicked: {}", panic_message(&*panic));
+            return RestError::new(StatusCode::INTERNAL_SERVER_ERROR, "internal server error")
+                .into_response();
+        }
+    };
+
+    let is_problem = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .is_some_and(|content_type| content_type == APPLICATION_PROBLEM_JSON);
+    if !response.status().is_server_error() || is_problem {
+        return response;
+    }
+
+    let (parts, mut body) = response.into_parts();
+    let mut detail = Vec::new();
+    while let Some(Ok(chunk)) = body.data().await {
+        detail.extend_from_slice(&chunk);
+    }
+    let detail = String::from_utf8_lossy(&detail);
+    let detail = match detail.trim() {
+        "" => parts.status.canonical_reason().unwrap_or("Unknown Error"),
+        detail => detail,
+    };
+
+    let mut problem = RestError::new(parts.status, detail).into_response();
+    // Keep any other headers, e.g. `Retry-After`, which still apply to the problem document
+    for (name, value) in &parts.headers {
+        if name != header::CONTENT_TYPE && name != header::CONTENT_LENGTH {
+            problem
+                .headers_mut()
+                .entry(name)
+                .or_insert_with(|| value.clone());
+        }
+    }
+
+    problem
+}
+
+fn panic_message(panic: &(dyn Any + Send)) -> &str {
+    panic
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic")
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::Body, http::HeaderMap, routing::get, Router};
+    use sui_types::storage::error::Error as StorageError;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::ProblemDetails;
+
+    /// A state reader failing in a way its caller doesn't expect.
+    fn read_system_state() -> Result<(), StorageError> {
+        Err(StorageError::custom("database is corrupt"))
+    }
+
+    fn app() -> Router {
+        Router::new()
+            .route(
+                "/panic",
+                get(|| async {
+                    read_system_state().expect("system state is readable");
+                }),
+            )
+            .route(
+                "/plain",
+                get(|| async { (StatusCode::INTERNAL_SERVER_ERROR, "malformed summary") }),
+            )
+            .route(
+                "/unavailable",
+                get(|| async {
+                    (
+                        StatusCode::SERVICE_UNAVAILABLE,
+                        [(header::RETRY_AFTER, "5")],
+                    )
+                }),
+            )
+            .route(
+                "/missing",
+                get(|| async { Err::<(), _>(RestError::new(StatusCode::NOT_FOUND, "not found")) }),
+            )
+            .layer(axum::middleware::from_fn(structured_server_errors))
+    }
+
+    async fn get_problem(uri: &str) -> (StatusCode, HeaderMap, ProblemDetails) {
+        let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+        let response = app().oneshot(request).await.unwrap();
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            APPLICATION_PROBLEM_JSON
+        );
+
+        let (parts, body) = response.into_parts();
+        let body = hyper::body::to_bytes(body).await.unwrap();
+        let problem = serde_json::from_slice(&body).unwrap();
+
+        (parts.status, parts.headers, problem)
+    }
+
+    #[tokio::test]
+    async fn server_errors_are_problems() {
+        let (status, _, problem) = get_problem("/panic").await;
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(problem.status, 500);
+        assert_eq!(problem.title, "Internal Server Error");
+        // The panic message isn't leaked to the client
+        assert_eq!(problem.detail.as_deref(), Some("internal server error"));
+
+        let (status, _, problem) = get_problem("/plain").await;
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(problem.status, 500);
+        assert_eq!(problem.detail.as_deref(), Some("malformed summary"));
+
+        let (status, headers, problem) = get_problem("/unavailable").await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(headers[header::RETRY_AFTER], "5");
+        assert_eq!(problem.status, 503);
+        assert_eq!(problem.detail.as_deref(), Some("Service Unavailable"));
+
+        // Errors which are already problem documents are left alone
+        let (status, _, problem) = get_problem("/missing").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(problem.detail.as_deref(), Some("not found"));
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::reader::StateReader;
+use axum::{extract::State, Json};
+use sui_types::digests::ChainIdentifier;
+
+use super::SystemStateSummary;
+
+pub const GET_BOOTSTRAP_PATH: &str = "/system/bootstrap";
+
+/// Everything a new client needs to get started, in a single round-trip.
+///
+/// A node which can't read its system state or chain identifier, or hasn't executed any
+/// checkpoints, responds with `ready: false` and the reason rather than an error.
+pub async fn get_bootstrap(State(state): State<StateReader>) -> Json<BootstrapInfo> {
+    Json(state.spawn_read(bootstrap_info).await)
+}
+
+fn bootstrap_info(state: &StateReader) -> BootstrapInfo {
+    let Ok(summary) = state.get_system_state_summary() else {
+        return BootstrapInfo::default().not_ready("unable to read the system state");
+    };
+    let chain_identifier = match state.inner().get_chain_identifier() {
+        Ok(chain_identifier) => chain_identifier,
+        Err(e) => {
+            return BootstrapInfo::default()
+                .not_ready(format!("unable to read the chain identifier: {e}"))
+        }
+    };
+
+    let info = BootstrapInfo::new(&summary, chain_identifier);
+    match state.inner().get_latest_checkpoint() {
+        Ok(_) => info,
+        Err(e) => info.not_ready(format!("unable to read the latest checkpoint: {e}")),
+    }
+}
+
+/// Node readiness along with the current epoch information.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BootstrapInfo {
+    /// Whether the node has executed checkpoints and is able to serve requests.
+    pub ready: bool,
+    /// Why the node isn't ready, if it isn't.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// The epoch information below is `None` if the system state or chain identifier can't be
+    /// read.
+    #[serde_as(as = "Option<sui_types::sui_serde::BigInt<u64>>")]
+    pub epoch: Option<u64>,
+    #[serde_as(as = "Option<sui_types::sui_serde::BigInt<u64>>")]
+    pub protocol_version: Option<u64>,
+    #[serde_as(as = "Option<sui_types::sui_serde::BigInt<u64>>")]
+    pub reference_gas_price: Option<u64>,
+    /// Identifier of the chain, the first four bytes of the genesis checkpoint digest in hex.
+    pub chain_identifier: Option<String>,
+}
+
+impl BootstrapInfo {
+    pub fn new(summary: &SystemStateSummary, chain_identifier: ChainIdentifier) -> Self {
+        Self {
+            ready: true,
+            reason: None,
+            epoch: Some(summary.epoch),
+            protocol_version: Some(summary.protocol_version),
+            reference_gas_price: Some(summary.reference_gas_price),
+            chain_identifier: Some(chain_identifier.to_string()),
+        }
+    }
+
+    /// Mark the node as not ready because of `reason`.
+    pub fn not_ready<T: Into<String>>(self, reason: T) -> Self {
+        Self {
+            ready: false,
+            reason: Some(reason.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::{test_summary, test_validator};
+    use sui_types::digests::CheckpointDigest;
+
+    #[test]
+    fn healthy_node() {
+        let mut summary = test_summary(vec![test_validator(1)]);
+        summary.protocol_version = 42;
+        let chain_identifier = ChainIdentifier::from(CheckpointDigest::new([0xab; 32]));
+
+        assert_eq!(
+            BootstrapInfo::new(&summary, chain_identifier),
+            BootstrapInfo {
+                ready: true,
+                reason: None,
+                epoch: Some(10),
+                protocol_version: Some(42),
+                reference_gas_price: Some(1000),
+                chain_identifier: Some("abababab".to_owned()),
+            }
+        );
+    }
+
+    #[test]
+    fn unreadable_state() {
+        let info = BootstrapInfo::default().not_ready("unable to read the system state");
+        assert!(!info.ready);
+        assert_eq!(info.epoch, None);
+
+        let json = serde_json::to_value(&info).unwrap();
+        assert_eq!(json["ready"], false);
+        assert_eq!(json["reason"], "unable to read the system state");
+        assert!(json["chain_identifier"].is_null());
+
+        // Epoch information is kept when only the checkpoints can't be read
+        let summary = test_summary(vec![test_validator(1)]);
+        let chain_identifier = ChainIdentifier::from(CheckpointDigest::new([0xab; 32]));
+        let info = BootstrapInfo::new(&summary, chain_identifier).not_ready("no checkpoints");
+        assert!(!info.ready);
+        assert_eq!(info.reason.as_deref(), Some("no checkpoints"));
+        assert_eq!(info.epoch, Some(10));
+    }
+}
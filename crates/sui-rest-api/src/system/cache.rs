@@ -0,0 +1,49 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::http::HeaderValue;
+
+use super::reconfiguration::{epoch_end_timestamp_ms, now_ms};
+use super::SystemStateSummary;
+
+/// Shortest `max-age` advertised, so that clients polling around an epoch boundary, or while
+/// reconfiguration is overdue, don't hammer the service.
+const MIN_MAX_AGE_SECS: u64 = 1;
+
+/// `Cache-Control` header for responses derived from `summary`, which remain valid until the end
+/// of the current epoch.
+pub(crate) fn cache_control(summary: &SystemStateSummary) -> HeaderValue {
+    HeaderValue::from_str(&format!("max-age={}", max_age_secs(summary, now_ms())))
+        .expect("max-age is a valid header value")
+}
+
+/// Number of seconds remaining in the epoch of `summary` at `now_ms`, rounded down and clamped to
+/// [`MIN_MAX_AGE_SECS`].
+fn max_age_secs(summary: &SystemStateSummary, now_ms: u64) -> u64 {
+    (epoch_end_timestamp_ms(summary).saturating_sub(now_ms) / 1000).max(MIN_MAX_AGE_SECS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::test_summary;
+
+    #[test]
+    fn remaining_epoch_time() {
+        let mut summary = test_summary(vec![]);
+        summary.epoch_start_timestamp_ms = 1_000_000;
+        summary.epoch_duration_ms = 86_400_000;
+        let epoch_end_ms = 1_000_000 + 86_400_000;
+
+        assert_eq!(max_age_secs(&summary, 1_000_000), 86_400);
+        assert_eq!(max_age_secs(&summary, epoch_end_ms - 3_600_000), 3_600);
+
+        // Near and past the epoch boundary the minimum is used
+        assert_eq!(max_age_secs(&summary, epoch_end_ms - 1_500), 1);
+        assert_eq!(max_age_secs(&summary, epoch_end_ms - 500), MIN_MAX_AGE_SECS);
+        assert_eq!(
+            max_age_secs(&summary, epoch_end_ms + 60_000),
+            MIN_MAX_AGE_SECS
+        );
+    }
+}
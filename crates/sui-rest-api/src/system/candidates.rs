@@ -0,0 +1,169 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{reader::StateReader, Result};
+use axum::{
+    extract::{Query, State},
+    http::HeaderMap,
+    Json,
+};
+use sui_sdk2::types::{Address, ObjectId};
+
+use super::{
+    inactive_pools::{check_cursor, cursor_headers, paginate},
+    ValidatorSummary,
+};
+
+pub const GET_VALIDATOR_CANDIDATES_PATH: &str = "/system/validators/candidates";
+
+const DEFAULT_VALIDATOR_CANDIDATES_LIMIT: usize = 50;
+const MAX_VALIDATOR_CANDIDATES_LIMIT: usize = 1000;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct ValidatorCandidatesQueryParameters {
+    /// Maximum number of candidates to return. Defaults to 50, capped at 1000.
+    pub limit: Option<u32>,
+    /// Resume listing after this entry, as returned in the `x-sui-cursor` header of the previous
+    /// page.
+    pub cursor: Option<ObjectId>,
+}
+
+impl ValidatorCandidatesQueryParameters {
+    fn limit(&self) -> usize {
+        self.limit
+            .map(|limit| (limit as usize).clamp(1, MAX_VALIDATOR_CANDIDATES_LIMIT))
+            .unwrap_or(DEFAULT_VALIDATOR_CANDIDATES_LIMIT)
+    }
+}
+
+/// List the preactive validators, which have applied to join the validator set but aren't active
+/// yet, from the `validator_candidates_id` table.
+///
+/// If there are further candidates the cursor for the next page is returned in the
+/// `x-sui-cursor` header. A cursor which isn't an entry of the table is rejected with
+/// `400 Bad Request`.
+pub async fn get_validator_candidates(
+    Query(parameters): Query<ValidatorCandidatesQueryParameters>,
+    State(state): State<StateReader>,
+) -> Result<(HeaderMap, Json<Vec<ValidatorCandidate>>)> {
+    let (cursor, limit) = (parameters.cursor, parameters.limit());
+    let (validators, next_cursor) = state
+        .spawn_read(move |state| {
+            let table_id = state.get_system_state_summary()?.validator_candidates_id;
+
+            check_cursor(cursor, |cursor| Ok(state.is_table_field(table_id, cursor)?))?;
+            paginate(cursor, limit, |cursor, limit| {
+                state.get_validator_candidates(table_id, cursor, limit)
+            })
+        })
+        .await?;
+
+    let candidates = validators.iter().map(ValidatorCandidate::new).collect();
+
+    Ok((cursor_headers(next_cursor), Json(candidates)))
+}
+
+/// The identity of a preactive validator and the stake staged with it so far.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ValidatorCandidate {
+    pub address: Address,
+    pub name: String,
+    pub description: String,
+    pub image_url: String,
+    pub project_url: String,
+    pub staking_pool_id: ObjectId,
+    /// The SUI staked with the candidate's pool, which will count towards its voting power once
+    /// it joins the active set.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub staking_pool_sui_balance: u64,
+    /// Stake of the candidate's pool which has yet to be processed.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub pending_stake: u64,
+}
+
+impl ValidatorCandidate {
+    pub fn new(validator: &ValidatorSummary) -> Self {
+        Self {
+            address: validator.address,
+            name: validator.name.clone(),
+            description: validator.description.clone(),
+            image_url: validator.image_url.clone(),
+            project_url: validator.project_url.clone(),
+            staking_pool_id: validator.staking_pool_id,
+            staking_pool_sui_balance: validator.staking_pool_sui_balance,
+            pending_stake: validator.pending_stake,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::test_validator;
+    use axum::{http::StatusCode, response::IntoResponse};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn walk_candidate_pages() {
+        // Fixture table, keyed by the object id of each candidate's dynamic field
+        let table = (0..5u8)
+            .map(|i| {
+                let mut validator = test_validator(i * 10);
+                validator.staking_pool_sui_balance = 1_000 * (i as u64 + 1);
+                validator.pending_stake = 10;
+                (ObjectId::new([i + 1; 32]), validator)
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let fetch =
+            |cursor: Option<ObjectId>, limit: usize| -> sui_types::storage::error::Result<_> {
+                Ok(table
+                    .iter()
+                    .filter(|(id, _)| cursor.map_or(true, |cursor| **id > cursor))
+                    .take(limit)
+                    .map(|(id, validator)| (*id, validator.clone()))
+                    .collect())
+            };
+        let is_entry = |cursor: ObjectId| Ok(table.contains_key(&cursor));
+
+        let mut walked = Vec::new();
+        let mut cursor = None;
+        let mut pages = 0;
+        loop {
+            check_cursor(cursor, is_entry).unwrap();
+            let (page, next_cursor) = paginate(cursor, 2, fetch).unwrap();
+            walked.extend(page.iter().map(ValidatorCandidate::new));
+            pages += 1;
+
+            cursor = next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        assert_eq!(pages, 3);
+
+        let expected = table
+            .values()
+            .map(ValidatorCandidate::new)
+            .collect::<Vec<_>>();
+        assert_eq!(walked, expected);
+        assert_eq!(walked[2].name, "validator-20");
+        assert_eq!(walked[2].staking_pool_sui_balance, 3_000);
+        assert_eq!(walked[2].pending_stake, 10);
+
+        // Cursors which aren't entries of the table are rejected
+        let error = check_cursor(Some(ObjectId::new([0xff; 32])), is_entry)
+            .unwrap_err()
+            .into_response();
+        assert_eq!(error.status(), StatusCode::BAD_REQUEST);
+
+        // As are those which aren't object ids at all
+        let query = "/?cursor=not-an-object-id"
+            .parse::<axum::http::Uri>()
+            .unwrap();
+        let rejection =
+            Query::<ValidatorCandidatesQueryParameters>::try_from_uri(&query).unwrap_err();
+        assert_eq!(rejection.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{reader::StateReader, Result};
+use axum::{
+    extract::State,
+    http::{header, HeaderValue},
+    response::{IntoResponse, Response},
+    Json,
+};
+use fastcrypto::{
+    encoding::{Encoding, Hex},
+    hash::{Blake2b256, HashFunction},
+};
+
+use super::SystemStateSummary;
+
+pub const GET_SYSTEM_CHECKSUM_PATH: &str = "/system/checksum";
+
+/// Return a checksum of the current system state summary, for cheaply detecting changes.
+///
+/// The response is byte-stable for a given system state, so it carries a strong `ETag`. The
+/// `/system` endpoint on the other hand only carries a weak `ETag` derived from the same
+/// checksum, as its representation varies with the `Accept` header and query parameters.
+pub async fn get_system_checksum(State(state): State<StateReader>) -> Result<Response> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+    let checksum = SystemStateChecksum::new(&summary);
+    let etag = strong_etag(&checksum.checksum);
+
+    Ok(([(header::ETAG, etag)], Json(checksum)).into_response())
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SystemStateChecksum {
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub epoch: u64,
+    /// Hex encoded Blake2b-256 digest of the BCS encoded system state summary.
+    pub checksum: String,
+}
+
+impl SystemStateChecksum {
+    pub fn new(summary: &SystemStateSummary) -> Self {
+        Self {
+            epoch: summary.epoch,
+            checksum: checksum(summary),
+        }
+    }
+}
+
+/// Hex encoded digest of the BCS encoding of `summary`, which unlike its JSON representation
+/// doesn't depend on how the response is shaped.
+pub(crate) fn checksum(summary: &SystemStateSummary) -> String {
+    let bytes = bcs::to_bytes(summary).expect("system state summary is BCS serializable");
+
+    Hex::encode(Blake2b256::digest(bytes).digest)
+}
+
+/// A strong `ETag` for a byte-stable representation identified by `checksum`.
+pub(crate) fn strong_etag(checksum: &str) -> HeaderValue {
+    HeaderValue::from_str(&format!("\"{checksum}\"")).expect("hex is a valid header value")
+}
+
+/// A weak `ETag` for representations of the state identified by `checksum` which are
+/// semantically, but not necessarily byte-for-byte, equivalent.
+pub(crate) fn weak_etag(checksum: &str) -> HeaderValue {
+    HeaderValue::from_str(&format!("W/\"{checksum}\"")).expect("hex is a valid header value")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::{test_summary, test_validator};
+
+    #[test]
+    fn weak_and_strong_etags() {
+        let summary = test_summary(vec![test_validator(1)]);
+        let checksum = SystemStateChecksum::new(&summary);
+        assert_eq!(checksum.epoch, 10);
+        assert_eq!(checksum.checksum.len(), 64);
+
+        let weak = weak_etag(&checksum.checksum);
+        let strong = strong_etag(&checksum.checksum);
+        assert!(weak.to_str().unwrap().starts_with("W/\""));
+        assert!(strong.to_str().unwrap().starts_with('"'));
+        // Both identify the same state
+        assert_eq!(
+            weak.to_str().unwrap().strip_prefix("W/"),
+            strong.to_str().ok()
+        );
+
+        // Any change to the state changes the tags
+        let mut changed = summary.clone();
+        changed.reference_gas_price += 1;
+        assert_ne!(checksum, SystemStateChecksum::new(&changed));
+        assert_eq!(checksum, SystemStateChecksum::new(&summary));
+    }
+}
@@ -0,0 +1,300 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{reader::StateReader, RestError, Result};
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    Json,
+};
+use sui_types::base_types::EpochId;
+
+use super::SystemStateSummary;
+
+pub const GET_VALIDATORS_COUNT_PATH: &str = "/system/validators/count";
+
+pub async fn get_validators_count(
+    State(state): State<StateReader>,
+) -> Result<Json<ValidatorsCount>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(ValidatorsCount::new(&summary)))
+}
+
+/// The number of validators in each stage of the validator lifecycle.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ValidatorsCount {
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub active: u64,
+    /// Number of validators which will join the active set at the end of the epoch.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub pending_active: u64,
+    /// Number of validators which will leave the active set at the end of the epoch.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub pending_removals: u64,
+    /// Number of preactive validator candidates.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub candidates: u64,
+}
+
+impl ValidatorsCount {
+    pub fn new(summary: &SystemStateSummary) -> Self {
+        Self {
+            active: summary.active_validators.len() as u64,
+            pending_active: summary.pending_active_validators_size,
+            pending_removals: summary.pending_removals.len() as u64,
+            candidates: summary.validator_candidates_size,
+        }
+    }
+}
+
+pub const GET_VALIDATOR_CHURN_PATH: &str = "/system/validators/churn";
+
+pub async fn get_validator_churn(State(state): State<StateReader>) -> Result<Json<ValidatorChurn>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(ValidatorChurn::new(&summary)))
+}
+
+/// How much the active validator set will change at the end of the epoch.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ValidatorChurn {
+    /// Number of validators which will join the active set at the end of the epoch.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub joining: u64,
+    /// Number of validators which will leave the active set at the end of the epoch.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub leaving: u64,
+    /// Number of validators joining or leaving relative to the size of the current active set, or
+    /// 0 if there are no active validators.
+    pub churn_ratio: f64,
+}
+
+impl ValidatorChurn {
+    pub fn new(summary: &SystemStateSummary) -> Self {
+        let joining = summary.pending_active_validators_size;
+        let leaving = summary.pending_removals.len() as u64;
+        let active = summary.active_validators.len();
+
+        let churn_ratio = if active == 0 {
+            0.0
+        } else {
+            (joining + leaving) as f64 / active as f64
+        };
+
+        Self {
+            joining,
+            leaving,
+            churn_ratio,
+        }
+    }
+}
+
+pub const GET_VALIDATOR_SET_SIZE_TREND_PATH: &str = "/system/validators/size-trend";
+
+/// Number of epochs of validator set sizes returned when none is requested.
+const DEFAULT_SIZE_TREND_EPOCHS: u64 = 30;
+
+/// Most epochs of validator set sizes which may be requested, as each requires reading an
+/// archived system state.
+pub const MAX_SIZE_TREND_EPOCHS: u64 = 100;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct ValidatorSetSizeTrendQueryParameters {
+    /// Number of most recent epochs, including the current one, to return the active validator
+    /// count of. Defaults to 30, and may not exceed 100.
+    pub epochs: Option<u64>,
+}
+
+/// List the number of active validators over the most recent epochs, oldest first, for charting
+/// the growth of the validator set.
+///
+/// Epochs whose system state has been pruned are omitted from the series.
+pub async fn get_validator_set_size_trend(
+    Query(parameters): Query<ValidatorSetSizeTrendQueryParameters>,
+    State(state): State<StateReader>,
+) -> Result<Json<Vec<ValidatorSetSize>>> {
+    let epochs = size_trend_epochs(parameters.epochs)?;
+    let trend = state
+        .spawn_read(move |state| {
+            let summary = state.get_system_state_summary()?;
+
+            size_trend(&summary, epochs, |epoch| {
+                state.get_system_state_summary_at_epoch(epoch)
+            })
+        })
+        .await?;
+
+    Ok(Json(trend))
+}
+
+/// The size of the active validator set in an epoch.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ValidatorSetSize {
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub epoch: EpochId,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub active_validators: u64,
+}
+
+fn size_trend_epochs(requested: Option<u64>) -> Result<u64> {
+    match requested {
+        None => Ok(DEFAULT_SIZE_TREND_EPOCHS),
+        Some(0) => Err(RestError::new(
+            StatusCode::BAD_REQUEST,
+            "epochs must be at least 1",
+        )),
+        Some(epochs) if epochs > MAX_SIZE_TREND_EPOCHS => Err(RestError::new(
+            StatusCode::BAD_REQUEST,
+            format!(
+                "epochs {epochs} exceeds the maximum validator set size trend of \
+                 {MAX_SIZE_TREND_EPOCHS} epochs"
+            ),
+        )),
+        Some(epochs) => Ok(epochs),
+    }
+}
+
+/// The active validator counts of the `epochs` most recent epochs up to and including that of
+/// `current`, oldest first, reading the system state of earlier epochs with `historical`.
+pub(crate) fn size_trend<F>(
+    current: &SystemStateSummary,
+    epochs: u64,
+    mut historical: F,
+) -> Result<Vec<ValidatorSetSize>>
+where
+    F: FnMut(EpochId) -> Result<Option<SystemStateSummary>>,
+{
+    let oldest = current.epoch.saturating_sub(epochs.saturating_sub(1));
+
+    let mut trend = Vec::new();
+    for epoch in oldest..current.epoch {
+        if let Some(summary) = historical(epoch)? {
+            trend.push(ValidatorSetSize {
+                epoch,
+                active_validators: summary.active_validators.len() as u64,
+            });
+        }
+    }
+    trend.push(ValidatorSetSize {
+        epoch: current.epoch,
+        active_validators: current.active_validators.len() as u64,
+    });
+
+    Ok(trend)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::{test_summary, test_validator};
+    use axum::response::IntoResponse;
+
+    #[test]
+    fn validators_count() {
+        let mut summary = test_summary((0..4).map(|i| test_validator(i * 10)).collect());
+        summary.pending_active_validators_size = 2;
+        summary.pending_removals = vec![1, 3];
+        summary.validator_candidates_size = 5;
+
+        assert_eq!(
+            ValidatorsCount::new(&summary),
+            ValidatorsCount {
+                active: 4,
+                pending_active: 2,
+                pending_removals: 2,
+                candidates: 5,
+            }
+        );
+    }
+
+    #[test]
+    fn validator_churn() {
+        let mut summary = test_summary((0..8).map(|i| test_validator(i * 10)).collect());
+        summary.pending_active_validators_size = 1;
+        summary.pending_removals = vec![2, 5, 6];
+
+        assert_eq!(
+            ValidatorChurn::new(&summary),
+            ValidatorChurn {
+                joining: 1,
+                leaving: 3,
+                churn_ratio: 0.5,
+            }
+        );
+
+        // Churn of an empty set shouldn't divide by zero
+        let mut summary = test_summary(vec![]);
+        summary.pending_active_validators_size = 4;
+        let churn = ValidatorChurn::new(&summary);
+        assert_eq!(churn.joining, 4);
+        assert_eq!(churn.churn_ratio, 0.0);
+        assert!(serde_json::to_string(&churn).is_ok());
+    }
+
+    #[test]
+    fn validator_set_size_trend() {
+        // Epochs 5 to 10 with one more validator each epoch, of which epoch 7 has been pruned
+        let history = (5..10)
+            .filter(|epoch| *epoch != 7)
+            .map(|epoch| {
+                let mut summary = test_summary(
+                    (0..epoch - 3)
+                        .map(|i| test_validator(i as u8 * 10))
+                        .collect(),
+                );
+                summary.epoch = epoch;
+                (epoch, summary)
+            })
+            .collect::<std::collections::BTreeMap<_, _>>();
+        let current = test_summary((0..7).map(|i| test_validator(i * 10)).collect());
+
+        let mut reads = Vec::new();
+        let trend = size_trend(&current, 4, |epoch| {
+            reads.push(epoch);
+            Ok(history.get(&epoch).cloned())
+        })
+        .unwrap();
+        // The current epoch is never read from the archive
+        assert_eq!(reads, vec![7, 8, 9]);
+        assert_eq!(
+            trend,
+            vec![
+                ValidatorSetSize {
+                    epoch: 8,
+                    active_validators: 5,
+                },
+                ValidatorSetSize {
+                    epoch: 9,
+                    active_validators: 6,
+                },
+                ValidatorSetSize {
+                    epoch: 10,
+                    active_validators: 7,
+                },
+            ]
+        );
+
+        // Requesting more epochs than the chain has reaches back no further than genesis
+        let trend = size_trend(&current, 20, |epoch| Ok(history.get(&epoch).cloned())).unwrap();
+        let series = trend
+            .iter()
+            .map(|size| (size.epoch, size.active_validators))
+            .collect::<Vec<_>>();
+        assert_eq!(series, vec![(5, 2), (6, 3), (8, 5), (9, 6), (10, 7)]);
+
+        assert_eq!(size_trend_epochs(None).unwrap(), DEFAULT_SIZE_TREND_EPOCHS);
+        assert_eq!(size_trend_epochs(Some(100)).unwrap(), 100);
+        for epochs in [0, MAX_SIZE_TREND_EPOCHS + 1] {
+            let error = size_trend_epochs(Some(epochs)).unwrap_err().into_response();
+            assert_eq!(error.status(), StatusCode::BAD_REQUEST);
+        }
+    }
+}
@@ -0,0 +1,374 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{reader::StateReader, RestError, Result};
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    Json,
+};
+use sui_sdk2::types::Address;
+use sui_types::committee::{QUORUM_THRESHOLD, TOTAL_VOTING_POWER};
+
+use super::ValidatorSummary;
+
+pub const GET_DECENTRALIZATION_PATH: &str = "/system/decentralization";
+
+pub async fn get_decentralization(
+    State(state): State<StateReader>,
+) -> Result<Json<Decentralization>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(Decentralization::new(&summary.active_validators)))
+}
+
+/// Decentralization metrics of the voting power distribution across the active validator set.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Decentralization {
+    /// Gini coefficient of the voting power distribution, from 0 (perfectly equal) towards 1
+    /// (concentrated in a single validator).
+    pub gini_coefficient: f64,
+    /// Smallest number of validators whose combined voting power exceeds one third of the total,
+    /// i.e. enough to halt consensus.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub nakamoto_coefficient: u64,
+    /// Number of equally weighted validators which would give the same concentration of voting
+    /// power, i.e. the inverse of the Herfindahl-Hirschman index.
+    pub effective_validator_count: f64,
+}
+
+impl Decentralization {
+    pub fn new(validators: &[ValidatorSummary]) -> Self {
+        let mut voting_power = validators
+            .iter()
+            .map(|validator| validator.voting_power)
+            .collect::<Vec<_>>();
+        voting_power.sort_unstable();
+
+        let total = voting_power
+            .iter()
+            .map(|power| *power as u128)
+            .sum::<u128>();
+        if total == 0 {
+            return Self {
+                gini_coefficient: 0.0,
+                nakamoto_coefficient: 0,
+                effective_validator_count: 0.0,
+            };
+        }
+
+        let n = voting_power.len() as f64;
+        let rank_weighted = voting_power
+            .iter()
+            .enumerate()
+            .map(|(i, power)| (i + 1) as f64 * *power as f64)
+            .sum::<f64>();
+        let gini_coefficient = 2.0 * rank_weighted / (n * total as f64) - (n + 1.0) / n;
+
+        let mut cumulative = 0;
+        let nakamoto_coefficient = voting_power
+            .iter()
+            .rev()
+            .take_while(|power| {
+                let below_threshold = cumulative * 3 <= total;
+                cumulative += **power as u128;
+                below_threshold
+            })
+            .count() as u64;
+
+        let concentration = voting_power
+            .iter()
+            .map(|power| (*power as f64 / total as f64).powi(2))
+            .sum::<f64>();
+
+        Self {
+            gini_coefficient,
+            nakamoto_coefficient,
+            effective_validator_count: 1.0 / concentration,
+        }
+    }
+}
+
+pub const GET_DECENTRALIZATION_WARNINGS_PATH: &str = "/system/decentralization/warnings";
+
+/// Default share of the total voting power above which a single validator is flagged.
+const DEFAULT_MAX_VOTING_POWER_SHARE: f64 = 0.1;
+
+/// Number of the largest validators checked for being able to reach quorum on their own.
+const TOP_VALIDATORS: usize = 3;
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct DecentralizationWarningsQueryParameters {
+    /// Share of the total voting power, between 0 and 1, above which a single validator is
+    /// flagged. Defaults to `0.1`.
+    pub max_share: Option<f64>,
+}
+
+pub async fn get_decentralization_warnings(
+    Query(parameters): Query<DecentralizationWarningsQueryParameters>,
+    State(state): State<StateReader>,
+) -> Result<Json<Vec<ConcentrationWarning>>> {
+    let max_share = parameters
+        .max_share
+        .unwrap_or(DEFAULT_MAX_VOTING_POWER_SHARE);
+    if !(max_share > 0.0 && max_share <= 1.0) {
+        return Err(RestError::new(
+            StatusCode::BAD_REQUEST,
+            format!("max_share {max_share} must be greater than 0 and at most 1"),
+        ));
+    }
+
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(concentration_warnings(
+        &summary.active_validators,
+        max_share,
+    )))
+}
+
+/// A concentration of voting power in too few validators.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ConcentrationWarning {
+    pub kind: ConcentrationWarningKind,
+    /// The validators responsible for the concentration, largest first.
+    pub validators: Vec<VotingPowerShare>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConcentrationWarningKind {
+    /// A single validator holds more than the maximum share of the voting power.
+    SingleValidator,
+    /// The three largest validators together hold enough voting power to reach quorum.
+    TopThreeQuorum,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct VotingPowerShare {
+    pub address: Address,
+    pub name: String,
+    /// Share of the total voting power, between 0 and 1.
+    pub share: f64,
+}
+
+pub(crate) fn concentration_warnings(
+    validators: &[ValidatorSummary],
+    max_share: f64,
+) -> Vec<ConcentrationWarning> {
+    let total = validators
+        .iter()
+        .map(|validator| validator.voting_power as u128)
+        .sum::<u128>();
+    if total == 0 {
+        return Vec::new();
+    }
+
+    let mut shares = validators
+        .iter()
+        .map(|validator| VotingPowerShare {
+            address: validator.address,
+            name: validator.name.clone(),
+            share: validator.voting_power as f64 / total as f64,
+        })
+        .collect::<Vec<_>>();
+    shares.sort_by(|a, b| b.share.total_cmp(&a.share));
+
+    let mut warnings = Vec::new();
+    for share in shares.iter().filter(|share| share.share > max_share) {
+        warnings.push(ConcentrationWarning {
+            kind: ConcentrationWarningKind::SingleValidator,
+            validators: vec![share.clone()],
+        });
+    }
+
+    let top = &shares[..shares.len().min(TOP_VALIDATORS)];
+    let top_share = top.iter().map(|share| share.share).sum::<f64>();
+    if top_share >= QUORUM_THRESHOLD as f64 / TOTAL_VOTING_POWER as f64 {
+        warnings.push(ConcentrationWarning {
+            kind: ConcentrationWarningKind::TopThreeQuorum,
+            validators: top.to_vec(),
+        });
+    }
+
+    warnings
+}
+
+pub const GET_CHAIN_SAFETY_PATH: &str = "/system/safety";
+
+/// How much of the voting power of the active validator set is needed to halt the chain, for
+/// security analysis.
+pub async fn get_chain_safety(State(state): State<StateReader>) -> Result<Json<ChainSafety>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(ChainSafety::new(&summary.active_validators)))
+}
+
+/// The chain halts once validators holding more than `TOTAL_VOTING_POWER - QUORUM_THRESHOLD`,
+/// i.e. one third, of the voting power stop participating, as the rest can no longer reach
+/// quorum.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ChainSafety {
+    /// Voting power needed to certify, out of a total voting power of 10000.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub quorum_threshold: u64,
+    /// Voting power of the largest active validator.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub max_single_validator_power: u64,
+    /// Whether the largest validator on its own holds enough voting power to halt the chain.
+    pub can_any_single_halt: bool,
+    /// Smallest number of validators, taken from the largest down, whose combined voting power
+    /// is enough to halt the chain.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub validators_to_halt: u64,
+}
+
+impl ChainSafety {
+    pub fn new(validators: &[ValidatorSummary]) -> Self {
+        let halting_power = TOTAL_VOTING_POWER - QUORUM_THRESHOLD;
+
+        let mut voting_power = validators
+            .iter()
+            .map(|validator| validator.voting_power)
+            .collect::<Vec<_>>();
+        voting_power.sort_unstable_by(|a, b| b.cmp(a));
+
+        let mut cumulative = 0;
+        let validators_to_halt = voting_power
+            .iter()
+            .take_while(|power| {
+                let below_threshold = cumulative <= halting_power;
+                cumulative += **power;
+                below_threshold
+            })
+            .count() as u64;
+        let max_single_validator_power = voting_power.first().copied().unwrap_or_default();
+
+        Self {
+            quorum_threshold: QUORUM_THRESHOLD,
+            max_single_validator_power,
+            can_any_single_halt: max_single_validator_power > halting_power,
+            validators_to_halt,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::validators_with;
+
+    fn validators(voting_power: &[u64]) -> Vec<ValidatorSummary> {
+        validators_with(
+            |validator, voting_power| validator.voting_power = *voting_power,
+            voting_power,
+        )
+    }
+
+    #[test]
+    fn voting_power_distribution() {
+        const TOLERANCE: f64 = 1e-9;
+
+        // Shares of 10%, 20%, 30% and 40%, listed out of order
+        let metrics = Decentralization::new(&validators(&[3000, 1000, 4000, 2000]));
+        assert!((metrics.gini_coefficient - 0.25).abs() < TOLERANCE);
+        assert_eq!(metrics.nakamoto_coefficient, 1);
+        // 1 / (0.1^2 + 0.2^2 + 0.3^2 + 0.4^2)
+        assert!((metrics.effective_validator_count - 1.0 / 0.3).abs() < TOLERANCE);
+
+        let metrics = Decentralization::new(&validators(&[2500; 4]));
+        assert!(metrics.gini_coefficient.abs() < TOLERANCE);
+        assert_eq!(metrics.nakamoto_coefficient, 2);
+        assert!((metrics.effective_validator_count - 4.0).abs() < TOLERANCE);
+
+        assert_eq!(
+            Decentralization::new(&[]),
+            Decentralization {
+                gini_coefficient: 0.0,
+                nakamoto_coefficient: 0,
+                effective_validator_count: 0.0,
+            }
+        );
+    }
+
+    #[test]
+    fn concentration() {
+        // Shares of 40%, 15%, 5% and the remaining 40% spread across 8 validators
+        let mut voting_power = vec![4000, 1500, 500];
+        voting_power.extend([500; 8]);
+        let validators = validators(&voting_power);
+
+        let warnings = concentration_warnings(&validators, DEFAULT_MAX_VOTING_POWER_SHARE);
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|warning| (warning.kind, warning.validators.len()))
+                .collect::<Vec<_>>(),
+            [
+                (ConcentrationWarningKind::SingleValidator, 1),
+                (ConcentrationWarningKind::SingleValidator, 1),
+            ]
+        );
+        assert_eq!(warnings[0].validators[0].address, validators[0].address);
+        assert_eq!(warnings[0].validators[0].name, "validator-0");
+        assert!((warnings[0].validators[0].share - 0.4).abs() < 1e-9);
+        assert!((warnings[1].validators[0].share - 0.15).abs() < 1e-9);
+
+        // Raising the threshold only flags the largest validator
+        let warnings = concentration_warnings(&validators, 0.2);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].validators[0].address, validators[0].address);
+
+        // The three largest validators reaching quorum together
+        let validators = self::validators(&[3000, 1000, 3000, 1000, 2000]);
+        let warnings = concentration_warnings(&validators, 1.0);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].kind, ConcentrationWarningKind::TopThreeQuorum);
+        assert_eq!(
+            warnings[0]
+                .validators
+                .iter()
+                .map(|share| share.name.as_str())
+                .collect::<Vec<_>>(),
+            ["validator-0", "validator-20", "validator-40"]
+        );
+
+        assert!(concentration_warnings(&self::validators(&[1000; 10]), 0.3).is_empty());
+    }
+
+    #[test]
+    fn halting_power() {
+        // 3334 is the least voting power which halts the chain
+        let safety = ChainSafety::new(&validators(&[1500, 3334, 2000, 1500, 1666]));
+        assert_eq!(
+            safety,
+            ChainSafety {
+                quorum_threshold: 6667,
+                max_single_validator_power: 3334,
+                can_any_single_halt: true,
+                validators_to_halt: 1,
+            }
+        );
+
+        // The largest validator falls one short on its own, so it takes the two largest
+        let safety = ChainSafety::new(&validators(&[1500, 3333, 2000, 1500, 1667]));
+        assert_eq!(safety.max_single_validator_power, 3333);
+        assert!(!safety.can_any_single_halt);
+        assert_eq!(safety.validators_to_halt, 2);
+
+        let safety = ChainSafety::new(&validators(&[1000; 10]));
+        assert!(!safety.can_any_single_halt);
+        assert_eq!(safety.validators_to_halt, 4);
+
+        let safety = ChainSafety::new(&[]);
+        assert_eq!(safety.max_single_validator_power, 0);
+        assert_eq!(safety.validators_to_halt, 0);
+    }
+}
@@ -0,0 +1,376 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    accept::{AcceptFormat, UnsupportedAcceptFormatError},
+    reader::StateReader,
+    response::Cbor,
+    RestError, Result,
+};
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde_json::{Map, Value};
+use sui_sdk2::types::{Address, EpochId};
+
+use super::{encoding, SystemStateQueryParameters, SystemStateSummary};
+
+const SYSTEM_STATE_DELTA_FORMATS: &[AcceptFormat] = &[AcceptFormat::Json, AcceptFormat::Cbor];
+
+/// Render the changes to the system state since the start of `since_epoch`.
+///
+/// `historical` is used to look up the system state as of the start of an epoch.
+pub(crate) fn render<F>(
+    accept: AcceptFormat,
+    parameters: &SystemStateQueryParameters,
+    summary: &SystemStateSummary,
+    since_epoch: EpochId,
+    historical: F,
+) -> Result<Response>
+where
+    F: FnOnce(EpochId) -> Result<Option<SystemStateSummary>>,
+{
+    if since_epoch == summary.epoch {
+        return Ok(StatusCode::NOT_MODIFIED.into_response());
+    }
+    check_since_epoch(since_epoch, summary.epoch)?;
+
+    let bigints_as_numbers = match accept {
+        AcceptFormat::Json => false,
+        AcceptFormat::Cbor => true,
+        AcceptFormat::Bcs => {
+            return Err(UnsupportedAcceptFormatError::new(SYSTEM_STATE_DELTA_FORMATS).into())
+        }
+    };
+
+    let previous = historical_summary(since_epoch, historical)?;
+    let delta = SystemStateDelta::new(
+        since_epoch,
+        parameters.serialize(&previous)?,
+        parameters.serialize(summary)?,
+    );
+    let value = serde_json::to_value(delta).map_err(anyhow::Error::from)?;
+    let value = parameters.encode(value, bigints_as_numbers);
+
+    let response = match accept {
+        AcceptFormat::Cbor => Cbor(encoding::to_cbor(value)).into_response(),
+        _ => Json(value).into_response(),
+    };
+
+    Ok(response)
+}
+
+pub const GET_VALIDATOR_CHANGES_PATH: &str = "/system/validators/changes";
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct ValidatorChangesQueryParameters {
+    /// The epoch to compare the current active validator set against.
+    pub since_epoch: EpochId,
+}
+
+pub async fn get_validator_changes(
+    Query(parameters): Query<ValidatorChangesQueryParameters>,
+    State(state): State<StateReader>,
+) -> Result<Json<ValidatorChanges>> {
+    let since_epoch = parameters.since_epoch;
+    let changes = state
+        .spawn_read(move |state| {
+            let summary = state.get_system_state_summary()?;
+
+            ValidatorChanges::new(&summary, since_epoch, |epoch| {
+                state.get_system_state_summary_at_epoch(epoch)
+            })
+        })
+        .await?;
+
+    Ok(Json(changes))
+}
+
+/// The validators which joined or left the active validator set since the start of an earlier
+/// epoch.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ValidatorChanges {
+    pub joined: Vec<Address>,
+    pub left: Vec<Address>,
+}
+
+impl ValidatorChanges {
+    /// Compare the active validators of `summary` against those at the start of `since_epoch`.
+    ///
+    /// `historical` is used to look up the system state as of the start of an epoch.
+    pub(crate) fn new<F>(
+        summary: &SystemStateSummary,
+        since_epoch: EpochId,
+        historical: F,
+    ) -> Result<Self>
+    where
+        F: FnOnce(EpochId) -> Result<Option<SystemStateSummary>>,
+    {
+        if since_epoch == summary.epoch {
+            return Ok(Self::default());
+        }
+        check_since_epoch(since_epoch, summary.epoch)?;
+
+        let previous = historical_summary(since_epoch, historical)?;
+        let addresses = |summary: &SystemStateSummary| {
+            summary
+                .active_validators
+                .iter()
+                .map(|validator| validator.address)
+                .collect::<Vec<_>>()
+        };
+        let previous = addresses(&previous);
+        let current = addresses(summary);
+
+        Ok(Self {
+            joined: current
+                .iter()
+                .filter(|address| !previous.contains(address))
+                .copied()
+                .collect(),
+            left: previous
+                .iter()
+                .filter(|address| !current.contains(address))
+                .copied()
+                .collect(),
+        })
+    }
+}
+
+/// Reject comparisons against epochs which haven't happened yet.
+fn check_since_epoch(since_epoch: EpochId, current_epoch: EpochId) -> Result<()> {
+    if since_epoch > current_epoch {
+        return Err(RestError::new(
+            StatusCode::BAD_REQUEST,
+            format!("since_epoch {since_epoch} is ahead of the current epoch {current_epoch}"),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Look up the system state as of the start of `epoch`, responding with `404 Not Found` if it's
+/// no longer available.
+fn historical_summary<F>(epoch: EpochId, historical: F) -> Result<SystemStateSummary>
+where
+    F: FnOnce(EpochId) -> Result<Option<SystemStateSummary>>,
+{
+    historical(epoch)?.ok_or_else(|| {
+        RestError::new(
+            StatusCode::NOT_FOUND,
+            format!("system state for epoch {epoch} is not available"),
+        )
+    })
+}
+
+/// The changes to the system state since the start of an earlier epoch.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SystemStateDelta {
+    /// The epoch the changes are relative to.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub since_epoch: u64,
+    /// The current value of every field of the summary, other than the active validators, which
+    /// changed.
+    pub changed_fields: Map<String, Value>,
+    /// The changes to the active validator set.
+    pub active_validators: ValidatorSetDelta,
+}
+
+/// The changes to the active validator set between two epochs.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ValidatorSetDelta {
+    /// Validators which joined the active set.
+    pub added: Vec<Value>,
+    /// Addresses of the validators which left the active set.
+    pub removed: Vec<Value>,
+    /// The current entry of every validator which remained in the active set but changed.
+    pub changed: Vec<Value>,
+}
+
+impl SystemStateDelta {
+    /// Compute the delta between two serialized system state summaries.
+    fn new(since_epoch: EpochId, previous: Value, current: Value) -> Self {
+        let mut previous = into_map(previous);
+        let mut current = into_map(current);
+
+        let active_validators = ValidatorSetDelta::new(
+            into_vec(previous.remove("active_validators")),
+            into_vec(current.remove("active_validators")),
+        );
+        let changed_fields = current
+            .into_iter()
+            .filter(|(key, value)| previous.get(key) != Some(value))
+            .collect();
+
+        Self {
+            since_epoch,
+            changed_fields,
+            active_validators,
+        }
+    }
+}
+
+impl ValidatorSetDelta {
+    fn new(previous: Vec<Value>, current: Vec<Value>) -> Self {
+        let address = |validator: &Value| validator.get("address").cloned();
+        let find = |validators: &[Value], validator: &Value| {
+            validators
+                .iter()
+                .position(|other| address(other) == address(validator))
+        };
+
+        let mut delta = Self::default();
+        for validator in &current {
+            match find(&previous, validator) {
+                None => delta.added.push(validator.clone()),
+                Some(i) if previous[i] != *validator => delta.changed.push(validator.clone()),
+                Some(_) => {}
+            }
+        }
+        delta.removed = previous
+            .iter()
+            .filter(|validator| find(&current, *validator).is_none())
+            .filter_map(address)
+            .collect();
+
+        delta
+    }
+}
+
+fn into_map(value: Value) -> Map<String, Value> {
+    match value {
+        Value::Object(map) => map,
+        _ => Map::new(),
+    }
+}
+
+fn into_vec(value: Option<Value>) -> Vec<Value> {
+    match value {
+        Some(Value::Array(items)) => items,
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::{test_summary, test_validator};
+
+    #[tokio::test]
+    async fn since_current_epoch() {
+        let summary = test_summary(vec![test_validator(1)]);
+
+        let response = render(
+            AcceptFormat::Json,
+            &SystemStateQueryParameters::default(),
+            &summary,
+            summary.epoch,
+            |_| panic!("the current epoch shouldn't be looked up"),
+        )
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+
+        let error = render(
+            AcceptFormat::Json,
+            &SystemStateQueryParameters::default(),
+            &summary,
+            summary.epoch + 1,
+            |_| panic!("future epochs shouldn't be looked up"),
+        )
+        .unwrap_err()
+        .into_response();
+        assert_eq!(error.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn since_older_epoch() {
+        let [unchanged, changed, removed, added] = [0, 10, 20, 30].map(test_validator);
+
+        let mut previous = test_summary(vec![unchanged.clone(), changed.clone(), removed.clone()]);
+        previous.epoch = 7;
+        previous.reference_gas_price = 500;
+
+        let mut changed = changed;
+        changed.gas_price = 2000;
+        let summary = test_summary(vec![unchanged, changed.clone(), added.clone()]);
+
+        let response = render(
+            AcceptFormat::Json,
+            &SystemStateQueryParameters::default(),
+            &summary,
+            7,
+            |epoch| {
+                assert_eq!(epoch, 7);
+                Ok(Some(previous))
+            },
+        )
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let delta: SystemStateDelta = serde_json::from_slice(&body).unwrap();
+        assert_eq!(delta.since_epoch, 7);
+        assert_eq!(
+            delta.changed_fields.keys().collect::<Vec<_>>(),
+            ["epoch", "reference_gas_price"]
+        );
+        assert_eq!(delta.changed_fields["epoch"], Value::from("10"));
+        assert_eq!(
+            delta.active_validators,
+            ValidatorSetDelta {
+                added: vec![serde_json::to_value(&added).unwrap()],
+                removed: vec![serde_json::to_value(removed.address).unwrap()],
+                changed: vec![serde_json::to_value(&changed).unwrap()],
+            }
+        );
+
+        // Epochs which are no longer available
+        let error = render(
+            AcceptFormat::Json,
+            &SystemStateQueryParameters::default(),
+            &summary,
+            3,
+            |_| Ok(None),
+        )
+        .unwrap_err()
+        .into_response();
+        assert_eq!(error.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn validator_changes() {
+        let [kept, left, joined] = [0, 10, 20].map(test_validator);
+
+        let mut previous = test_summary(vec![kept.clone(), left.clone()]);
+        previous.epoch = 7;
+        let summary = test_summary(vec![kept, joined.clone()]);
+
+        let changes = ValidatorChanges::new(&summary, 7, |epoch| {
+            assert_eq!(epoch, 7);
+            Ok(Some(previous))
+        })
+        .unwrap();
+        assert_eq!(
+            changes,
+            ValidatorChanges {
+                joined: vec![joined.address],
+                left: vec![left.address],
+            }
+        );
+
+        let unchanged = ValidatorChanges::new(&summary, summary.epoch, |_| {
+            panic!("the current epoch shouldn't be looked up")
+        })
+        .unwrap();
+        assert_eq!(unchanged, ValidatorChanges::default());
+
+        let error = ValidatorChanges::new(&summary, 3, |_| Ok(None))
+            .unwrap_err()
+            .into_response();
+        assert_eq!(error.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,481 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use serde_json::{Map, Value};
+
+/// Fields of [`SystemStateSummary`](super::SystemStateSummary),
+/// [`ValidatorSummary`](super::ValidatorSummary) and [`SystemStateDelta`](super::SystemStateDelta)
+/// which are serialized as `BigInt` strings, either directly or as the elements of a list.
+pub(crate) const BIGINT_FIELDS: &[&str] = &[
+    // SystemStateSummary
+    "epoch",
+    "protocol_version",
+    "system_state_version",
+    "storage_fund_total_object_storage_rebates",
+    "storage_fund_non_refundable_balance",
+    "reference_gas_price",
+    "safe_mode_storage_rewards",
+    "safe_mode_computation_rewards",
+    "safe_mode_storage_rebates",
+    "safe_mode_non_refundable_storage_fee",
+    "epoch_start_timestamp_ms",
+    "epoch_duration_ms",
+    "stake_subsidy_start_epoch",
+    "max_validator_count",
+    "min_validator_joining_stake",
+    "validator_low_stake_threshold",
+    "validator_very_low_stake_threshold",
+    "validator_low_stake_grace_period",
+    "stake_subsidy_balance",
+    "stake_subsidy_distribution_counter",
+    "stake_subsidy_current_distribution_amount",
+    "stake_subsidy_period_length",
+    "total_stake",
+    "pending_active_validators_size",
+    "pending_removals",
+    "staking_pool_mappings_size",
+    "inactive_pools_size",
+    "validator_candidates_size",
+    "at_risk_validators",
+    // ValidatorSummary
+    "voting_power",
+    "gas_price",
+    "commission_rate",
+    "next_epoch_stake",
+    "next_epoch_gas_price",
+    "next_epoch_commission_rate",
+    "staking_pool_activation_epoch",
+    "staking_pool_deactivation_epoch",
+    "staking_pool_sui_balance",
+    "rewards_pool",
+    "pool_token_balance",
+    "pending_stake",
+    "pending_total_sui_withdraw",
+    "pending_pool_token_withdraw",
+    "exchange_rates_size",
+    // SystemStateDelta
+    "since_epoch",
+    // Summaries read with `on_error=skip`
+    "skipped_validators",
+];
+
+/// Fields of [`SystemStateSummary`](super::SystemStateSummary) and
+/// [`ValidatorSummary`](super::ValidatorSummary) which are object IDs.
+const OBJECT_ID_FIELDS: &[&str] = &[
+    // SystemStateSummary
+    "pending_active_validators_id",
+    "staking_pool_mappings_id",
+    "inactive_pools_id",
+    "validator_candidates_id",
+    // ValidatorSummary
+    "operation_cap_id",
+    "staking_pool_id",
+    "exchange_rates_id",
+];
+
+/// Tables of [`SystemStateSummary`](super::SystemStateSummary), each of which is described by an
+/// `{table}_id` and `{table}_size` field.
+const TABLES: &[&str] = &[
+    "pending_active_validators",
+    "staking_pool_mappings",
+    "inactive_pools",
+    "validator_candidates",
+];
+
+/// Fields of [`ValidatorSummary`](super::ValidatorSummary) which are addresses.
+const ADDRESS_FIELDS: &[&str] = &["address"];
+
+/// Fields of [`SystemStateSummary`](super::SystemStateSummary) which hold addresses within lists,
+/// possibly alongside other values in tuples.
+const ADDRESS_LIST_FIELDS: &[&str] = &[
+    "at_risk_validators",
+    "validator_report_records",
+    // Summaries read with `on_error=skip`
+    "skipped_validator_addresses",
+];
+
+/// Describe the type of every field of a serialized summary, keyed by the path to that field.
+///
+/// The fields of the elements of a list are keyed as `list[].field`. Fields holding BigInts,
+/// either directly or as the elements of a list, are described as `bigint`, or as `u64` if they
+/// are encoded as plain integers, and object IDs and addresses as `object_id` and `address`. Any
+/// other field is described by its JSON type.
+pub(crate) fn field_types(value: &Value, bigints_as_numbers: bool) -> Map<String, Value> {
+    let mut types = Map::new();
+    collect_field_types(value, "", bigints_as_numbers, &mut types);
+    types
+}
+
+fn collect_field_types(
+    value: &Value,
+    prefix: &str,
+    bigints_as_numbers: bool,
+    types: &mut Map<String, Value>,
+) {
+    let Value::Object(map) = value else {
+        return;
+    };
+
+    for (key, field) in map {
+        let path = format!("{prefix}{key}");
+        let type_ = if BIGINT_FIELDS.contains(&key.as_str()) {
+            if bigints_as_numbers {
+                "u64"
+            } else {
+                "bigint"
+            }
+        } else if OBJECT_ID_FIELDS.contains(&key.as_str()) {
+            "object_id"
+        } else if ADDRESS_FIELDS.contains(&key.as_str()) {
+            "address"
+        } else {
+            json_type(field)
+        };
+
+        match field {
+            Value::Object(_) => {
+                collect_field_types(field, &format!("{path}."), bigints_as_numbers, types)
+            }
+            Value::Array(items) => {
+                let prefix = format!("{path}[].");
+                for item in items {
+                    collect_field_types(item, &prefix, bigints_as_numbers, types);
+                }
+            }
+            _ => {}
+        }
+
+        // Optional fields which are unset in one list element may be set in another
+        if types.get(&path).map_or(true, |existing| existing == "null") {
+            types.insert(path, type_.into());
+        }
+    }
+}
+
+fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Rewrite every `BigInt` string of a serialized summary into a plain integer.
+///
+/// This is used for encodings, like CBOR, which natively support 64-bit integers and thus have no
+/// need for the string representation used to keep JSON consumers from losing precision.
+pub(crate) fn bigints_as_numbers(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, field) in map.iter_mut() {
+                if BIGINT_FIELDS.contains(&key.as_str()) {
+                    strings_as_numbers(field);
+                } else {
+                    bigints_as_numbers(field);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(bigints_as_numbers),
+        _ => {}
+    }
+}
+
+/// Remove the `next_epoch_*` fields of each validator of a serialized summary.
+pub(crate) fn remove_next_epoch_fields(value: &mut Value) {
+    if let Some(Value::Array(validators)) = value.get_mut("active_validators") {
+        for validator in validators {
+            if let Value::Object(map) = validator {
+                map.retain(|key, _| !key.starts_with("next_epoch_"));
+            }
+        }
+    }
+}
+
+/// Remove the id and size fields of the dynamic field tables of a serialized summary.
+pub(crate) fn remove_table_fields(value: &mut Value) {
+    if let Value::Object(map) = value {
+        for table in TABLES {
+            map.remove(&format!("{table}_id"));
+            map.remove(&format!("{table}_size"));
+        }
+    }
+}
+
+/// Rename the fields of every object from `snake_case` to `camelCase`.
+pub(crate) fn camel_case_keys(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            *map = std::mem::take(map)
+                .into_iter()
+                .map(|(key, mut field)| {
+                    camel_case_keys(&mut field);
+                    (snake_to_camel_case(&key), field)
+                })
+                .collect();
+        }
+        Value::Array(items) => items.iter_mut().for_each(camel_case_keys),
+        _ => {}
+    }
+}
+
+fn snake_to_camel_case(s: &str) -> String {
+    let mut camel = String::with_capacity(s.len());
+    let mut capitalize_next = false;
+
+    for c in s.chars() {
+        if c == '_' {
+            capitalize_next = true;
+        } else if capitalize_next {
+            camel.push(c.to_ascii_uppercase());
+            capitalize_next = false;
+        } else {
+            camel.push(c);
+        }
+    }
+
+    camel
+}
+
+/// Convert a JSON value into its CBOR equivalent.
+///
+/// This can't be done by serializing the JSON value directly, as with the `arbitrary_precision`
+/// feature of `serde_json` numbers serialize as a special struct rather than as integers.
+pub(crate) fn to_cbor(value: Value) -> ciborium::value::Value {
+    use ciborium::value::Value as CborValue;
+
+    match value {
+        Value::Null => CborValue::Null,
+        Value::Bool(b) => CborValue::Bool(b),
+        Value::Number(n) => {
+            if let Some(n) = n.as_u64() {
+                CborValue::Integer(n.into())
+            } else if let Some(n) = n.as_i64() {
+                CborValue::Integer(n.into())
+            } else {
+                CborValue::Float(n.as_f64().unwrap_or(f64::NAN))
+            }
+        }
+        Value::String(s) => CborValue::Text(s),
+        Value::Array(items) => CborValue::Array(items.into_iter().map(to_cbor).collect()),
+        Value::Object(map) => CborValue::Map(
+            map.into_iter()
+                .map(|(key, value)| (CborValue::Text(key), to_cbor(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// Rewrite every object ID and address of a serialized summary from its `0x` prefixed hex
+/// representation into Base58, for consumers which expect ids in that encoding.
+pub(crate) fn ids_as_base58(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, field) in map.iter_mut() {
+                let key = key.as_str();
+                if OBJECT_ID_FIELDS.contains(&key)
+                    || ADDRESS_FIELDS.contains(&key)
+                    || ADDRESS_LIST_FIELDS.contains(&key)
+                {
+                    hex_as_base58(field);
+                } else {
+                    ids_as_base58(field);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(ids_as_base58),
+        _ => {}
+    }
+}
+
+fn hex_as_base58(value: &mut Value) {
+    use fastcrypto::encoding::{Base58, Encoding, Hex};
+
+    match value {
+        Value::String(s) => {
+            // Other values sharing a list with ids, like BigInt strings, aren't `0x` prefixed
+            if let Some(bytes) = s.strip_prefix("0x").and_then(|hex| Hex::decode(hex).ok()) {
+                *s = Base58::encode(bytes);
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(hex_as_base58),
+        _ => {}
+    }
+}
+
+fn strings_as_numbers(value: &mut Value) {
+    let number = match value {
+        Value::String(s) => s.parse::<u64>().ok(),
+        Value::Array(items) => {
+            items.iter_mut().for_each(strings_as_numbers);
+            None
+        }
+        _ => None,
+    };
+
+    if let Some(number) = number {
+        *value = Value::from(number);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::{test_summary, test_validator};
+
+    #[test]
+    fn camel_case() {
+        assert_eq!(snake_to_camel_case("epoch"), "epoch");
+        assert_eq!(
+            snake_to_camel_case("reference_gas_price"),
+            "referenceGasPrice"
+        );
+        assert_eq!(snake_to_camel_case("p2p_address"), "p2pAddress");
+    }
+
+    /// Every field of a serialized value, including those of nested objects and list elements.
+    fn fields<'a>(value: &'a Value, fields: &mut Vec<(&'a str, &'a Value)>) {
+        match value {
+            Value::Object(map) => {
+                for (key, field) in map {
+                    fields.push((key.as_str(), field));
+                    self::fields(field, fields);
+                }
+            }
+            Value::Array(items) => items.iter().for_each(|item| self::fields(item, fields)),
+            _ => {}
+        }
+    }
+
+    /// Whether `value`, or any element of it if it is a list, satisfies `f`.
+    fn holds(value: &Value, f: fn(&Value) -> bool) -> bool {
+        match value {
+            Value::Array(items) => items.iter().any(|item| holds(item, f)),
+            value => f(value),
+        }
+    }
+
+    fn is_u64(value: &Value) -> bool {
+        value.as_str().is_some_and(|s| s.parse::<u64>().is_ok())
+    }
+
+    fn is_hex(value: &Value) -> bool {
+        value.as_str().is_some_and(|s| s.starts_with("0x"))
+    }
+
+    /// The field lists above are maintained by hand, so check them against the fields which are
+    /// actually serialized, with every optional field and list populated.
+    #[test]
+    fn field_lists_match_serialization() {
+        use crate::system::{SystemStateDelta, SystemStateQueryParameters};
+        use std::collections::BTreeSet;
+        use sui_sdk2::types::Address;
+
+        let mut validator = test_validator(1);
+        validator.staking_pool_deactivation_epoch = Some(5);
+        let mut summary = test_summary(vec![validator]);
+        summary.pending_removals = vec![0];
+        summary.at_risk_validators = vec![(summary.active_validators[0].address, 3)];
+        summary.validator_report_records = vec![(
+            summary.active_validators[0].address,
+            vec![Address::new([9; 32])],
+        )];
+
+        let values = [
+            SystemStateQueryParameters::default()
+                .to_value_with_skipped(&summary, Some(&[Address::new([9; 32])]), false)
+                .unwrap(),
+            serde_json::to_value(SystemStateDelta {
+                since_epoch: 5,
+                changed_fields: Map::new(),
+                active_validators: Default::default(),
+            })
+            .unwrap(),
+        ];
+        let mut serialized = Vec::new();
+        for value in &values {
+            fields(value, &mut serialized);
+        }
+
+        let set = |fields: &[&[&'static str]]| -> BTreeSet<&str> {
+            fields
+                .iter()
+                .flat_map(|fields| fields.iter().copied())
+                .collect()
+        };
+        let serialized_where = |f: fn(&Value) -> bool| -> BTreeSet<&str> {
+            serialized
+                .iter()
+                .filter(|(_, value)| f(value))
+                .map(|(key, _)| *key)
+                .collect()
+        };
+
+        assert_eq!(
+            serialized_where(|value| holds(value, is_u64)),
+            set(&[BIGINT_FIELDS])
+        );
+        assert_eq!(
+            serialized_where(is_hex),
+            set(&[OBJECT_ID_FIELDS, ADDRESS_FIELDS])
+        );
+        assert_eq!(
+            serialized_where(|value| value.is_array() && holds(value, is_hex)),
+            set(&[ADDRESS_LIST_FIELDS])
+        );
+    }
+
+    fn cbor_field<'a>(value: &'a ciborium::value::Value, key: &str) -> &'a ciborium::value::Value {
+        value
+            .as_map()
+            .unwrap()
+            .iter()
+            .find_map(|(k, v)| (k.as_text() == Some(key)).then_some(v))
+            .unwrap()
+    }
+
+    #[test]
+    fn cbor_round_trip() {
+        use ciborium::value::Value as CborValue;
+
+        let mut summary = test_summary(vec![test_validator(1), test_validator(2)]);
+        summary.at_risk_validators = vec![(summary.active_validators[0].address, 3)];
+
+        let mut value = serde_json::to_value(&summary).unwrap();
+        bigints_as_numbers(&mut value);
+        let cbor = to_cbor(value);
+
+        let mut buf = Vec::new();
+        ciborium::ser::into_writer(&cbor, &mut buf).unwrap();
+        let decoded: CborValue = ciborium::de::from_reader(buf.as_slice()).unwrap();
+
+        assert_eq!(decoded, cbor);
+        assert_eq!(
+            cbor_field(&decoded, "epoch"),
+            &CborValue::Integer(summary.epoch.into())
+        );
+        assert_eq!(cbor_field(&decoded, "safe_mode"), &CborValue::Bool(false));
+
+        let validators = cbor_field(&decoded, "active_validators")
+            .as_array()
+            .unwrap();
+        assert_eq!(
+            cbor_field(&validators[1], "gas_price"),
+            &CborValue::Integer(summary.active_validators[1].gas_price.into())
+        );
+        assert_eq!(
+            cbor_field(&validators[0], "name"),
+            &CborValue::Text(summary.active_validators[0].name.clone())
+        );
+
+        let at_risk = cbor_field(&decoded, "at_risk_validators")
+            .as_array()
+            .unwrap();
+        assert_eq!(
+            at_risk[0].as_array().unwrap()[1],
+            CborValue::Integer(3u64.into())
+        );
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{reader::StateReader, Result};
+use axum::{extract::State, Json};
+use sui_types::digests::ChainIdentifier;
+
+use super::{reconfiguration::now_ms, SystemStateSummary};
+
+pub const GET_EPOCH_PATH: &str = "/system/epoch";
+
+pub async fn get_epoch(State(state): State<StateReader>) -> Result<Json<EpochInfo>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(EpochInfo::new(&summary)))
+}
+
+/// The current epoch and when it started.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct EpochInfo {
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub epoch: u64,
+    /// Start of the epoch, in milliseconds since the Unix epoch.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub epoch_start_timestamp_ms: u64,
+    /// Start of the epoch as an RFC 3339 timestamp in UTC, e.g.
+    /// `2023-05-01T12:30:00.250+00:00`.
+    pub epoch_start_rfc3339: String,
+}
+
+impl EpochInfo {
+    pub fn new(summary: &SystemStateSummary) -> Self {
+        Self {
+            epoch: summary.epoch,
+            epoch_start_timestamp_ms: summary.epoch_start_timestamp_ms,
+            epoch_start_rfc3339: timestamp_ms_to_rfc3339(summary.epoch_start_timestamp_ms),
+        }
+    }
+}
+
+pub const GET_EPOCH_PROGRESS_PATH: &str = "/system/epoch/progress";
+
+pub async fn get_epoch_progress(State(state): State<StateReader>) -> Result<Json<EpochProgress>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(EpochProgress::new(&summary, now_ms())))
+}
+
+/// How far through its expected duration the current epoch is, e.g. for progress bars.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EpochProgress {
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub epoch: u64,
+    /// Wall clock time since the start of the epoch, in milliseconds.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub elapsed_ms: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub duration_ms: u64,
+    /// `elapsed_ms` as a percentage of `duration_ms`, clamped to between 0 and 100 as epochs can
+    /// run over their expected duration.
+    pub progress_percent: f64,
+}
+
+impl EpochProgress {
+    pub fn new(summary: &SystemStateSummary, now_ms: u64) -> Self {
+        let elapsed_ms = now_ms.saturating_sub(summary.epoch_start_timestamp_ms);
+        let duration_ms = summary.epoch_duration_ms;
+        let progress_percent = if duration_ms == 0 {
+            100.0
+        } else {
+            (elapsed_ms as f64 / duration_ms as f64 * 100.0).clamp(0.0, 100.0)
+        };
+
+        Self {
+            epoch: summary.epoch,
+            elapsed_ms,
+            duration_ms,
+            progress_percent,
+        }
+    }
+}
+
+pub const GET_SYSTEM_INFO_PATH: &str = "/system/info";
+
+pub async fn get_system_info(State(state): State<StateReader>) -> Result<Json<SystemInfo>> {
+    state.spawn_read(read_system_info).await.map(Json)
+}
+
+pub(crate) fn read_system_info(state: &StateReader) -> Result<SystemInfo> {
+    let summary = state.get_system_state_summary()?;
+    let chain_identifier = state.inner().get_chain_identifier()?;
+
+    Ok(SystemInfo::new(&summary, chain_identifier))
+}
+
+/// The chain the system state belongs to along with its current epoch, so that clients talking
+/// to several networks can confirm they're on the intended chain before trusting epoch data.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SystemInfo {
+    /// Identifier of the chain, the first four bytes of the genesis checkpoint digest in hex.
+    pub chain_identifier: String,
+    /// Name of the chain, e.g. `mainnet`, or `unknown` if it isn't a well known network.
+    pub chain: String,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub epoch: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub protocol_version: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub system_state_version: u64,
+}
+
+impl SystemInfo {
+    pub fn new(summary: &SystemStateSummary, chain_identifier: ChainIdentifier) -> Self {
+        Self {
+            chain_identifier: chain_identifier.to_string(),
+            chain: chain_identifier.chain().as_str().to_owned(),
+            epoch: summary.epoch,
+            protocol_version: summary.protocol_version,
+            system_state_version: summary.system_state_version,
+        }
+    }
+}
+
+/// Render a timestamp in milliseconds since the Unix epoch as an RFC 3339 timestamp in UTC.
+///
+/// Timestamps too far in the future to be represented render as the latest representable time.
+fn timestamp_ms_to_rfc3339(timestamp_ms: u64) -> String {
+    use chrono::{SecondsFormat, TimeZone, Utc};
+
+    let timestamp_ms = i64::try_from(timestamp_ms).unwrap_or(i64::MAX);
+    Utc.timestamp_millis_opt(timestamp_ms)
+        .single()
+        .unwrap_or(chrono::DateTime::<Utc>::MAX_UTC)
+        .to_rfc3339_opts(SecondsFormat::Millis, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::test_summary;
+    use sui_types::digests::CheckpointDigest;
+
+    #[test]
+    fn rfc3339_rendering() {
+        assert_eq!(timestamp_ms_to_rfc3339(0), "1970-01-01T00:00:00.000+00:00");
+        assert_eq!(
+            timestamp_ms_to_rfc3339(1_682_944_200_250),
+            "2023-05-01T12:30:00.250+00:00"
+        );
+
+        let mut summary = test_summary(vec![]);
+        summary.epoch_start_timestamp_ms = 1_682_944_200_250;
+        assert_eq!(
+            EpochInfo::new(&summary),
+            EpochInfo {
+                epoch: 10,
+                epoch_start_timestamp_ms: 1_682_944_200_250,
+                epoch_start_rfc3339: "2023-05-01T12:30:00.250+00:00".to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn chain_identifier() {
+        let chain_identifier = ChainIdentifier::from(CheckpointDigest::new([0x12; 32]));
+        let mut summary = test_summary(vec![]);
+
+        let info = SystemInfo::new(&summary, chain_identifier);
+        assert_eq!(info.chain_identifier, "12121212");
+        assert_eq!(info.chain, "unknown");
+        assert_eq!(info.epoch, 10);
+
+        // The identifier doesn't change along with the rest of the system state
+        summary.epoch += 1;
+        let next = SystemInfo::new(&summary, chain_identifier);
+        assert_eq!(next.chain_identifier, info.chain_identifier);
+        assert_eq!(next.epoch, 11);
+    }
+
+    #[test]
+    fn epoch_progress() {
+        let mut summary = test_summary(vec![]);
+        summary.epoch_start_timestamp_ms = 1_000_000;
+        summary.epoch_duration_ms = 86_400_000;
+
+        let midway = EpochProgress::new(&summary, 1_000_000 + 43_200_000);
+        assert_eq!(
+            midway,
+            EpochProgress {
+                epoch: 10,
+                elapsed_ms: 43_200_000,
+                duration_ms: 86_400_000,
+                progress_percent: 50.0,
+            }
+        );
+
+        // Overdue epochs are clamped to 100%
+        let overdue = EpochProgress::new(&summary, 1_000_000 + 2 * 86_400_000);
+        assert_eq!(overdue.elapsed_ms, 2 * 86_400_000);
+        assert_eq!(overdue.progress_percent, 100.0);
+
+        // A clock behind the start of the epoch reports no progress
+        assert_eq!(EpochProgress::new(&summary, 0).progress_percent, 0.0);
+    }
+}
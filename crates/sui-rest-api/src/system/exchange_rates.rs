@@ -0,0 +1,258 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{RestError, RestService, Result};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use std::ops::RangeInclusive;
+use sui_sdk2::types::{Address, EpochId};
+use sui_types::storage::error::{Error as StorageError, Result as StorageResult};
+
+use super::{validators::find_validator, ValidatorSummary};
+
+pub const GET_VALIDATOR_EXCHANGE_RATES_PATH: &str = "/system/validators/:address/exchange-rates";
+
+/// Default maximum number of epochs of exchange rate history which can be requested at once.
+pub const DEFAULT_MAX_EXCHANGE_RATE_HISTORY_EPOCHS: u64 = 300;
+
+const DEFAULT_EXCHANGE_RATE_HISTORY_EPOCHS: u64 = 30;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct ExchangeRateHistoryQueryParameters {
+    /// Number of most recent epochs, including the current one, to return the exchange rates of.
+    /// Defaults to 30, and may not exceed the maximum configured by the server.
+    pub epochs: Option<u64>,
+}
+
+/// List the exchange rates of a validator's staking pool over its most recent epochs.
+///
+/// Epochs before the pool was activated are omitted.
+pub async fn get_validator_exchange_rates(
+    Path(address): Path<Address>,
+    Query(parameters): Query<ExchangeRateHistoryQueryParameters>,
+    State(state): State<RestService>,
+) -> Result<Json<Vec<ExchangeRate>>> {
+    let epochs = history_epochs(parameters.epochs, state.max_exchange_rate_history_epochs())?;
+
+    let exchange_rates = state
+        .reader
+        .spawn_read(move |state| {
+            let summary = state.get_system_state_summary()?;
+            let validator = find_validator(&summary, address)?;
+
+            Ok::<_, RestError>(state.get_pool_exchange_rates(
+                validator.exchange_rates_id,
+                history_range(validator, summary.epoch, epochs),
+            )?)
+        })
+        .await?
+        .into_iter()
+        .map(|(epoch, rate)| ExchangeRate { epoch, rate })
+        .collect();
+
+    Ok(Json(exchange_rates))
+}
+
+pub const GET_VALIDATORS_EXCHANGE_RATES_PATH: &str = "/system/validators/exchange-rates";
+
+/// List the exchange rates of every active validator's staking pool over its most recent epochs.
+///
+/// A validator whose exchange rates can't be read is reported with an `error` rather than failing
+/// the whole response.
+pub async fn get_validators_exchange_rates(
+    Query(parameters): Query<ExchangeRateHistoryQueryParameters>,
+    State(state): State<RestService>,
+) -> Result<Json<Vec<PoolResult<Vec<ExchangeRate>>>>> {
+    let epochs = history_epochs(parameters.epochs, state.max_exchange_rate_history_epochs())?;
+
+    let results = state
+        .reader
+        .spawn_read(move |state| {
+            let summary = state.get_system_state_summary()?;
+
+            Ok::<_, RestError>(pool_results(&summary.active_validators, |validator| {
+                let exchange_rates = state.get_pool_exchange_rates(
+                    validator.exchange_rates_id,
+                    history_range(validator, summary.epoch, epochs),
+                )?;
+                let exchange_rates = require_exchange_rates(validator, exchange_rates)?;
+
+                Ok(exchange_rates
+                    .into_iter()
+                    .map(|(epoch, rate)| ExchangeRate { epoch, rate })
+                    .collect())
+            }))
+        })
+        .await?;
+
+    Ok(Json(results))
+}
+
+/// The exchange rate of a staking pool at the start of an epoch.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ExchangeRate {
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub epoch: EpochId,
+    /// Number of pool tokens per SUI.
+    pub rate: f64,
+}
+
+/// The outcome of reading the staking pool of a single validator as part of a bulk request.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PoolResult<T> {
+    pub address: Address,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<T>,
+    /// Why the validator's pool couldn't be read, set instead of `result`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Read the staking pool of each of `validators`, recording failures per validator rather than
+/// giving up on the rest.
+pub(crate) fn pool_results<T, F>(validators: &[ValidatorSummary], mut read: F) -> Vec<PoolResult<T>>
+where
+    F: FnMut(&ValidatorSummary) -> StorageResult<T>,
+{
+    validators
+        .iter()
+        .map(|validator| match read(validator) {
+            Ok(result) => PoolResult {
+                address: validator.address,
+                result: Some(result),
+                error: None,
+            },
+            Err(e) => PoolResult {
+                address: validator.address,
+                result: None,
+                error: Some(e.to_string()),
+            },
+        })
+        .collect()
+}
+
+/// Treat an active pool without any exchange rates as missing its exchange rates table, since an
+/// entry is recorded for every epoch from the pool's activation onwards.
+pub(crate) fn require_exchange_rates(
+    validator: &ValidatorSummary,
+    exchange_rates: Vec<(EpochId, f64)>,
+) -> StorageResult<Vec<(EpochId, f64)>> {
+    if exchange_rates.is_empty() && validator.staking_pool_activation_epoch.is_some() {
+        return Err(StorageError::missing(format!(
+            "exchange rates table {} of validator {} is missing",
+            validator.exchange_rates_id, validator.address
+        )));
+    }
+
+    Ok(exchange_rates)
+}
+
+/// The `epochs` most recent epochs up to `current_epoch`, excluding those before `validator`'s
+/// pool was activated.
+fn history_range(
+    validator: &ValidatorSummary,
+    current_epoch: EpochId,
+    epochs: u64,
+) -> RangeInclusive<EpochId> {
+    let first_epoch = current_epoch
+        .saturating_sub(epochs - 1)
+        .max(validator.staking_pool_activation_epoch.unwrap_or(0));
+
+    first_epoch..=current_epoch
+}
+
+/// Resolve the number of epochs of exchange rate history requested, rejecting requests for more
+/// than `max_epochs`.
+pub(crate) fn history_epochs(requested: Option<u64>, max_epochs: u64) -> Result<u64> {
+    match requested {
+        None => Ok(DEFAULT_EXCHANGE_RATE_HISTORY_EPOCHS.min(max_epochs).max(1)),
+        Some(0) => Err(RestError::new(
+            StatusCode::BAD_REQUEST,
+            "epochs must be at least 1",
+        )),
+        Some(epochs) if epochs > max_epochs => Err(RestError::new(
+            StatusCode::BAD_REQUEST,
+            format!(
+                "epochs {epochs} exceeds the maximum exchange rate history of {max_epochs} epochs"
+            ),
+        )),
+        Some(epochs) => Ok(epochs),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::test_validator;
+    use axum::response::IntoResponse;
+
+    #[tokio::test]
+    async fn history_depth_cap() {
+        let max = DEFAULT_MAX_EXCHANGE_RATE_HISTORY_EPOCHS;
+        assert_eq!(history_epochs(None, max).unwrap(), 30);
+        assert_eq!(history_epochs(Some(max), max).unwrap(), max);
+        // The default is never more than the configured cap
+        assert_eq!(history_epochs(None, 10).unwrap(), 10);
+
+        let response = history_epochs(Some(max + 1), max)
+            .unwrap_err()
+            .into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let problem: crate::ProblemDetails = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            problem.detail.as_deref(),
+            Some("epochs 301 exceeds the maximum exchange rate history of 300 epochs")
+        );
+
+        let error = history_epochs(Some(0), max).unwrap_err().into_response();
+        assert_eq!(error.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn partial_pool_results() {
+        let validators = (0..3)
+            .map(|i| {
+                let mut validator = test_validator(i * 10);
+                validator.staking_pool_activation_epoch = Some(0);
+                validator
+            })
+            .collect::<Vec<_>>();
+
+        // The exchange rates table of the second validator is missing
+        let tables = [
+            (validators[0].exchange_rates_id, vec![(9, 0.98), (10, 0.97)]),
+            (validators[2].exchange_rates_id, vec![(10, 0.95)]),
+        ];
+        let results = pool_results(&validators, |validator| {
+            let exchange_rates = tables
+                .iter()
+                .find(|(id, _)| *id == validator.exchange_rates_id)
+                .map(|(_, exchange_rates)| exchange_rates.clone())
+                .unwrap_or_default();
+            require_exchange_rates(validator, exchange_rates)
+        });
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].address, validators[0].address);
+        assert_eq!(results[0].result, Some(vec![(9, 0.98), (10, 0.97)]));
+        assert_eq!(results[0].error, None);
+
+        assert_eq!(results[1].address, validators[1].address);
+        assert_eq!(results[1].result, None);
+        let error = results[1].error.as_deref().unwrap();
+        assert!(error.contains(&validators[1].exchange_rates_id.to_string()));
+
+        assert_eq!(results[2].result, Some(vec![(10, 0.95)]));
+        assert_eq!(results[2].error, None);
+
+        // Only the field relevant to each outcome is serialized
+        let json = serde_json::to_value(&results).unwrap();
+        assert!(json[0].get("error").is_none());
+        assert!(json[1].get("result").is_none());
+    }
+}
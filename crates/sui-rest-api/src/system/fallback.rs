@@ -0,0 +1,86 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::{Arc, RwLock};
+
+use axum::http::{header, HeaderMap, HeaderValue};
+
+use crate::{types::X_EPOCH_STALE, RestError, Result};
+
+use super::SystemStateSummary;
+
+/// Fallback for `/system` which serves the last successfully read summary, marked as stale,
+/// when reading the live system state fails, e.g. during reconfiguration.
+#[derive(Clone, Debug, Default)]
+pub struct StaleSystemStateFallback {
+    last_known_good: Arc<RwLock<Option<SystemStateSummary>>>,
+}
+
+impl StaleSystemStateFallback {
+    /// Resolve the summary to serve given the result of reading the live system state, along with
+    /// whether it is stale.
+    ///
+    /// A successful read replaces the cached summary. A failed read is only returned if there is
+    /// no cached summary to fall back to.
+    pub(crate) fn resolve<E: Into<RestError>>(
+        &self,
+        live: Result<SystemStateSummary, E>,
+    ) -> Result<(SystemStateSummary, bool)> {
+        match live {
+            Ok(summary) => {
+                *self.last_known_good.write().unwrap() = Some(summary.clone());
+                Ok((summary, false))
+            }
+            Err(e) => match self.last_known_good.read().unwrap().clone() {
+                Some(summary) => Ok((summary, true)),
+                None => Err(e.into()),
+            },
+        }
+    }
+}
+
+/// Mark a response as serving stale data.
+pub(crate) fn insert_stale_headers(headers: &mut HeaderMap) {
+    headers.insert(
+        header::WARNING,
+        HeaderValue::from_static("110 - \"Response is Stale\""),
+    );
+    headers.insert(X_EPOCH_STALE, HeaderValue::from_static("true"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::test_summary;
+    use axum::http::StatusCode;
+
+    fn read_failure() -> Result<SystemStateSummary> {
+        Err(RestError::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "system state unavailable",
+        ))
+    }
+
+    #[test]
+    fn serve_stale_summary() {
+        let fallback = StaleSystemStateFallback::default();
+
+        // Nothing to fall back to yet
+        assert!(fallback.resolve(read_failure()).is_err());
+
+        let mut summary = test_summary(vec![]);
+        summary.epoch = 5;
+        let (live, stale) = fallback.resolve(Ok::<_, RestError>(summary)).unwrap();
+        assert_eq!(live.epoch, 5);
+        assert!(!stale);
+
+        let (cached, stale) = fallback.resolve(read_failure()).unwrap();
+        assert_eq!(cached.epoch, 5);
+        assert!(stale);
+
+        let mut headers = HeaderMap::new();
+        insert_stale_headers(&mut headers);
+        assert_eq!(headers[header::WARNING], "110 - \"Response is Stale\"");
+        assert_eq!(headers[X_EPOCH_STALE], "true");
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::{Arc, RwLock};
+
+use axum::{
+    body::Bytes,
+    http::{header, HeaderMap, HeaderValue},
+    response::{IntoResponse, Response},
+};
+use flate2::{write::GzEncoder, Compression};
+
+use crate::Result;
+
+use super::SystemStateSummary;
+
+/// Cache of the gzip compressed JSON body of the default `/system` response.
+///
+/// The body is keyed by the [`checksum`](super::checksum::checksum) of the summary it was
+/// compressed from, so it's only compressed once for as long as the system state doesn't change
+/// rather than on every request, and is recompressed as soon as it does, including within an
+/// epoch.
+#[derive(Clone, Debug, Default)]
+pub struct SystemStateGzipCache {
+    cached: Arc<RwLock<Option<(String, Bytes)>>>,
+}
+
+impl SystemStateGzipCache {
+    /// The compressed body of `summary`, whose checksum is `checksum`, compressing `summary` if
+    /// there is none cached for it yet.
+    pub(crate) fn get_or_compress(
+        &self,
+        checksum: &str,
+        summary: &SystemStateSummary,
+    ) -> Result<Bytes> {
+        if let Some((cached, body)) = &*self.cached.read().unwrap() {
+            if cached == checksum {
+                return Ok(body.clone());
+            }
+        }
+
+        let body = compress(summary)?;
+        *self.cached.write().unwrap() = Some((checksum.to_owned(), body.clone()));
+
+        Ok(body)
+    }
+}
+
+fn compress(summary: &SystemStateSummary) -> Result<Bytes> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    serde_json::to_writer(&mut encoder, summary).map_err(anyhow::Error::from)?;
+
+    Ok(encoder.finish().map_err(anyhow::Error::from)?.into())
+}
+
+/// Whether the `Accept-Encoding` header of a request allows a gzip encoded response.
+pub(crate) fn accepts_gzip(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(header::ACCEPT_ENCODING)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|coding| {
+            let mut parameters = coding.split(';');
+            let coding = parameters.next().unwrap_or_default().trim();
+            // A quality of 0 explicitly rules the coding out
+            let rejected = parameters.any(|parameter| {
+                parameter
+                    .trim()
+                    .strip_prefix("q=")
+                    .and_then(|quality| quality.trim().parse::<f32>().ok())
+                    .is_some_and(|quality| quality == 0.0)
+            });
+
+            coding.eq_ignore_ascii_case("gzip") && !rejected
+        })
+}
+
+/// A JSON response with an already gzip compressed `body`.
+pub(crate) fn gzip_response(body: Bytes) -> Response {
+    (
+        [
+            (
+                header::CONTENT_TYPE,
+                HeaderValue::from_static(crate::APPLICATION_JSON),
+            ),
+            (header::CONTENT_ENCODING, HeaderValue::from_static("gzip")),
+        ],
+        body,
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::{test_summary, test_validator};
+    use std::io::Read;
+
+    fn decompress(body: &[u8]) -> SystemStateSummary {
+        let mut json = Vec::new();
+        flate2::read::GzDecoder::new(body)
+            .read_to_end(&mut json)
+            .unwrap();
+
+        serde_json::from_slice(&json).unwrap()
+    }
+
+    #[test]
+    fn compressed_once_per_state() {
+        let cache = SystemStateGzipCache::default();
+        let get_or_compress = |summary: &SystemStateSummary| {
+            cache.get_or_compress(&crate::system::checksum::checksum(summary), summary)
+        };
+        let mut summary = test_summary(vec![test_validator(1)]);
+
+        let body = get_or_compress(&summary).unwrap();
+        assert_eq!(decompress(&body).epoch, 10);
+
+        // Reused as long as the summary doesn't change
+        let reused = get_or_compress(&summary).unwrap();
+        assert_eq!(reused.as_ptr(), body.as_ptr());
+
+        // Regenerated when it changes within the epoch
+        summary.reference_gas_price += 1;
+        let regenerated = get_or_compress(&summary).unwrap();
+        assert_ne!(regenerated.as_ptr(), body.as_ptr());
+        assert_eq!(decompress(&regenerated).reference_gas_price, 1001);
+
+        // And after an epoch transition
+        summary.epoch = 11;
+        let decompressed = decompress(&get_or_compress(&summary).unwrap());
+        assert_eq!(decompressed.epoch, 11);
+        assert_eq!(decompressed.reference_gas_price, 1001);
+    }
+
+    #[tokio::test]
+    async fn vary_accept_encoding() {
+        use axum::{body::Body, http::Request};
+        use tower::ServiceExt;
+
+        let app = crate::RestService::new_without_version(Arc::new(
+            crate::reader::tests::TestReader::new(),
+        ))
+        .into_router();
+
+        // Whether or not the response is compressed, caches must key it on `Accept-Encoding`
+        for (uri, accept_encoding) in [
+            ("/system", Some("gzip")),
+            ("/system", None),
+            ("/system?naming=camel", Some("gzip")),
+            ("/system.bcs", Some("gzip")),
+        ] {
+            let mut request = Request::builder().uri(uri);
+            if let Some(accept_encoding) = accept_encoding {
+                request = request.header(header::ACCEPT_ENCODING, accept_encoding);
+            }
+            let response = app
+                .clone()
+                .oneshot(request.body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert!(response.status().is_success(), "{uri}");
+            assert_eq!(
+                response.headers()[header::VARY],
+                "accept-encoding",
+                "{uri} with {accept_encoding:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn gzip_accepted() {
+        let accepts = |value: &'static str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(header::ACCEPT_ENCODING, HeaderValue::from_static(value));
+            accepts_gzip(&headers)
+        };
+
+        assert!(accepts("gzip"));
+        assert!(accepts("br, GZIP;q=0.5"));
+        assert!(!accepts("br"));
+        assert!(!accepts("gzip;q=0"));
+        assert!(!accepts_gzip(&HeaderMap::new()));
+    }
+}
@@ -0,0 +1,203 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{reader::StateReader, types::X_SUI_CURSOR, RestError, Result};
+use axum::{
+    extract::{Query, State},
+    http::{HeaderMap, HeaderValue, StatusCode},
+    Json,
+};
+use sui_sdk2::types::ObjectId;
+
+use super::ValidatorSummary;
+
+pub const GET_INACTIVE_POOLS_PATH: &str = "/system/inactive-pools";
+
+const DEFAULT_INACTIVE_POOLS_LIMIT: usize = 50;
+const MAX_INACTIVE_POOLS_LIMIT: usize = 1000;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct InactivePoolsQueryParameters {
+    /// Maximum number of pools to return. Defaults to 50, capped at 1000.
+    pub limit: Option<u32>,
+    /// Resume listing after this entry, as returned in the `x-sui-cursor` header of the previous
+    /// page.
+    pub cursor: Option<ObjectId>,
+}
+
+impl InactivePoolsQueryParameters {
+    fn limit(&self) -> usize {
+        self.limit
+            .map(|limit| (limit as usize).clamp(1, MAX_INACTIVE_POOLS_LIMIT))
+            .unwrap_or(DEFAULT_INACTIVE_POOLS_LIMIT)
+    }
+}
+
+/// List the staking pools of validators which have left the validator set.
+///
+/// If there are further pools the cursor for the next page is returned in the `x-sui-cursor`
+/// header. A cursor which isn't an entry of the `inactive_pools_id` table is rejected with
+/// `400 Bad Request`.
+pub async fn get_inactive_pools(
+    Query(parameters): Query<InactivePoolsQueryParameters>,
+    State(state): State<StateReader>,
+) -> Result<(HeaderMap, Json<Vec<InactivePool>>)> {
+    let (cursor, limit) = (parameters.cursor, parameters.limit());
+    let (pools, next_cursor) = state
+        .spawn_read(move |state| {
+            let table_id = state.get_system_state_summary()?.inactive_pools_id;
+
+            check_cursor(cursor, |cursor| Ok(state.is_table_field(table_id, cursor)?))?;
+            paginate(cursor, limit, |cursor, limit| {
+                state.get_inactive_pools(table_id, cursor, limit)
+            })
+        })
+        .await?;
+
+    Ok((cursor_headers(next_cursor), Json(pools)))
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct InactivePool {
+    pub staking_pool_id: ObjectId,
+    /// The validator which operated this pool, as of when it became inactive.
+    pub validator: ValidatorSummary,
+}
+
+/// The headers of a page, returning the cursor of the next page in `x-sui-cursor` if there is one.
+pub(crate) fn cursor_headers(next_cursor: Option<ObjectId>) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    if let Some(next_cursor) = next_cursor {
+        headers.insert(
+            X_SUI_CURSOR,
+            HeaderValue::from_str(&next_cursor.to_string())
+                .expect("object ids are valid header values"),
+        );
+    }
+
+    headers
+}
+
+/// Fetch a single page of entries using `fetch`, which returns up to `limit` entries starting
+/// after `cursor`, each paired with the cursor identifying it.
+///
+/// One more entry than requested is fetched to determine whether there is a next page, in which
+/// case the cursor of the last returned entry is returned alongside the page.
+pub(crate) fn paginate<T, F>(
+    cursor: Option<ObjectId>,
+    limit: usize,
+    fetch: F,
+) -> Result<(Vec<T>, Option<ObjectId>)>
+where
+    F: FnOnce(Option<ObjectId>, usize) -> sui_types::storage::error::Result<Vec<(ObjectId, T)>>,
+{
+    let mut entries = fetch(cursor, limit + 1)?;
+
+    let next_cursor = if entries.len() > limit {
+        entries.truncate(limit);
+        entries.last().map(|(cursor, _)| *cursor)
+    } else {
+        None
+    };
+
+    let page = entries.into_iter().map(|(_, entry)| entry).collect();
+
+    Ok((page, next_cursor))
+}
+
+/// Reject a `cursor` for which `is_entry` doesn't hold, as it can't have been returned by a
+/// previous page of the table being listed.
+pub(crate) fn check_cursor<F>(cursor: Option<ObjectId>, is_entry: F) -> Result<()>
+where
+    F: FnOnce(ObjectId) -> Result<bool>,
+{
+    match cursor {
+        Some(cursor) if !is_entry(cursor)? => Err(RestError::new(
+            StatusCode::BAD_REQUEST,
+            format!("cursor {cursor} is not an entry of the table being listed"),
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::test_validator;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn walk_two_pages() {
+        // Fixture table, keyed by the object id of each pool's dynamic field
+        let table = (0..5u8)
+            .map(|i| {
+                let validator = test_validator(i * 10);
+                let pool = InactivePool {
+                    staking_pool_id: validator.staking_pool_id,
+                    validator,
+                };
+                (ObjectId::new([i + 1; 32]), pool)
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let fetch =
+            |cursor: Option<ObjectId>, limit: usize| -> sui_types::storage::error::Result<_> {
+                Ok(table
+                    .iter()
+                    .filter(|(id, _)| cursor.map_or(true, |cursor| **id > cursor))
+                    .take(limit)
+                    .map(|(id, pool)| (*id, pool.clone()))
+                    .collect())
+            };
+
+        let (first, cursor) = paginate(None, 3, fetch).unwrap();
+        assert_eq!(first.len(), 3);
+        assert_eq!(cursor, Some(ObjectId::new([3; 32])));
+
+        let (second, cursor) = paginate(cursor, 3, fetch).unwrap();
+        assert_eq!(second.len(), 2);
+        assert_eq!(cursor, None);
+
+        // Only cursors which are entries of the table are accepted
+        let is_entry = |cursor: ObjectId| Ok(table.contains_key(&cursor));
+        check_cursor(Some(ObjectId::new([3; 32])), is_entry).unwrap();
+        let error = check_cursor(Some(ObjectId::new([0xff; 32])), is_entry).unwrap_err();
+        assert_eq!(
+            axum::response::IntoResponse::into_response(error).status(),
+            StatusCode::BAD_REQUEST
+        );
+
+        let walked = first
+            .iter()
+            .chain(&second)
+            .map(|pool| pool.staking_pool_id)
+            .collect::<Vec<_>>();
+        let expected = table
+            .values()
+            .map(|pool| pool.staking_pool_id)
+            .collect::<Vec<_>>();
+        assert_eq!(walked, expected);
+    }
+
+    #[test]
+    fn bad_cursor() {
+        use axum::response::IntoResponse;
+
+        let query = "/?limit=10&cursor=not-an-object-id"
+            .parse::<axum::http::Uri>()
+            .unwrap();
+        let rejection = Query::<InactivePoolsQueryParameters>::try_from_uri(&query).unwrap_err();
+        assert_eq!(
+            rejection.into_response().status(),
+            axum::http::StatusCode::BAD_REQUEST
+        );
+
+        let query = format!("/?cursor={}", ObjectId::new([1; 32]))
+            .parse::<axum::http::Uri>()
+            .unwrap();
+        let Query(parameters) =
+            Query::<InactivePoolsQueryParameters>::try_from_uri(&query).unwrap();
+        assert_eq!(parameters.cursor, Some(ObjectId::new([1; 32])));
+        assert_eq!(parameters.limit(), DEFAULT_INACTIVE_POOLS_LIMIT);
+    }
+}
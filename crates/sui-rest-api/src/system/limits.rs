@@ -0,0 +1,81 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{reader::StateReader, Result};
+use axum::{extract::State, Json};
+
+use super::SystemStateSummary;
+
+pub const GET_VALIDATOR_SET_LIMITS_PATH: &str = "/system/validator-set-limits";
+
+/// Report the size of the validator set against its limit.
+///
+/// The protocol config has no parameter bounding the validator set, so the limit reported is the
+/// on-chain system parameter in effect at the current protocol version.
+pub async fn get_validator_set_limits(
+    State(state): State<StateReader>,
+) -> Result<Json<ValidatorSetLimits>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(ValidatorSetLimits::new(&summary)))
+}
+
+/// The size of the validator set against its limit, along with the changes scheduled for the end
+/// of the epoch.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ValidatorSetLimits {
+    /// Protocol version at which the limit is in effect.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub protocol_version: u64,
+    /// Maximum number of active validators at any moment, set by the `max_validator_count`
+    /// on-chain system parameter, as there is no equivalent in the protocol config.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub max_validator_count: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub current_active_count: u64,
+    /// Number of validators which will join the active set at the end of the epoch.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub pending_join_count: u64,
+    /// Number of validators which will leave the active set at the end of the epoch.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub pending_removal_count: u64,
+}
+
+impl ValidatorSetLimits {
+    pub fn new(summary: &SystemStateSummary) -> Self {
+        Self {
+            protocol_version: summary.protocol_version,
+            max_validator_count: summary.max_validator_count,
+            current_active_count: summary.active_validators.len() as u64,
+            pending_join_count: summary.pending_active_validators_size,
+            pending_removal_count: summary.pending_removals.len() as u64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::{test_summary, test_validator};
+
+    #[test]
+    fn validator_set_limits() {
+        let mut summary = test_summary((0..3).map(|i| test_validator(i * 10)).collect());
+        summary.pending_active_validators_size = 2;
+        summary.pending_removals = vec![0];
+
+        assert_eq!(
+            ValidatorSetLimits::new(&summary),
+            ValidatorSetLimits {
+                protocol_version: summary.protocol_version,
+                max_validator_count: 150,
+                current_active_count: 3,
+                pending_join_count: 2,
+                pending_removal_count: 1,
+            }
+        );
+    }
+}
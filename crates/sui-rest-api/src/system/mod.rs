@@ -0,0 +1,1927 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Instant;
+
+use crate::{
+    accept::{respond, AcceptFormat},
+    reader::StateReader,
+    response::Cbor,
+    Result,
+};
+use axum::{
+    extract::{Query, State},
+    http::{header, HeaderMap, HeaderValue},
+    response::{IntoResponse, Response},
+};
+use sui_sdk2::types::{Address, ObjectId};
+
+mod cache;
+mod delta;
+pub use delta::get_validator_changes;
+pub use delta::SystemStateDelta;
+pub use delta::ValidatorChanges;
+pub use delta::ValidatorChangesQueryParameters;
+pub use delta::ValidatorSetDelta;
+pub use delta::GET_VALIDATOR_CHANGES_PATH;
+mod encoding;
+mod fallback;
+pub use fallback::StaleSystemStateFallback;
+mod gzip;
+pub use gzip::SystemStateGzipCache;
+mod stats;
+pub use stats::get_commission_stats;
+pub use stats::get_gas_price_changes;
+pub use stats::get_gas_price_distribution;
+pub use stats::get_validator_stake_shares;
+pub use stats::get_validators_by_pool_balance;
+pub use stats::get_validators_by_stake_growth;
+pub use stats::CommissionStats;
+pub use stats::GasPriceChange;
+pub use stats::GasPriceDirection;
+pub use stats::GasPriceDistribution;
+pub use stats::Percentiles;
+pub use stats::PoolBalanceRank;
+pub use stats::PoolBalanceRankingQueryParameters;
+pub use stats::SortOrder;
+pub use stats::StakeGrowth;
+pub use stats::StakeShare;
+pub use stats::GET_COMMISSION_STATS_PATH;
+pub use stats::GET_GAS_PRICE_CHANGES_PATH;
+pub use stats::GET_GAS_PRICE_DISTRIBUTION_PATH;
+pub use stats::GET_VALIDATORS_BY_POOL_BALANCE_PATH;
+pub use stats::GET_VALIDATORS_BY_STAKE_GROWTH_PATH;
+pub use stats::GET_VALIDATOR_STAKE_SHARES_PATH;
+mod reports;
+pub use reports::get_report_graph;
+pub use reports::get_reported_validators;
+pub use reports::get_validator_reputation;
+pub use reports::ReportGraph;
+pub use reports::ReportedValidator;
+pub use reports::ReportedValidatorsQueryParameters;
+pub use reports::ValidatorReputation;
+pub use reports::GET_REPORTED_VALIDATORS_PATH;
+pub use reports::GET_REPORT_GRAPH_PATH;
+pub use reports::GET_VALIDATOR_REPUTATION_PATH;
+mod validators;
+pub use validators::get_pending_removals;
+pub use validators::get_pending_validators;
+pub use validators::get_validator;
+pub use validators::get_validator_comparison;
+pub use validators::get_validator_staking_pool;
+pub use validators::get_validators;
+pub use validators::get_validators_batch;
+pub use validators::verify_validator_proof_of_possession;
+pub use validators::Comparison;
+pub use validators::PendingRemoval;
+pub use validators::PendingValidator;
+pub use validators::PendingValidators;
+pub use validators::ProofOfPossessionVerification;
+pub use validators::StakingPoolSummary;
+pub use validators::ValidatorComparison;
+pub use validators::ValidatorComparisonQueryParameters;
+pub use validators::ValidatorNotFoundError;
+pub use validators::ValidatorWithRisk;
+pub use validators::ValidatorsQueryParameters;
+pub use validators::GET_PENDING_REMOVALS_PATH;
+pub use validators::GET_PENDING_VALIDATORS_PATH;
+pub use validators::GET_VALIDATORS_PATH;
+pub use validators::GET_VALIDATOR_COMPARISON_PATH;
+pub use validators::GET_VALIDATOR_PATH;
+pub use validators::GET_VALIDATOR_STAKING_POOL_PATH;
+pub use validators::POST_VALIDATORS_BATCH_PATH;
+pub use validators::VERIFY_VALIDATOR_PROOF_OF_POSSESSION_PATH;
+mod stake_subsidy;
+pub use stake_subsidy::get_stake_subsidy_actual;
+pub use stake_subsidy::get_stake_subsidy_balance_check;
+pub use stake_subsidy::get_stake_subsidy_runway;
+pub use stake_subsidy::get_stake_subsidy_status;
+pub use stake_subsidy::StakeSubsidyActual;
+pub use stake_subsidy::StakeSubsidyBalanceCheck;
+pub use stake_subsidy::StakeSubsidyRunway;
+pub use stake_subsidy::StakeSubsidyStatus;
+pub use stake_subsidy::GET_STAKE_SUBSIDY_ACTUAL_PATH;
+pub use stake_subsidy::GET_STAKE_SUBSIDY_BALANCE_CHECK_PATH;
+pub use stake_subsidy::GET_STAKE_SUBSIDY_RUNWAY_PATH;
+pub use stake_subsidy::GET_STAKE_SUBSIDY_STATUS_PATH;
+mod inactive_pools;
+pub use inactive_pools::get_inactive_pools;
+pub use inactive_pools::InactivePool;
+pub use inactive_pools::InactivePoolsQueryParameters;
+pub use inactive_pools::GET_INACTIVE_POOLS_PATH;
+mod total_stake;
+pub use total_stake::get_pending_stake;
+pub use total_stake::get_stake_reconciliation;
+pub use total_stake::get_total_stake;
+pub use total_stake::PendingStake;
+pub use total_stake::StakeReconciliation;
+pub use total_stake::TotalStake;
+pub use total_stake::GET_PENDING_STAKE_PATH;
+pub use total_stake::GET_STAKE_RECONCILIATION_PATH;
+pub use total_stake::GET_TOTAL_STAKE_PATH;
+mod object_refs;
+pub use object_refs::get_system_object_refs;
+pub use object_refs::GET_SYSTEM_OBJECT_REFS_PATH;
+mod rewards;
+pub use rewards::get_rewards_parameters;
+pub use rewards::get_validator_estimated_rewards;
+pub use rewards::get_validators_estimated_rewards;
+pub use rewards::EstimatedRewards;
+pub use rewards::RewardsParameters;
+pub use rewards::GET_REWARDS_PARAMETERS_PATH;
+pub use rewards::GET_VALIDATORS_ESTIMATED_REWARDS_PATH;
+pub use rewards::GET_VALIDATOR_ESTIMATED_REWARDS_PATH;
+mod node;
+pub use node::get_self_validator;
+pub use node::SelfValidator;
+pub use node::GET_SELF_VALIDATOR_PATH;
+mod tiers;
+pub use tiers::get_validator_eligibility;
+pub use tiers::get_validator_tiers;
+pub use tiers::StakeTier;
+pub use tiers::StakeTierKind;
+pub use tiers::ValidatorEligibility;
+pub use tiers::ValidatorTiers;
+pub use tiers::GET_VALIDATOR_ELIGIBILITY_PATH;
+pub use tiers::GET_VALIDATOR_TIERS_PATH;
+mod reconfiguration;
+pub use reconfiguration::get_next_reconfiguration;
+pub use reconfiguration::NextReconfiguration;
+pub use reconfiguration::GET_NEXT_RECONFIGURATION_PATH;
+mod rates;
+pub use rates::get_validator_commission_changes;
+pub use rates::get_validator_rates;
+pub use rates::CommissionChange;
+pub use rates::ValidatorRates;
+pub use rates::GET_VALIDATOR_COMMISSION_CHANGES_PATH;
+pub use rates::GET_VALIDATOR_RATES_PATH;
+mod tenure;
+pub use tenure::get_rejoined_validators;
+pub use tenure::get_validators_by_tenure;
+pub use tenure::RejoinedValidator;
+pub use tenure::ValidatorTenure;
+pub use tenure::GET_REJOINED_VALIDATORS_PATH;
+pub use tenure::GET_VALIDATORS_BY_TENURE_PATH;
+mod table_sizes;
+pub use table_sizes::get_system_table_sizes;
+pub use table_sizes::ExchangeRatesTableSize;
+pub use table_sizes::SystemTableSizes;
+pub use table_sizes::GET_SYSTEM_TABLE_SIZES_PATH;
+mod raw;
+pub use raw::get_raw_system_state;
+pub use raw::GET_RAW_SYSTEM_STATE_PATH;
+mod limits;
+pub use limits::get_validator_set_limits;
+pub use limits::ValidatorSetLimits;
+pub use limits::GET_VALIDATOR_SET_LIMITS_PATH;
+mod operation_cap;
+pub use operation_cap::get_validator_operation_cap;
+pub use operation_cap::ValidatorOperationCap;
+pub use operation_cap::GET_VALIDATOR_OPERATION_CAP_PATH;
+mod count;
+pub use count::get_validator_churn;
+pub use count::get_validator_set_size_trend;
+pub use count::get_validators_count;
+pub use count::ValidatorChurn;
+pub use count::ValidatorSetSize;
+pub use count::ValidatorSetSizeTrendQueryParameters;
+pub use count::ValidatorsCount;
+pub use count::GET_VALIDATORS_COUNT_PATH;
+pub use count::GET_VALIDATOR_CHURN_PATH;
+pub use count::GET_VALIDATOR_SET_SIZE_TREND_PATH;
+pub use count::MAX_SIZE_TREND_EPOCHS;
+mod epoch;
+pub use epoch::get_epoch;
+pub use epoch::get_epoch_progress;
+pub use epoch::get_system_info;
+pub use epoch::EpochInfo;
+pub use epoch::EpochProgress;
+pub use epoch::SystemInfo;
+pub use epoch::GET_EPOCH_PATH;
+pub use epoch::GET_EPOCH_PROGRESS_PATH;
+pub use epoch::GET_SYSTEM_INFO_PATH;
+mod next_epoch;
+pub use next_epoch::get_incomplete_next_epoch_validators;
+pub use next_epoch::IncompleteNextEpochConfig;
+pub use next_epoch::GET_INCOMPLETE_NEXT_EPOCH_VALIDATORS_PATH;
+mod staking_pools;
+pub use staking_pools::get_staking_pool_validator;
+pub use staking_pools::GET_STAKING_POOL_VALIDATOR_PATH;
+mod decentralization;
+pub use decentralization::get_chain_safety;
+pub use decentralization::get_decentralization;
+pub use decentralization::get_decentralization_warnings;
+pub use decentralization::ChainSafety;
+pub use decentralization::ConcentrationWarning;
+pub use decentralization::ConcentrationWarningKind;
+pub use decentralization::Decentralization;
+pub use decentralization::DecentralizationWarningsQueryParameters;
+pub use decentralization::VotingPowerShare;
+pub use decentralization::GET_CHAIN_SAFETY_PATH;
+pub use decentralization::GET_DECENTRALIZATION_PATH;
+pub use decentralization::GET_DECENTRALIZATION_WARNINGS_PATH;
+mod not_found;
+pub use not_found::system_fallback;
+pub use not_found::UnknownSystemPathError;
+mod bootstrap;
+pub use bootstrap::get_bootstrap;
+pub use bootstrap::BootstrapInfo;
+pub use bootstrap::GET_BOOTSTRAP_PATH;
+mod exchange_rates;
+pub use exchange_rates::get_validator_exchange_rates;
+pub use exchange_rates::get_validators_exchange_rates;
+pub use exchange_rates::ExchangeRate;
+pub use exchange_rates::ExchangeRateHistoryQueryParameters;
+pub use exchange_rates::PoolResult;
+pub use exchange_rates::DEFAULT_MAX_EXCHANGE_RATE_HISTORY_EPOCHS;
+pub use exchange_rates::GET_VALIDATORS_EXCHANGE_RATES_PATH;
+pub use exchange_rates::GET_VALIDATOR_EXCHANGE_RATES_PATH;
+mod network_index;
+pub use network_index::get_validator_address_validation;
+pub use network_index::get_validator_addresses;
+pub use network_index::get_validator_consensus_addresses;
+pub use network_index::get_validator_network_index;
+pub use network_index::MalformedNetworkAddress;
+pub use network_index::MalformedNetworkAddresses;
+pub use network_index::ValidatorConsensusAddresses;
+pub use network_index::ValidatorNetworkAddresses;
+pub use network_index::GET_VALIDATOR_ADDRESSES_PATH;
+pub use network_index::GET_VALIDATOR_ADDRESS_VALIDATION_PATH;
+pub use network_index::GET_VALIDATOR_CONSENSUS_ADDRESSES_PATH;
+pub use network_index::GET_VALIDATOR_NETWORK_INDEX_PATH;
+mod checksum;
+pub use checksum::get_system_checksum;
+pub use checksum::SystemStateChecksum;
+pub use checksum::GET_SYSTEM_CHECKSUM_PATH;
+mod protocol_config;
+pub use protocol_config::get_protocol_config;
+pub use protocol_config::ProtocolConfigParameters;
+pub use protocol_config::GET_PROTOCOL_CONFIG_PATH;
+mod ws;
+pub use ws::get_system_ws;
+pub use ws::GET_SYSTEM_WS_PATH;
+mod candidates;
+pub use candidates::get_validator_candidates;
+pub use candidates::ValidatorCandidate;
+pub use candidates::ValidatorCandidatesQueryParameters;
+pub use candidates::GET_VALIDATOR_CANDIDATES_PATH;
+mod prefer;
+mod routes;
+pub use routes::routes;
+mod stream;
+mod timing;
+mod trailing_slash;
+pub(crate) use trailing_slash::tolerate_trailing_slash;
+
+pub const GET_SYSTEM_STATE_SUMMARY_PATH: &str = "/system";
+/// Equivalent to [`GET_SYSTEM_STATE_SUMMARY_PATH`] requested with `Accept: application/json`.
+pub const GET_SYSTEM_STATE_SUMMARY_JSON_PATH: &str = "/system.json";
+/// Equivalent to [`GET_SYSTEM_STATE_SUMMARY_PATH`] requested with `Accept: application/bcs`.
+pub const GET_SYSTEM_STATE_SUMMARY_BCS_PATH: &str = "/system.bcs";
+
+/// Render the current system state summary.
+///
+/// The time spent reading the system state and serializing the response is reported in the
+/// `Server-Timing` header as `state_read` and `serialize` respectively. The validators of streamed
+/// responses are serialized as the body streams, so `serialize` only covers sizing them.
+///
+/// With `Prefer: return=minimal` the system state is still read, but the response is a
+/// `204 No Content` rather than the summary, e.g. for liveness checks of the state reader.
+///
+/// The `ETag` is weak, as the representation of the same system state varies with the `Accept`
+/// header and query parameters. See [`GET_SYSTEM_CHECKSUM_PATH`] for a byte-stable resource with a
+/// strong `ETag`.
+///
+/// Clients accepting gzip are served the default JSON representation from a compressed body
+/// which is cached until the system state changes, see [`SystemStateGzipCache`]. Every response
+/// varies on `Accept-Encoding`.
+pub async fn get_system_state_summary(
+    accept: AcceptFormat,
+    prefer: prefer::Prefer,
+    Query(parameters): Query<SystemStateQueryParameters>,
+    State(state): State<StateReader>,
+    State(fallback): State<Option<StaleSystemStateFallback>>,
+    State(gzip_cache): State<SystemStateGzipCache>,
+    headers: HeaderMap,
+) -> Result<Response> {
+    let started = Instant::now();
+    let skip_malformed = parameters.on_error == OnError::Skip && accept != AcceptFormat::Bcs;
+    let (live, skipped) = state
+        .spawn_read(move |state| {
+            if !skip_malformed {
+                return (state.get_system_state_summary(), None);
+            }
+            match state.get_system_state_summary_skipping_malformed_validators() {
+                Ok((summary, addresses)) => (Ok(summary), Some(addresses)),
+                Err(e) => (Err(e), None),
+            }
+        })
+        .await;
+    let (mut summary, stale) = match fallback {
+        Some(fallback) => fallback.resolve(live)?,
+        None => (live?, false),
+    };
+    parameters.sort.apply(&mut summary.active_validators);
+    let state_read = started.elapsed();
+    let cache_control = cache::cache_control(&summary);
+    let checksum = checksum::checksum(&summary);
+    let etag = checksum::weak_etag(&checksum);
+
+    let started = Instant::now();
+    let mut response = match parameters.since_epoch {
+        _ if prefer.return_minimal => prefer::minimal_response(),
+        // Rendering a delta reads the historical system state, so happens alongside the reads
+        Some(since_epoch) => {
+            let parameters = parameters.clone();
+            state
+                .spawn_read(move |state| {
+                    delta::render(accept, &parameters, &summary, since_epoch, |epoch| {
+                        state.get_system_state_summary_at_epoch(epoch)
+                    })
+                })
+                .await?
+        }
+        None => render(
+            accept,
+            &parameters,
+            summary,
+            skipped.as_deref(),
+            &checksum,
+            &gzip_cache,
+            &headers,
+        )?,
+    };
+    // Every representation is serialized by now, other than the validators of the streamed JSON,
+    // which are only sized up front and serialized again as the body streams
+    let serialize = started.elapsed();
+
+    decorate(
+        response.headers_mut(),
+        cache_control,
+        etag,
+        &[("state_read", state_read), ("serialize", serialize)],
+        stale,
+    );
+
+    Ok(response)
+}
+
+/// Render `summary` as requested by `accept` and `parameters`, other than as a delta.
+///
+/// `skipped` lists the validators skipped while reading a summary with `on_error=skip`, and
+/// `checksum` is that of `summary`, keying its compressed body in `gzip_cache`.
+fn render(
+    accept: AcceptFormat,
+    parameters: &SystemStateQueryParameters,
+    summary: SystemStateSummary,
+    skipped: Option<&[Address]>,
+    checksum: &str,
+    gzip_cache: &SystemStateGzipCache,
+    headers: &HeaderMap,
+) -> Result<Response> {
+    let response = match accept {
+        // The default representation is cached compressed until the system state changes, when
+        // validators are in on-chain order, and streamed otherwise
+        AcceptFormat::Json if parameters.is_default() => {
+            if parameters.sort == ValidatorOrder::Chain && gzip::accepts_gzip(headers) {
+                gzip::gzip_response(gzip_cache.get_or_compress(checksum, &summary)?)
+            } else {
+                stream::json_response(summary)?
+            }
+        }
+        AcceptFormat::Json => respond(
+            accept,
+            parameters.to_value_with_skipped(&summary, skipped, false)?,
+        )?,
+        // CBOR has native 64-bit integers so there's no need to encode BigInts as strings
+        AcceptFormat::Cbor => Cbor(encoding::to_cbor(
+            parameters.to_value_with_skipped(&summary, skipped, true)?,
+        ))
+        .into_response(),
+        // The query parameters only shape self-describing encodings
+        AcceptFormat::Bcs => respond(accept, summary)?,
+    };
+
+    Ok(response)
+}
+
+/// Add the headers describing the summary to a `/system` response, whatever its representation.
+fn decorate(
+    headers: &mut HeaderMap,
+    cache_control: HeaderValue,
+    etag: HeaderValue,
+    timings: &[(&str, std::time::Duration)],
+    stale: bool,
+) {
+    headers.insert(header::CACHE_CONTROL, cache_control);
+    headers.insert(header::ETAG, etag);
+    // Only some requests are served a compressed body, but caches need to know that any of them
+    // could have been
+    headers.insert(header::VARY, HeaderValue::from_static("accept-encoding"));
+    headers.insert(timing::SERVER_TIMING, timing::server_timing(timings));
+    if stale {
+        fallback::insert_stale_headers(headers);
+    }
+}
+
+/// Options controlling how the `/system` response is rendered.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SystemStateQueryParameters {
+    /// Naming convention used for the field names of the response. Defaults to `snake`.
+    #[serde(default)]
+    pub naming: FieldNaming,
+    /// Encoding used for 64-bit integer fields of the response. Defaults to `string`.
+    #[serde(default)]
+    pub bigint: BigIntEncoding,
+    /// Whether to include the `next_epoch_*` fields of each validator. Defaults to `true`.
+    #[serde(default = "default_include_next_epoch")]
+    pub include_next_epoch: bool,
+    /// Whether to include the id and size fields of the system's dynamic field tables, e.g.
+    /// `staking_pool_mappings_id`. Defaults to `true`.
+    #[serde(default = "default_include_tables")]
+    pub include_tables: bool,
+    /// Only return what changed since the start of this epoch, see [`SystemStateDelta`].
+    ///
+    /// Responds with `304 Not Modified` if this is the current epoch.
+    pub since_epoch: Option<u64>,
+    /// Whether to wrap the summary as `{ "data": <summary>, "types": <field types> }`, where the
+    /// sidecar `types` object describes the type of each field, for example marking which fields
+    /// are BigInt strings or object IDs. Defaults to `false`.
+    #[serde(default)]
+    pub annotate: bool,
+    /// What to do with active validators which can't be parsed. Defaults to `fail`.
+    #[serde(default)]
+    pub on_error: OnError,
+    /// Order of the active validators. Defaults to `chain`.
+    #[serde(default)]
+    pub sort: ValidatorOrder,
+    /// Encoding used for object ID and address fields of the response. Defaults to `hex`.
+    #[serde(default)]
+    pub id_encoding: IdEncoding,
+}
+
+impl Default for SystemStateQueryParameters {
+    fn default() -> Self {
+        Self {
+            naming: FieldNaming::default(),
+            bigint: BigIntEncoding::default(),
+            include_next_epoch: default_include_next_epoch(),
+            include_tables: default_include_tables(),
+            since_epoch: None,
+            annotate: false,
+            on_error: OnError::default(),
+            sort: ValidatorOrder::default(),
+            id_encoding: IdEncoding::default(),
+        }
+    }
+}
+
+fn default_include_next_epoch() -> bool {
+    true
+}
+
+fn default_include_tables() -> bool {
+    true
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldNaming {
+    /// `reference_gas_price`
+    #[default]
+    Snake,
+    /// `referenceGasPrice`
+    Camel,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OnError {
+    /// Fail the whole response.
+    #[default]
+    Fail,
+    /// Leave the validators out of the response, listing their addresses in a top-level
+    /// `skipped_validator_addresses` field alongside their number in `skipped_validators`.
+    ///
+    /// Only applies to self-describing encodings, BCS responses always fail.
+    Skip,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ValidatorOrder {
+    /// The order of the on-chain validator set, which may change between otherwise identical
+    /// system states.
+    #[default]
+    Chain,
+    /// Sorted by address, for stable output which can be cached and diffed.
+    Address,
+}
+
+impl ValidatorOrder {
+    pub(crate) fn apply(self, validators: &mut [ValidatorSummary]) {
+        match self {
+            Self::Chain => {}
+            Self::Address => validators.sort_by_key(|validator| validator.address),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BigIntEncoding {
+    /// `"1000"`, which can be parsed losslessly by any JSON consumer.
+    #[default]
+    String,
+    /// `1000`
+    ///
+    /// Note that values larger than 2^53 will lose precision when parsed by consumers which
+    /// represent JSON numbers as doubles, for example JavaScript's `JSON.parse`. Only use this with
+    /// parsers which handle 64-bit integers.
+    Number,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IdEncoding {
+    /// `"0x0101…01"`
+    #[default]
+    Hex,
+    /// `"4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi"`, as expected by some ecosystem tools.
+    Base58,
+}
+
+impl SystemStateQueryParameters {
+    fn is_default(&self) -> bool {
+        self.naming == FieldNaming::Snake
+            && self.bigint == BigIntEncoding::String
+            && self.include_next_epoch
+            && self.include_tables
+            && self.since_epoch.is_none()
+            && !self.annotate
+            && self.on_error == OnError::Fail
+            && self.id_encoding == IdEncoding::Hex
+    }
+
+    /// Serialize `summary` into a generic value with all of the requested options applied.
+    fn to_value(
+        &self,
+        summary: &SystemStateSummary,
+        bigints_as_numbers: bool,
+    ) -> Result<serde_json::Value> {
+        self.to_value_with_skipped(summary, None, bigints_as_numbers)
+    }
+
+    /// Serialize `summary` like [`Self::to_value`], additionally listing the `skipped` validators
+    /// of a summary read with `on_error=skip`.
+    fn to_value_with_skipped(
+        &self,
+        summary: &SystemStateSummary,
+        skipped: Option<&[Address]>,
+        bigints_as_numbers: bool,
+    ) -> Result<serde_json::Value> {
+        let mut value = self.serialize(summary)?;
+        if let (Some(skipped), serde_json::Value::Object(map)) = (skipped, &mut value) {
+            map.insert(
+                "skipped_validators".to_owned(),
+                skipped.len().to_string().into(),
+            );
+            map.insert(
+                "skipped_validator_addresses".to_owned(),
+                serde_json::to_value(skipped).map_err(anyhow::Error::from)?,
+            );
+        }
+
+        if !self.annotate {
+            return Ok(self.encode(value, bigints_as_numbers));
+        }
+
+        // Types are derived from the original field names, before any renaming
+        let mut types = serde_json::Value::Object(encoding::field_types(
+            &value,
+            bigints_as_numbers || self.bigint == BigIntEncoding::Number,
+        ));
+        if self.naming == FieldNaming::Camel {
+            encoding::camel_case_keys(&mut types);
+        }
+
+        Ok(serde_json::json!({
+            "data": self.encode(value, bigints_as_numbers),
+            "types": types,
+        }))
+    }
+
+    /// Serialize `summary` with the options which select which fields are included applied.
+    fn serialize(&self, summary: &SystemStateSummary) -> Result<serde_json::Value> {
+        let mut value = serde_json::to_value(summary).map_err(anyhow::Error::from)?;
+
+        if !self.include_next_epoch {
+            encoding::remove_next_epoch_fields(&mut value);
+        }
+        if !self.include_tables {
+            encoding::remove_table_fields(&mut value);
+        }
+
+        Ok(value)
+    }
+
+    /// Apply the options which select how the fields of a serialized value are encoded.
+    fn encode(&self, mut value: serde_json::Value, bigints_as_numbers: bool) -> serde_json::Value {
+        if bigints_as_numbers || self.bigint == BigIntEncoding::Number {
+            encoding::bigints_as_numbers(&mut value);
+        }
+        if self.id_encoding == IdEncoding::Base58 {
+            encoding::ids_as_base58(&mut value);
+        }
+
+        // Renaming needs to happen last as the other transformations operate on the original
+        // field names
+        if self.naming == FieldNaming::Camel {
+            encoding::camel_case_keys(&mut value);
+        }
+
+        value
+    }
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SystemStateSummary {
+    /// The current epoch ID, starting from 0.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub epoch: u64,
+    /// The current protocol version, starting from 1.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub protocol_version: u64,
+    /// The current version of the system state data structure type.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub system_state_version: u64,
+    /// The storage rebates of all the objects on-chain stored in the storage fund.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub storage_fund_total_object_storage_rebates: u64,
+    /// The non-refundable portion of the storage fund coming from storage reinvestment, non-refundable
+    /// storage rebates and any leftover staking rewards.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub storage_fund_non_refundable_balance: u64,
+    /// The reference gas price for the current epoch.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub reference_gas_price: u64,
+    /// Whether the system is running in a downgraded safe mode due to a non-recoverable bug.
+    /// This is set whenever we failed to execute advance_epoch, and ended up executing advance_epoch_safe_mode.
+    /// It can be reset once we are able to successfully execute advance_epoch.
+    pub safe_mode: bool,
+    /// Amount of storage rewards accumulated (and not yet distributed) during safe mode.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub safe_mode_storage_rewards: u64,
+    /// Amount of computation rewards accumulated (and not yet distributed) during safe mode.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub safe_mode_computation_rewards: u64,
+    /// Amount of storage rebates accumulated (and not yet burned) during safe mode.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub safe_mode_storage_rebates: u64,
+    /// Amount of non-refundable storage fee accumulated during safe mode.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub safe_mode_non_refundable_storage_fee: u64,
+    /// Unix timestamp of the current epoch start
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub epoch_start_timestamp_ms: u64,
+
+    // System parameters
+    /// The duration of an epoch, in milliseconds.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub epoch_duration_ms: u64,
+
+    /// The starting epoch in which stake subsidies start being paid out
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub stake_subsidy_start_epoch: u64,
+
+    /// Maximum number of active validators at any moment.
+    /// We do not allow the number of validators in any epoch to go above this.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub max_validator_count: u64,
+
+    /// Lower-bound on the amount of stake required to become a validator.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub min_validator_joining_stake: u64,
+
+    /// Validators with stake amount below `validator_low_stake_threshold` are considered to
+    /// have low stake and will be escorted out of the validator set after being below this
+    /// threshold for more than `validator_low_stake_grace_period` number of epochs.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub validator_low_stake_threshold: u64,
+
+    /// Validators with stake below `validator_very_low_stake_threshold` will be removed
+    /// immediately at epoch change, no grace period.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub validator_very_low_stake_threshold: u64,
+
+    /// A validator can have stake below `validator_low_stake_threshold`
+    /// for this many epochs before being kicked out.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub validator_low_stake_grace_period: u64,
+
+    // Stake subsidy information
+    /// Balance of SUI set aside for stake subsidies that will be drawn down over time.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub stake_subsidy_balance: u64,
+    /// This counter may be different from the current epoch number if
+    /// in some epochs we decide to skip the subsidy.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub stake_subsidy_distribution_counter: u64,
+    /// The amount of stake subsidy to be drawn down per epoch.
+    /// This amount decays and decreases over time.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub stake_subsidy_current_distribution_amount: u64,
+    /// Number of distributions to occur before the distribution amount decays.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub stake_subsidy_period_length: u64,
+    /// The rate at which the distribution amount decays at the end of each
+    /// period. Expressed in basis points.
+    pub stake_subsidy_decrease_rate: u16,
+
+    // Validator set
+    /// Total amount of stake from all active validators at the beginning of the epoch.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub total_stake: u64,
+    /// The list of active validators in the current epoch.
+    pub active_validators: Vec<ValidatorSummary>,
+    /// ID of the object that contains the list of new validators that will join at the end of the epoch.
+    pub pending_active_validators_id: ObjectId,
+    /// Number of new validators that will join at the end of the epoch.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub pending_active_validators_size: u64,
+    /// Removal requests from the validators. Each element is an index
+    /// pointing to `active_validators`.
+    #[serde_as(as = "Vec<sui_types::sui_serde::BigInt<u64>>")]
+    pub pending_removals: Vec<u64>,
+    /// ID of the object that maps from staking pool's ID to the sui address of a validator.
+    pub staking_pool_mappings_id: ObjectId,
+    /// Number of staking pool mappings.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub staking_pool_mappings_size: u64,
+    /// ID of the object that maps from a staking pool ID to the inactive validator that has that pool as its staking pool.
+    pub inactive_pools_id: ObjectId,
+    /// Number of inactive staking pools.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub inactive_pools_size: u64,
+    /// ID of the object that stores preactive validators, mapping their addresses to their `Validator` structs.
+    pub validator_candidates_id: ObjectId,
+    /// Number of preactive validators.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub validator_candidates_size: u64,
+    /// Map storing the number of epochs for which each validator has been below the low stake threshold.
+    #[serde_as(as = "Vec<(_, sui_types::sui_serde::BigInt<u64>)>")]
+    pub at_risk_validators: Vec<(Address, u64)>,
+    /// A map storing the records of validator reporting each other.
+    pub validator_report_records: Vec<(Address, Vec<Address>)>,
+}
+
+/// This is the REST type for the sui validator. It flattens all inner structures
+/// to top-level fields so that they are decoupled from the internal definitions.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ValidatorSummary {
+    // Metadata
+    pub address: Address,
+    pub protocol_public_key: sui_sdk2::types::Bls12381PublicKey,
+    pub network_public_key: sui_sdk2::types::Ed25519PublicKey,
+    pub worker_public_key: sui_sdk2::types::Ed25519PublicKey,
+    #[serde_as(as = "fastcrypto::encoding::Base64")]
+    pub proof_of_possession_bytes: Vec<u8>,
+    pub name: String,
+    pub description: String,
+    pub image_url: String,
+    pub project_url: String,
+    pub net_address: String,
+    pub p2p_address: String,
+    pub primary_address: String,
+    pub worker_address: String,
+    pub next_epoch_protocol_public_key: Option<sui_sdk2::types::Bls12381PublicKey>,
+    pub next_epoch_network_public_key: Option<sui_sdk2::types::Ed25519PublicKey>,
+    pub next_epoch_worker_public_key: Option<sui_sdk2::types::Ed25519PublicKey>,
+    #[serde_as(as = "Option<fastcrypto::encoding::Base64>")]
+    pub next_epoch_proof_of_possession: Option<Vec<u8>>,
+    pub next_epoch_net_address: Option<String>,
+    pub next_epoch_p2p_address: Option<String>,
+    pub next_epoch_primary_address: Option<String>,
+    pub next_epoch_worker_address: Option<String>,
+
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub voting_power: u64,
+    pub operation_cap_id: ObjectId,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub gas_price: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub commission_rate: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub next_epoch_stake: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub next_epoch_gas_price: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub next_epoch_commission_rate: u64,
+
+    // Staking pool information
+    /// ID of the staking pool object.
+    pub staking_pool_id: ObjectId,
+    /// The epoch at which this pool became active.
+    #[serde_as(as = "Option<sui_types::sui_serde::BigInt<u64>>")]
+    pub staking_pool_activation_epoch: Option<u64>,
+    /// The epoch at which this staking pool ceased to be active. `None` = {pre-active, active},
+    #[serde_as(as = "Option<sui_types::sui_serde::BigInt<u64>>")]
+    pub staking_pool_deactivation_epoch: Option<u64>,
+    /// The total number of SUI tokens in this pool.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub staking_pool_sui_balance: u64,
+    /// The epoch stake rewards will be added here at the end of each epoch.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub rewards_pool: u64,
+    /// Total number of pool tokens issued by the pool.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub pool_token_balance: u64,
+    /// Pending stake amount for this epoch.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub pending_stake: u64,
+    /// Pending stake withdrawn during the current epoch, emptied at epoch boundaries.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub pending_total_sui_withdraw: u64,
+    /// Pending pool token withdrawn during the current epoch, emptied at epoch boundaries.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub pending_pool_token_withdraw: u64,
+    /// ID of the exchange rate table object.
+    pub exchange_rates_id: ObjectId,
+    /// Number of exchange rates in the table.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub exchange_rates_size: u64,
+}
+
+/// A validator key which couldn't be parsed while converting a validator summary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MalformedValidatorKey {
+    /// Address of the validator owning the key.
+    pub address: Address,
+    /// Name of the field holding the key, e.g. `protocol_public_key`.
+    pub field: &'static str,
+}
+
+/// One or more validators have keys which couldn't be parsed.
+///
+/// Every malformed key is collected rather than just the first one encountered, so that a single
+/// error reports all of the offending validators.
+#[derive(Debug)]
+pub struct MalformedValidatorKeysError {
+    keys: Vec<MalformedValidatorKey>,
+}
+
+impl MalformedValidatorKeysError {
+    pub fn keys(&self) -> &[MalformedValidatorKey] {
+        &self.keys
+    }
+}
+
+impl std::fmt::Display for MalformedValidatorKeysError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "malformed validator keys: ")?;
+
+        for (i, key) in self.keys.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} {}", key.address, key.field)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for MalformedValidatorKeysError {}
+
+impl From<MalformedValidatorKeysError> for crate::RestError {
+    fn from(value: MalformedValidatorKeysError) -> Self {
+        Self::new(
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            value.to_string(),
+        )
+    }
+}
+
+/// Converting the keys of the whole validator set taking longer than this gets logged.
+const SLOW_VALIDATOR_KEY_CONVERSION_THRESHOLD: std::time::Duration =
+    std::time::Duration::from_millis(5);
+
+/// Convert a set of validators, logging the number of keys converted if it takes at least
+/// `slow_threshold`.
+///
+/// Every validator is converted before bailing so that all malformed keys get reported.
+fn convert_validators(
+    validators: Vec<sui_types::sui_system_state::sui_system_state_summary::SuiValidatorSummary>,
+    slow_threshold: std::time::Duration,
+) -> Result<Vec<ValidatorSummary>, MalformedValidatorKeysError> {
+    let (validators, malformed) = convert_valid_validators(validators, slow_threshold);
+    if !malformed.is_empty() {
+        return Err(MalformedValidatorKeysError { keys: malformed });
+    }
+
+    Ok(validators)
+}
+
+/// Convert a set of validators like [`convert_validators`], but leaving out the validators with
+/// malformed keys rather than failing, along with the keys which were malformed.
+fn convert_valid_validators(
+    validators: Vec<sui_types::sui_system_state::sui_system_state_summary::SuiValidatorSummary>,
+    slow_threshold: std::time::Duration,
+) -> (Vec<ValidatorSummary>, Vec<MalformedValidatorKey>) {
+    let start = std::time::Instant::now();
+    let validator_count = validators.len();
+    let mut key_count = 0;
+    let mut malformed = Vec::new();
+
+    let validators = validators
+        .into_iter()
+        .filter_map(|validator| {
+            // The protocol, network and worker keys, and whichever next epoch ones are set
+            key_count += 3 + [
+                validator.next_epoch_protocol_pubkey_bytes.is_some(),
+                validator.next_epoch_network_pubkey_bytes.is_some(),
+                validator.next_epoch_worker_pubkey_bytes.is_some(),
+            ]
+            .into_iter()
+            .filter(|set| *set)
+            .count();
+
+            ValidatorSummary::try_from(validator)
+                .map_err(|e| malformed.extend(e.keys))
+                .ok()
+        })
+        .collect();
+
+    let elapsed = start.elapsed();
+    if elapsed >= slow_threshold {
+        tracing::debug!(
+            validator_count,
+            key_count,
+            elapsed_ms = elapsed.as_millis() as u64,
+            "slow validator key conversion"
+        );
+    }
+
+    (validators, malformed)
+}
+
+/// Parse a single key of the validator `address`, recording the `field` as malformed on failure.
+fn parse_key<T, E>(
+    address: Address,
+    field: &'static str,
+    key: Result<T, E>,
+    malformed: &mut Vec<MalformedValidatorKey>,
+) -> Option<T> {
+    match key {
+        Ok(key) => Some(key),
+        Err(_) => {
+            malformed.push(MalformedValidatorKey { address, field });
+            None
+        }
+    }
+}
+
+impl TryFrom<sui_types::sui_system_state::sui_system_state_summary::SuiValidatorSummary>
+    for ValidatorSummary
+{
+    type Error = MalformedValidatorKeysError;
+
+    fn try_from(
+        value: sui_types::sui_system_state::sui_system_state_summary::SuiValidatorSummary,
+    ) -> Result<Self, Self::Error> {
+        let sui_types::sui_system_state::sui_system_state_summary::SuiValidatorSummary {
+            sui_address,
+            protocol_pubkey_bytes,
+            network_pubkey_bytes,
+            worker_pubkey_bytes,
+            proof_of_possession_bytes,
+            name,
+            description,
+            image_url,
+            project_url,
+            net_address,
+            p2p_address,
+            primary_address,
+            worker_address,
+            next_epoch_protocol_pubkey_bytes,
+            next_epoch_proof_of_possession,
+            next_epoch_network_pubkey_bytes,
+            next_epoch_worker_pubkey_bytes,
+            next_epoch_net_address,
+            next_epoch_p2p_address,
+            next_epoch_primary_address,
+            next_epoch_worker_address,
+            voting_power,
+            operation_cap_id,
+            gas_price,
+            commission_rate,
+            next_epoch_stake,
+            next_epoch_gas_price,
+            next_epoch_commission_rate,
+            staking_pool_id,
+            staking_pool_activation_epoch,
+            staking_pool_deactivation_epoch,
+            staking_pool_sui_balance,
+            rewards_pool,
+            pool_token_balance,
+            pending_stake,
+            pending_total_sui_withdraw,
+            pending_pool_token_withdraw,
+            exchange_rates_id,
+            exchange_rates_size,
+        } = value;
+
+        use sui_sdk2::types::{Bls12381PublicKey, Ed25519PublicKey};
+
+        let address = sui_address.into();
+        let mut malformed = Vec::new();
+        let protocol_public_key = parse_key(
+            address,
+            "protocol_public_key",
+            Bls12381PublicKey::from_bytes(protocol_pubkey_bytes),
+            &mut malformed,
+        );
+        let network_public_key = parse_key(
+            address,
+            "network_public_key",
+            Ed25519PublicKey::from_bytes(network_pubkey_bytes),
+            &mut malformed,
+        );
+        let worker_public_key = parse_key(
+            address,
+            "worker_public_key",
+            Ed25519PublicKey::from_bytes(worker_pubkey_bytes),
+            &mut malformed,
+        );
+        let next_epoch_protocol_public_key = parse_key(
+            address,
+            "next_epoch_protocol_public_key",
+            next_epoch_protocol_pubkey_bytes
+                .map(Bls12381PublicKey::from_bytes)
+                .transpose(),
+            &mut malformed,
+        );
+        let next_epoch_network_public_key = parse_key(
+            address,
+            "next_epoch_network_public_key",
+            next_epoch_network_pubkey_bytes
+                .map(Ed25519PublicKey::from_bytes)
+                .transpose(),
+            &mut malformed,
+        );
+        let next_epoch_worker_public_key = parse_key(
+            address,
+            "next_epoch_worker_public_key",
+            next_epoch_worker_pubkey_bytes
+                .map(Ed25519PublicKey::from_bytes)
+                .transpose(),
+            &mut malformed,
+        );
+
+        let (
+            Some(protocol_public_key),
+            Some(network_public_key),
+            Some(worker_public_key),
+            Some(next_epoch_protocol_public_key),
+            Some(next_epoch_network_public_key),
+            Some(next_epoch_worker_public_key),
+        ) = (
+            protocol_public_key,
+            network_public_key,
+            worker_public_key,
+            next_epoch_protocol_public_key,
+            next_epoch_network_public_key,
+            next_epoch_worker_public_key,
+        )
+        else {
+            return Err(MalformedValidatorKeysError { keys: malformed });
+        };
+
+        Ok(Self {
+            address,
+            protocol_public_key,
+            network_public_key,
+            worker_public_key,
+            proof_of_possession_bytes,
+            name,
+            description,
+            image_url,
+            project_url,
+            net_address,
+            p2p_address,
+            primary_address,
+            worker_address,
+            next_epoch_protocol_public_key,
+            next_epoch_network_public_key,
+            next_epoch_worker_public_key,
+            next_epoch_proof_of_possession,
+            next_epoch_net_address,
+            next_epoch_p2p_address,
+            next_epoch_primary_address,
+            next_epoch_worker_address,
+            voting_power,
+            operation_cap_id: operation_cap_id.into(),
+            gas_price,
+            commission_rate,
+            next_epoch_stake,
+            next_epoch_gas_price,
+            next_epoch_commission_rate,
+            staking_pool_id: staking_pool_id.into(),
+            staking_pool_activation_epoch,
+            staking_pool_deactivation_epoch,
+            staking_pool_sui_balance,
+            rewards_pool,
+            pool_token_balance,
+            pending_stake,
+            pending_total_sui_withdraw,
+            pending_pool_token_withdraw,
+            exchange_rates_id: exchange_rates_id.into(),
+            exchange_rates_size,
+        })
+    }
+}
+
+impl TryFrom<sui_types::sui_system_state::sui_system_state_summary::SuiSystemStateSummary>
+    for SystemStateSummary
+{
+    type Error = MalformedValidatorKeysError;
+
+    fn try_from(
+        value: sui_types::sui_system_state::sui_system_state_summary::SuiSystemStateSummary,
+    ) -> Result<Self, Self::Error> {
+        let (summary, malformed) = Self::convert(value);
+        if !malformed.is_empty() {
+            return Err(MalformedValidatorKeysError { keys: malformed });
+        }
+
+        Ok(summary)
+    }
+}
+
+impl SystemStateSummary {
+    /// Convert a system state summary, leaving out the active validators which can't be parsed
+    /// rather than failing.
+    ///
+    /// Returns the addresses of the validators which were left out.
+    pub(crate) fn skipping_malformed_validators(
+        value: sui_types::sui_system_state::sui_system_state_summary::SuiSystemStateSummary,
+    ) -> (Self, Vec<Address>) {
+        let (summary, malformed) = Self::convert(value);
+
+        let mut skipped = Vec::<Address>::new();
+        for key in malformed {
+            if !skipped.contains(&key.address) {
+                skipped.push(key.address);
+            }
+        }
+
+        (summary, skipped)
+    }
+
+    fn convert(
+        value: sui_types::sui_system_state::sui_system_state_summary::SuiSystemStateSummary,
+    ) -> (Self, Vec<MalformedValidatorKey>) {
+        let sui_types::sui_system_state::sui_system_state_summary::SuiSystemStateSummary {
+            epoch,
+            protocol_version,
+            system_state_version,
+            storage_fund_total_object_storage_rebates,
+            storage_fund_non_refundable_balance,
+            reference_gas_price,
+            safe_mode,
+            safe_mode_storage_rewards,
+            safe_mode_computation_rewards,
+            safe_mode_storage_rebates,
+            safe_mode_non_refundable_storage_fee,
+            epoch_start_timestamp_ms,
+            epoch_duration_ms,
+            stake_subsidy_start_epoch,
+            max_validator_count,
+            min_validator_joining_stake,
+            validator_low_stake_threshold,
+            validator_very_low_stake_threshold,
+            validator_low_stake_grace_period,
+            stake_subsidy_balance,
+            stake_subsidy_distribution_counter,
+            stake_subsidy_current_distribution_amount,
+            stake_subsidy_period_length,
+            stake_subsidy_decrease_rate,
+            total_stake,
+            active_validators,
+            pending_active_validators_id,
+            pending_active_validators_size,
+            pending_removals,
+            staking_pool_mappings_id,
+            staking_pool_mappings_size,
+            inactive_pools_id,
+            inactive_pools_size,
+            validator_candidates_id,
+            validator_candidates_size,
+            at_risk_validators,
+            validator_report_records,
+        } = value;
+
+        let (active_validators, malformed) =
+            convert_valid_validators(active_validators, SLOW_VALIDATOR_KEY_CONVERSION_THRESHOLD);
+
+        let summary = Self {
+            epoch,
+            protocol_version,
+            system_state_version,
+            storage_fund_total_object_storage_rebates,
+            storage_fund_non_refundable_balance,
+            reference_gas_price,
+            safe_mode,
+            safe_mode_storage_rewards,
+            safe_mode_computation_rewards,
+            safe_mode_storage_rebates,
+            safe_mode_non_refundable_storage_fee,
+            epoch_start_timestamp_ms,
+            epoch_duration_ms,
+            stake_subsidy_start_epoch,
+            max_validator_count,
+            min_validator_joining_stake,
+            validator_low_stake_threshold,
+            validator_very_low_stake_threshold,
+            validator_low_stake_grace_period,
+            stake_subsidy_balance,
+            stake_subsidy_distribution_counter,
+            stake_subsidy_current_distribution_amount,
+            stake_subsidy_period_length,
+            stake_subsidy_decrease_rate,
+            total_stake,
+            active_validators,
+            pending_active_validators_id: pending_active_validators_id.into(),
+            pending_active_validators_size,
+            pending_removals,
+            staking_pool_mappings_id: staking_pool_mappings_id.into(),
+            staking_pool_mappings_size,
+            inactive_pools_id: inactive_pools_id.into(),
+            inactive_pools_size,
+            validator_candidates_id: validator_candidates_id.into(),
+            validator_candidates_size,
+            at_risk_validators: at_risk_validators
+                .into_iter()
+                .map(|(address, idx)| (address.into(), idx))
+                .collect(),
+            validator_report_records: validator_report_records
+                .into_iter()
+                .map(|(address, reports)| {
+                    (
+                        address.into(),
+                        reports.into_iter().map(Into::into).collect(),
+                    )
+                })
+                .collect(),
+        };
+
+        (summary, malformed)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// A validator with distinct, deterministic identifiers derived from `seed`.
+    pub(crate) fn test_validator(seed: u8) -> ValidatorSummary {
+        ValidatorSummary {
+            address: Address::new([seed; 32]),
+            protocol_public_key: sui_sdk2::types::Bls12381PublicKey::new([seed; 96]),
+            network_public_key: sui_sdk2::types::Ed25519PublicKey::new([seed; 32]),
+            worker_public_key: sui_sdk2::types::Ed25519PublicKey::new([seed; 32]),
+            proof_of_possession_bytes: vec![seed; 48],
+            name: format!("validator-{seed}"),
+            description: String::new(),
+            image_url: String::new(),
+            project_url: String::new(),
+            net_address: "/dns/localhost/tcp/8080/http".to_owned(),
+            p2p_address: "/dns/localhost/udp/8084".to_owned(),
+            primary_address: "/dns/localhost/udp/8081".to_owned(),
+            worker_address: "/dns/localhost/udp/8082".to_owned(),
+            next_epoch_protocol_public_key: None,
+            next_epoch_network_public_key: None,
+            next_epoch_worker_public_key: None,
+            next_epoch_proof_of_possession: None,
+            next_epoch_net_address: None,
+            next_epoch_p2p_address: None,
+            next_epoch_primary_address: None,
+            next_epoch_worker_address: None,
+            voting_power: 0,
+            operation_cap_id: ObjectId::new([seed.wrapping_add(1); 32]),
+            gas_price: 1000,
+            commission_rate: 200,
+            next_epoch_stake: 0,
+            next_epoch_gas_price: 1000,
+            next_epoch_commission_rate: 200,
+            staking_pool_id: ObjectId::new([seed.wrapping_add(2); 32]),
+            staking_pool_activation_epoch: Some(0),
+            staking_pool_deactivation_epoch: None,
+            staking_pool_sui_balance: 0,
+            rewards_pool: 0,
+            pool_token_balance: 0,
+            pending_stake: 0,
+            pending_total_sui_withdraw: 0,
+            pending_pool_token_withdraw: 0,
+            exchange_rates_id: ObjectId::new([seed.wrapping_add(3); 32]),
+            exchange_rates_size: 0,
+        }
+    }
+
+    #[test]
+    fn camel_case_naming() {
+        let summary = test_summary(vec![test_validator(1)]);
+        let parameters = SystemStateQueryParameters {
+            naming: FieldNaming::Camel,
+            ..Default::default()
+        };
+
+        let value = parameters.to_value(&summary, false).unwrap();
+        assert_eq!(
+            value["referenceGasPrice"],
+            serde_json::Value::from(summary.reference_gas_price.to_string())
+        );
+        assert!(value.get("reference_gas_price").is_none());
+        assert!(value["activeValidators"][0]
+            .get("nextEpochGasPrice")
+            .is_some());
+
+        let value = SystemStateQueryParameters::default()
+            .to_value(&summary, false)
+            .unwrap();
+        assert!(value.get("reference_gas_price").is_some());
+        assert!(value.get("referenceGasPrice").is_none());
+    }
+
+    #[test]
+    fn exclude_next_epoch() {
+        let mut validator = test_validator(1);
+        validator.next_epoch_net_address = Some("/dns/localhost/tcp/9090/http".to_owned());
+        let summary = test_summary(vec![validator]);
+
+        let value = SystemStateQueryParameters::default()
+            .to_value(&summary, false)
+            .unwrap();
+        let validator = value["active_validators"][0].as_object().unwrap();
+        assert!(validator.contains_key("next_epoch_net_address"));
+        assert!(validator.contains_key("next_epoch_stake"));
+
+        let parameters = SystemStateQueryParameters {
+            include_next_epoch: false,
+            ..Default::default()
+        };
+        assert!(!parameters.is_default());
+
+        let value = parameters.to_value(&summary, false).unwrap();
+        let validator = value["active_validators"][0].as_object().unwrap();
+        assert!(!validator.keys().any(|key| key.starts_with("next_epoch_")));
+        assert!(validator.contains_key("net_address"));
+        assert!(validator.contains_key("gas_price"));
+    }
+
+    #[test]
+    fn exclude_tables() {
+        const TABLE_FIELDS: &[&str] = &[
+            "staking_pool_mappings_id",
+            "staking_pool_mappings_size",
+            "inactive_pools_id",
+            "inactive_pools_size",
+            "validator_candidates_id",
+            "validator_candidates_size",
+            "pending_active_validators_id",
+            "pending_active_validators_size",
+        ];
+        let summary = test_summary(vec![test_validator(1)]);
+
+        let value = SystemStateQueryParameters::default()
+            .to_value(&summary, false)
+            .unwrap();
+        for field in TABLE_FIELDS {
+            assert!(value.get(field).is_some(), "{field}");
+        }
+
+        let parameters = SystemStateQueryParameters {
+            include_tables: false,
+            ..Default::default()
+        };
+        assert!(!parameters.is_default());
+
+        let value = parameters.to_value(&summary, false).unwrap();
+        for field in TABLE_FIELDS {
+            assert!(value.get(field).is_none(), "{field}");
+        }
+        assert!(value.get("epoch").is_some());
+        // The tables of the validators' staking pools are left alone
+        assert!(value["active_validators"][0]
+            .get("exchange_rates_id")
+            .is_some());
+    }
+
+    #[test]
+    fn bigint_encoding() {
+        let summary = test_summary(vec![test_validator(1)]);
+
+        let value = SystemStateQueryParameters::default()
+            .to_value(&summary, false)
+            .unwrap();
+        assert_eq!(value["epoch"], serde_json::Value::from("10"));
+
+        let parameters = SystemStateQueryParameters {
+            bigint: BigIntEncoding::Number,
+            ..Default::default()
+        };
+        assert!(!parameters.is_default());
+
+        let value = parameters.to_value(&summary, false).unwrap();
+        assert_eq!(value["epoch"], serde_json::Value::from(10u64));
+        assert_eq!(
+            value["active_validators"][0]["gas_price"],
+            serde_json::Value::from(1000u64)
+        );
+        // Non-BigInt fields are unaffected
+        assert_eq!(
+            value["active_validators"][0]["name"],
+            serde_json::Value::from("validator-1")
+        );
+    }
+
+    #[test]
+    fn annotated_field_types() {
+        let mut validator = test_validator(1);
+        validator.staking_pool_activation_epoch = Some(3);
+        let summary = test_summary(vec![validator]);
+        let parameters = SystemStateQueryParameters {
+            annotate: true,
+            ..Default::default()
+        };
+        assert!(!parameters.is_default());
+
+        let value = parameters.to_value(&summary, false).unwrap();
+        assert_eq!(
+            value["data"],
+            SystemStateQueryParameters::default()
+                .to_value(&summary, false)
+                .unwrap()
+        );
+
+        let types = &value["types"];
+        assert_eq!(types["epoch"], "bigint");
+        assert_eq!(types["pending_removals"], "bigint");
+        assert_eq!(types["pending_active_validators_id"], "object_id");
+        assert_eq!(types["safe_mode"], "boolean");
+        assert_eq!(types["active_validators"], "array");
+        assert_eq!(types["active_validators[].address"], "address");
+        assert_eq!(types["active_validators[].gas_price"], "bigint");
+        assert_eq!(
+            types["active_validators[].staking_pool_activation_epoch"],
+            "bigint"
+        );
+        assert_eq!(types["active_validators[].staking_pool_id"], "object_id");
+        assert_eq!(types["active_validators[].name"], "string");
+
+        let parameters = SystemStateQueryParameters {
+            annotate: true,
+            naming: FieldNaming::Camel,
+            bigint: BigIntEncoding::Number,
+            ..Default::default()
+        };
+        let value = parameters.to_value(&summary, false).unwrap();
+        assert_eq!(value["data"]["epoch"], serde_json::Value::from(10u64));
+        assert_eq!(value["types"]["epoch"], "u64");
+        assert_eq!(
+            value["types"]["activeValidators[].operationCapId"],
+            "object_id"
+        );
+    }
+
+    #[tokio::test]
+    async fn malformed_validator_keys() {
+        use axum::response::IntoResponse;
+        use sui_types::base_types::SuiAddress;
+        use sui_types::sui_system_state::sui_system_state_summary::{
+            SuiSystemStateSummary, SuiValidatorSummary,
+        };
+
+        let validator = |seed: u8| SuiValidatorSummary {
+            sui_address: SuiAddress::from(Address::new([seed; 32])),
+            protocol_pubkey_bytes: vec![seed; 96],
+            network_pubkey_bytes: vec![seed; 32],
+            worker_pubkey_bytes: vec![seed; 32],
+            ..Default::default()
+        };
+        let bad_protocol_key = SuiValidatorSummary {
+            protocol_pubkey_bytes: vec![2; 10],
+            ..validator(2)
+        };
+        let bad_network_keys = SuiValidatorSummary {
+            network_pubkey_bytes: vec![],
+            next_epoch_network_pubkey_bytes: Some(vec![3; 31]),
+            ..validator(3)
+        };
+
+        let summary = SuiSystemStateSummary {
+            active_validators: vec![validator(1)],
+            ..Default::default()
+        };
+        let summary = SystemStateSummary::try_from(summary).unwrap();
+        assert_eq!(summary.active_validators[0].address, Address::new([1; 32]));
+
+        let summary = SuiSystemStateSummary {
+            active_validators: vec![validator(1), bad_protocol_key, bad_network_keys],
+            ..Default::default()
+        };
+        let error = SystemStateSummary::try_from(summary).unwrap_err();
+        assert_eq!(
+            error.keys(),
+            [
+                MalformedValidatorKey {
+                    address: Address::new([2; 32]),
+                    field: "protocol_public_key",
+                },
+                MalformedValidatorKey {
+                    address: Address::new([3; 32]),
+                    field: "network_public_key",
+                },
+                MalformedValidatorKey {
+                    address: Address::new([3; 32]),
+                    field: "next_epoch_network_public_key",
+                },
+            ]
+        );
+
+        let response = crate::RestError::from(error).into_response();
+        assert_eq!(
+            response.status(),
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR
+        );
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let problem: crate::ProblemDetails = serde_json::from_slice(&body).unwrap();
+        let detail = problem.detail.unwrap();
+        assert!(detail.contains(&format!("{} protocol_public_key", Address::new([2; 32]))));
+        assert!(detail.contains(&format!("{} network_public_key", Address::new([3; 32]))));
+    }
+
+    #[test]
+    fn sort_by_address() {
+        let parameters = SystemStateQueryParameters {
+            sort: ValidatorOrder::Address,
+            ..Default::default()
+        };
+
+        // The same validator set in different on-chain orders
+        let render = |seeds: [u8; 4]| {
+            let mut summary = test_summary(seeds.into_iter().map(test_validator).collect());
+            parameters.sort.apply(&mut summary.active_validators);
+            serde_json::to_vec(&parameters.to_value(&summary, false).unwrap()).unwrap()
+        };
+        let sorted = render([30, 10, 40, 20]);
+        assert_eq!(render([30, 10, 40, 20]), sorted);
+        assert_eq!(render([40, 30, 20, 10]), sorted);
+        assert_eq!(render([10, 20, 30, 40]), sorted);
+
+        let value: serde_json::Value = serde_json::from_slice(&sorted).unwrap();
+        let names = value["active_validators"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|validator| validator["name"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                "validator-10",
+                "validator-20",
+                "validator-30",
+                "validator-40"
+            ]
+        );
+
+        // The on-chain order is kept by default
+        let mut validators = vec![test_validator(30), test_validator(10)];
+        ValidatorOrder::default().apply(&mut validators);
+        assert_eq!(validators[0].name, "validator-30");
+    }
+
+    #[test]
+    fn base58_ids() {
+        use fastcrypto::encoding::{Base58, Encoding};
+
+        let mut summary = test_summary(vec![test_validator(1), test_validator(2)]);
+        summary.at_risk_validators = vec![(summary.active_validators[1].address, 3)];
+        let parameters = SystemStateQueryParameters {
+            id_encoding: IdEncoding::Base58,
+            ..Default::default()
+        };
+        assert!(!parameters.is_default());
+
+        let hex = SystemStateQueryParameters::default()
+            .to_value(&summary, false)
+            .unwrap();
+        let base58 = parameters.to_value(&summary, false).unwrap();
+
+        // The same address in both encodings
+        let address = Base58::encode([2; 32]);
+        assert_eq!(
+            hex["active_validators"][1]["address"],
+            serde_json::Value::from(format!("0x{}", "02".repeat(32)))
+        );
+        assert_eq!(
+            base58["active_validators"][1]["address"],
+            serde_json::Value::from(address.clone())
+        );
+        assert_eq!(
+            base58["at_risk_validators"],
+            serde_json::json!([[address, "3"]])
+        );
+
+        let staking_pool_id = hex["active_validators"][0]["staking_pool_id"]
+            .as_str()
+            .unwrap();
+        assert!(staking_pool_id.starts_with("0x"));
+        let decoded = Base58::decode(
+            base58["active_validators"][0]["staking_pool_id"]
+                .as_str()
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            format!("0x{}", fastcrypto::encoding::Hex::encode(decoded)),
+            staking_pool_id
+        );
+
+        // Other fields are left alone
+        assert_eq!(base58["epoch"], hex["epoch"]);
+        assert_eq!(
+            base58["active_validators"][0]["name"],
+            hex["active_validators"][0]["name"]
+        );
+    }
+
+    #[test]
+    fn skip_malformed_validators() {
+        use sui_types::base_types::SuiAddress;
+        use sui_types::sui_system_state::sui_system_state_summary::{
+            SuiSystemStateSummary, SuiValidatorSummary,
+        };
+
+        let validator = |seed: u8| SuiValidatorSummary {
+            sui_address: SuiAddress::from(Address::new([seed; 32])),
+            protocol_pubkey_bytes: vec![seed; 96],
+            network_pubkey_bytes: vec![seed; 32],
+            worker_pubkey_bytes: vec![seed; 32],
+            ..Default::default()
+        };
+        // Both of the corrupt validator's keys are malformed, but it's only skipped once
+        let corrupt = SuiValidatorSummary {
+            protocol_pubkey_bytes: vec![2; 10],
+            worker_pubkey_bytes: vec![],
+            ..validator(2)
+        };
+        let summary = SuiSystemStateSummary {
+            active_validators: vec![validator(1), corrupt, validator(3)],
+            ..Default::default()
+        };
+
+        // Strict by default
+        assert!(SystemStateSummary::try_from(summary.clone()).is_err());
+
+        let (summary, skipped) = SystemStateSummary::skipping_malformed_validators(summary);
+        assert_eq!(skipped, [Address::new([2; 32])]);
+        assert_eq!(
+            summary
+                .active_validators
+                .iter()
+                .map(|validator| validator.address)
+                .collect::<Vec<_>>(),
+            [Address::new([1; 32]), Address::new([3; 32])]
+        );
+
+        let parameters = SystemStateQueryParameters {
+            on_error: OnError::Skip,
+            ..Default::default()
+        };
+        assert!(!parameters.is_default());
+
+        let value = parameters
+            .to_value_with_skipped(&summary, Some(&skipped), false)
+            .unwrap();
+        assert_eq!(value["skipped_validators"], serde_json::Value::from("1"));
+        assert_eq!(
+            value["skipped_validator_addresses"],
+            serde_json::to_value(&skipped).unwrap()
+        );
+        assert_eq!(value["active_validators"].as_array().unwrap().len(), 2);
+
+        // The count is a BigInt like any other
+        let value = parameters
+            .to_value_with_skipped(&summary, Some(&skipped), true)
+            .unwrap();
+        assert_eq!(value["skipped_validators"], serde_json::Value::from(1u64));
+
+        // Summaries read strictly don't mention skipped validators at all
+        let value = parameters.to_value(&summary, false).unwrap();
+        assert!(value.get("skipped_validators").is_none());
+    }
+
+    #[test]
+    fn slow_validator_key_conversion_logged() {
+        use std::io::Write;
+        use std::sync::{Arc, Mutex};
+        use sui_types::base_types::SuiAddress;
+        use sui_types::sui_system_state::sui_system_state_summary::SuiValidatorSummary;
+
+        #[derive(Clone, Default)]
+        struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for CapturedLogs {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let validators = (1..=2)
+            .map(|seed: u8| SuiValidatorSummary {
+                sui_address: SuiAddress::from(Address::new([seed; 32])),
+                protocol_pubkey_bytes: vec![seed; 96],
+                network_pubkey_bytes: vec![seed; 32],
+                worker_pubkey_bytes: vec![seed; 32],
+                next_epoch_network_pubkey_bytes: (seed == 2).then(|| vec![seed; 32]),
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+
+        let logs = CapturedLogs::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_ansi(false)
+            .with_writer({
+                let logs = logs.clone();
+                move || logs.clone()
+            })
+            .finish();
+
+        // Every conversion exceeds a zero threshold
+        let converted = tracing::subscriber::with_default(subscriber, || {
+            convert_validators(validators.clone(), std::time::Duration::ZERO)
+        })
+        .unwrap();
+        assert_eq!(converted.len(), 2);
+
+        let logs = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+        assert!(logs.contains("slow validator key conversion"), "{logs}");
+        assert!(logs.contains("validator_count=2"), "{logs}");
+        assert!(logs.contains("key_count=7"), "{logs}");
+
+        // Nothing is logged for fast conversions
+        let logs = CapturedLogs::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_writer({
+                let logs = logs.clone();
+                move || logs.clone()
+            })
+            .finish();
+        tracing::subscriber::with_default(subscriber, || {
+            convert_validators(validators, std::time::Duration::MAX)
+        })
+        .unwrap();
+        assert!(logs.0.lock().unwrap().is_empty());
+    }
+
+    /// One [`test_validator`] per element of `values`, with spaced seeds, each customized by
+    /// applying `set` with its value.
+    pub(crate) fn validators_with<T>(
+        mut set: impl FnMut(&mut ValidatorSummary, T),
+        values: impl IntoIterator<Item = T>,
+    ) -> Vec<ValidatorSummary> {
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| {
+                let mut validator = test_validator(i as u8 * 10);
+                set(&mut validator, value);
+                validator
+            })
+            .collect()
+    }
+
+    pub(crate) fn test_summary(active_validators: Vec<ValidatorSummary>) -> SystemStateSummary {
+        SystemStateSummary {
+            epoch: 10,
+            protocol_version: 42,
+            system_state_version: 2,
+            storage_fund_total_object_storage_rebates: 0,
+            storage_fund_non_refundable_balance: 0,
+            reference_gas_price: 1000,
+            safe_mode: false,
+            safe_mode_storage_rewards: 0,
+            safe_mode_computation_rewards: 0,
+            safe_mode_storage_rebates: 0,
+            safe_mode_non_refundable_storage_fee: 0,
+            epoch_start_timestamp_ms: 0,
+            epoch_duration_ms: 86_400_000,
+            stake_subsidy_start_epoch: 0,
+            max_validator_count: 150,
+            min_validator_joining_stake: 30_000_000_000_000_000,
+            validator_low_stake_threshold: 20_000_000_000_000_000,
+            validator_very_low_stake_threshold: 15_000_000_000_000_000,
+            validator_low_stake_grace_period: 7,
+            stake_subsidy_balance: 0,
+            stake_subsidy_distribution_counter: 0,
+            stake_subsidy_current_distribution_amount: 0,
+            stake_subsidy_period_length: 30,
+            stake_subsidy_decrease_rate: 1000,
+            total_stake: active_validators
+                .iter()
+                .map(|validator| validator.staking_pool_sui_balance)
+                .sum(),
+            active_validators,
+            pending_active_validators_id: ObjectId::new([0xa0; 32]),
+            pending_active_validators_size: 0,
+            pending_removals: vec![],
+            staking_pool_mappings_id: ObjectId::new([0xa1; 32]),
+            staking_pool_mappings_size: 0,
+            inactive_pools_id: ObjectId::new([0xa2; 32]),
+            inactive_pools_size: 0,
+            validator_candidates_id: ObjectId::new([0xa3; 32]),
+            validator_candidates_size: 0,
+            at_risk_validators: vec![],
+            validator_report_records: vec![],
+        }
+    }
+
+    /// An on-chain inner system state at epoch 10 with no validators, the counterpart of
+    /// [`test_summary`] for code reading the raw system state objects.
+    pub(crate) fn test_inner_state(
+    ) -> sui_types::sui_system_state::sui_system_state_inner_v2::SuiSystemStateInnerV2 {
+        use sui_types::balance::Balance;
+        use sui_types::base_types::ObjectID;
+        use sui_types::collection_types::{Bag, Table, TableVec, VecMap};
+        use sui_types::sui_system_state::sui_system_state_inner_v1::{
+            StakeSubsidyV1, StorageFundV1, ValidatorSetV1,
+        };
+        use sui_types::sui_system_state::sui_system_state_inner_v2::{
+            SuiSystemStateInnerV2, SystemParametersV2,
+        };
+
+        let table = |byte| Table {
+            id: ObjectID::from_single_byte(byte),
+            size: 0,
+        };
+
+        SuiSystemStateInnerV2 {
+            epoch: 10,
+            protocol_version: 42,
+            system_state_version: 2,
+            validators: ValidatorSetV1 {
+                total_stake: 1_000,
+                active_validators: vec![],
+                pending_active_validators: TableVec { contents: table(2) },
+                pending_removals: vec![3],
+                staking_pool_mappings: table(4),
+                inactive_validators: table(5),
+                validator_candidates: table(6),
+                at_risk_validators: VecMap { contents: vec![] },
+                extra_fields: Bag::default(),
+            },
+            storage_fund: StorageFundV1 {
+                total_object_storage_rebates: Balance::new(7),
+                non_refundable_balance: Balance::new(8),
+            },
+            parameters: SystemParametersV2 {
+                epoch_duration_ms: 86_400_000,
+                stake_subsidy_start_epoch: 0,
+                min_validator_count: 4,
+                max_validator_count: 150,
+                min_validator_joining_stake: 30_000_000,
+                validator_low_stake_threshold: 20_000_000,
+                validator_very_low_stake_threshold: 15_000_000,
+                validator_low_stake_grace_period: 7,
+                extra_fields: Bag::default(),
+            },
+            reference_gas_price: 1_000,
+            validator_report_records: VecMap { contents: vec![] },
+            stake_subsidy: StakeSubsidyV1 {
+                balance: Balance::new(9),
+                distribution_counter: 1,
+                current_distribution_amount: 100,
+                stake_subsidy_period_length: 30,
+                stake_subsidy_decrease_rate: 1_000,
+                extra_fields: Bag::default(),
+            },
+            safe_mode: false,
+            safe_mode_storage_rewards: Balance::new(0),
+            safe_mode_computation_rewards: Balance::new(0),
+            safe_mode_storage_rebates: 0,
+            safe_mode_non_refundable_storage_fee: 0,
+            epoch_start_timestamp_ms: 1_700_000_000_000,
+            extra_fields: Bag::default(),
+        }
+    }
+}
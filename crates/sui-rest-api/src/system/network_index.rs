@@ -0,0 +1,319 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    accept::{respond_with_cbor, AcceptFormat},
+    reader::StateReader,
+    Result,
+};
+use axum::{extract::State, response::Response, Json};
+use mysten_network::Multiaddr;
+use sui_sdk2::types::{Address, Ed25519PublicKey};
+
+use super::ValidatorSummary;
+
+pub const GET_VALIDATOR_NETWORK_INDEX_PATH: &str = "/system/validators/network-index";
+
+/// List the network addresses of every active validator, e.g. for bootstrapping P2P
+/// connections.
+///
+/// Requested with `Accept: application/bcs` the index is BCS encoded for compactness.
+pub async fn get_validator_network_index(
+    accept: AcceptFormat,
+    State(state): State<StateReader>,
+) -> Result<Response> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+    let index = network_index(&summary.active_validators);
+
+    Ok(respond_with_cbor(accept, index))
+}
+
+/// How to reach a validator over the network, as multiaddrs.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ValidatorNetworkAddresses {
+    pub address: Address,
+    pub net_address: String,
+    pub p2p_address: String,
+    pub primary_address: String,
+}
+
+pub(crate) fn network_index(validators: &[ValidatorSummary]) -> Vec<ValidatorNetworkAddresses> {
+    validators
+        .iter()
+        .map(|validator| ValidatorNetworkAddresses {
+            address: validator.address,
+            net_address: validator.net_address.clone(),
+            p2p_address: validator.p2p_address.clone(),
+            primary_address: validator.primary_address.clone(),
+        })
+        .collect()
+}
+
+pub const GET_VALIDATOR_ADDRESSES_PATH: &str = "/system/validators/addresses";
+
+/// List the addresses of the active validators, for clients which resolve their details
+/// elsewhere.
+///
+/// Requested with `Accept: application/bcs` the list is BCS encoded for compactness.
+pub async fn get_validator_addresses(
+    accept: AcceptFormat,
+    State(state): State<StateReader>,
+) -> Result<Response> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+    let addresses = validator_addresses(&summary.active_validators);
+
+    Ok(respond_with_cbor(accept, addresses))
+}
+
+pub(crate) fn validator_addresses(validators: &[ValidatorSummary]) -> Vec<Address> {
+    validators
+        .iter()
+        .map(|validator| validator.address)
+        .collect()
+}
+
+pub const GET_VALIDATOR_ADDRESS_VALIDATION_PATH: &str = "/system/validators/address-validation";
+
+/// List the active validators with network addresses which don't parse as multiaddrs, for
+/// operator tooling. Validators whose addresses are all well-formed are omitted.
+pub async fn get_validator_address_validation(
+    State(state): State<StateReader>,
+) -> Result<Json<Vec<MalformedNetworkAddresses>>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(malformed_network_addresses(
+        &summary.active_validators,
+    )))
+}
+
+/// The network addresses of a validator which couldn't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct MalformedNetworkAddresses {
+    pub address: Address,
+    pub name: String,
+    pub fields: Vec<MalformedNetworkAddress>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct MalformedNetworkAddress {
+    /// Name of the field holding the address, e.g. `p2p_address`.
+    pub field: String,
+    pub value: String,
+    /// Why the address couldn't be parsed.
+    pub error: String,
+}
+
+pub(crate) fn malformed_network_addresses(
+    validators: &[ValidatorSummary],
+) -> Vec<MalformedNetworkAddresses> {
+    validators
+        .iter()
+        .filter_map(|validator| {
+            let fields = [
+                ("net_address", &validator.net_address),
+                ("p2p_address", &validator.p2p_address),
+                ("primary_address", &validator.primary_address),
+                ("worker_address", &validator.worker_address),
+            ]
+            .into_iter()
+            .filter_map(|(field, value)| {
+                let error = value.parse::<Multiaddr>().err()?;
+
+                Some(MalformedNetworkAddress {
+                    field: field.to_owned(),
+                    value: value.clone(),
+                    error: error.to_string(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+            (!fields.is_empty()).then(|| MalformedNetworkAddresses {
+                address: validator.address,
+                name: validator.name.clone(),
+                fields,
+            })
+        })
+        .collect()
+}
+
+pub const GET_VALIDATOR_CONSENSUS_ADDRESSES_PATH: &str = "/system/validators/consensus-addresses";
+
+/// List the consensus network addresses and keys of every active validator, the subset of their
+/// details needed to bootstrap consensus connectivity.
+///
+/// Requested with `Accept: application/bcs` the list is BCS encoded.
+pub async fn get_validator_consensus_addresses(
+    accept: AcceptFormat,
+    State(state): State<StateReader>,
+) -> Result<Response> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+    let addresses = consensus_addresses(&summary.active_validators);
+
+    Ok(respond_with_cbor(accept, addresses))
+}
+
+/// How to reach a validator's consensus primary and worker.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ValidatorConsensusAddresses {
+    pub address: Address,
+    pub primary_address: String,
+    pub worker_address: String,
+    pub network_public_key: Ed25519PublicKey,
+    pub worker_public_key: Ed25519PublicKey,
+}
+
+pub(crate) fn consensus_addresses(
+    validators: &[ValidatorSummary],
+) -> Vec<ValidatorConsensusAddresses> {
+    validators
+        .iter()
+        .map(|validator| ValidatorConsensusAddresses {
+            address: validator.address,
+            primary_address: validator.primary_address.clone(),
+            worker_address: validator.worker_address.clone(),
+            network_public_key: validator.network_public_key,
+            worker_public_key: validator.worker_public_key,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::test_validator;
+
+    #[test]
+    fn addresses_and_bcs_round_trip() {
+        let validators = (0..3)
+            .map(|i| {
+                let mut validator = test_validator(i * 10);
+                validator.net_address = format!("/dns/validator-{i}/tcp/8080/http");
+                validator.p2p_address = format!("/dns/validator-{i}/udp/8084");
+                validator.primary_address = format!("/dns/validator-{i}/udp/8081");
+                validator
+            })
+            .collect::<Vec<_>>();
+
+        let index = network_index(&validators);
+        assert_eq!(index.len(), validators.len());
+        for (entry, validator) in index.iter().zip(&validators) {
+            assert_eq!(entry.address, validator.address);
+            assert_eq!(entry.net_address, validator.net_address);
+            assert_eq!(entry.p2p_address, validator.p2p_address);
+            assert_eq!(entry.primary_address, validator.primary_address);
+        }
+
+        let bytes = bcs::to_bytes(&index).unwrap();
+        let decoded: Vec<ValidatorNetworkAddresses> = bcs::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, index);
+    }
+
+    #[test]
+    fn active_validator_addresses() {
+        let validators = (0..4).map(|i| test_validator(i * 10)).collect::<Vec<_>>();
+
+        let addresses = validator_addresses(&validators);
+        assert_eq!(addresses.len(), validators.len());
+        for (address, validator) in addresses.iter().zip(&validators) {
+            assert_eq!(*address, validator.address);
+        }
+
+        let bytes = bcs::to_bytes(&addresses).unwrap();
+        let decoded: Vec<Address> = bcs::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, addresses);
+
+        assert!(validator_addresses(&[]).is_empty());
+    }
+
+    #[test]
+    fn malformed_p2p_address() {
+        let validators = (0..3)
+            .map(|i| {
+                let mut validator = test_validator(i * 10);
+                validator.net_address = format!("/dns/validator-{i}/tcp/8080/http");
+                validator.p2p_address = format!("/dns/validator-{i}/udp/8084");
+                validator.primary_address = format!("/dns/validator-{i}/udp/8081");
+                validator.worker_address = format!("/dns/validator-{i}/udp/8082");
+                validator
+            })
+            .collect::<Vec<_>>();
+        assert!(malformed_network_addresses(&validators).is_empty());
+
+        let mut validators = validators;
+        validators[1].p2p_address = "validator-1:8084".to_owned();
+
+        let malformed = malformed_network_addresses(&validators);
+        assert_eq!(malformed.len(), 1);
+        assert_eq!(malformed[0].address, validators[1].address);
+        assert_eq!(malformed[0].name, validators[1].name);
+        assert_eq!(malformed[0].fields.len(), 1);
+        assert_eq!(malformed[0].fields[0].field, "p2p_address");
+        assert_eq!(malformed[0].fields[0].value, "validator-1:8084");
+        assert!(!malformed[0].fields[0].error.is_empty());
+    }
+
+    #[test]
+    fn consensus_address_mapping() {
+        let validators = (0..3)
+            .map(|i| {
+                let mut validator = test_validator(i * 10);
+                validator.primary_address = format!("/dns/validator-{i}/udp/8081");
+                validator.worker_address = format!("/dns/validator-{i}/udp/8082");
+                validator.network_public_key = Ed25519PublicKey::new([i + 1; 32]);
+                validator.worker_public_key = Ed25519PublicKey::new([i + 100; 32]);
+                validator
+            })
+            .collect::<Vec<_>>();
+
+        let addresses = consensus_addresses(&validators);
+        assert_eq!(addresses.len(), validators.len());
+        for (entry, validator) in addresses.iter().zip(&validators) {
+            assert_eq!(entry.address, validator.address);
+            assert_eq!(entry.primary_address, validator.primary_address);
+            assert_eq!(entry.worker_address, validator.worker_address);
+            assert_eq!(entry.network_public_key, validator.network_public_key);
+            assert_eq!(entry.worker_public_key, validator.worker_public_key);
+        }
+
+        let bytes = bcs::to_bytes(&addresses).unwrap();
+        let decoded: Vec<ValidatorConsensusAddresses> = bcs::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, addresses);
+    }
+
+    #[tokio::test]
+    async fn consensus_addresses_formats() {
+        use axum::{
+            body::Body,
+            http::{header, Request, StatusCode},
+        };
+        use tower::ServiceExt;
+
+        let app = crate::RestService::new_without_version(std::sync::Arc::new(
+            crate::reader::tests::TestReader::new(),
+        ))
+        .into_router();
+
+        for format in [AcceptFormat::Json, AcceptFormat::Cbor, AcceptFormat::Bcs] {
+            let request = Request::builder()
+                .uri("/system/validators/consensus-addresses")
+                .header(header::ACCEPT, format.mime_type())
+                .body(Body::empty())
+                .unwrap();
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK, "{format:?}");
+            assert_eq!(
+                response.headers()[header::CONTENT_TYPE],
+                format.mime_type(),
+                "{format:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{reader::StateReader, Result};
+use axum::{extract::State, Json};
+use sui_sdk2::types::Address;
+
+use super::ValidatorSummary;
+
+pub const GET_INCOMPLETE_NEXT_EPOCH_VALIDATORS_PATH: &str =
+    "/system/validators/incomplete-next-epoch";
+
+pub async fn get_incomplete_next_epoch_validators(
+    State(state): State<StateReader>,
+) -> Result<Json<Vec<IncompleteNextEpochConfig>>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(incomplete_next_epoch_configs(
+        &summary.active_validators,
+    )))
+}
+
+/// A validator which has staged some, but not all, of its next epoch configuration.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct IncompleteNextEpochConfig {
+    pub address: Address,
+    pub name: String,
+    /// The `next_epoch_*` fields which are set.
+    pub populated: Vec<String>,
+    /// The `next_epoch_*` fields which aren't set.
+    pub missing: Vec<String>,
+}
+
+/// Which of the staged `next_epoch_*` configuration fields of `validator` are set.
+fn next_epoch_fields(validator: &ValidatorSummary) -> [(&'static str, bool); 8] {
+    [
+        (
+            "next_epoch_protocol_public_key",
+            validator.next_epoch_protocol_public_key.is_some(),
+        ),
+        (
+            "next_epoch_network_public_key",
+            validator.next_epoch_network_public_key.is_some(),
+        ),
+        (
+            "next_epoch_worker_public_key",
+            validator.next_epoch_worker_public_key.is_some(),
+        ),
+        (
+            "next_epoch_proof_of_possession",
+            validator.next_epoch_proof_of_possession.is_some(),
+        ),
+        (
+            "next_epoch_net_address",
+            validator.next_epoch_net_address.is_some(),
+        ),
+        (
+            "next_epoch_p2p_address",
+            validator.next_epoch_p2p_address.is_some(),
+        ),
+        (
+            "next_epoch_primary_address",
+            validator.next_epoch_primary_address.is_some(),
+        ),
+        (
+            "next_epoch_worker_address",
+            validator.next_epoch_worker_address.is_some(),
+        ),
+    ]
+}
+
+/// Collect the validators for which some but not all of the next epoch configuration is set,
+/// which may indicate a partially applied rotation.
+pub(crate) fn incomplete_next_epoch_configs(
+    validators: &[ValidatorSummary],
+) -> Vec<IncompleteNextEpochConfig> {
+    validators
+        .iter()
+        .filter_map(|validator| {
+            let (populated, missing): (Vec<_>, Vec<_>) = next_epoch_fields(validator)
+                .into_iter()
+                .partition(|(_, set)| *set);
+            if populated.is_empty() || missing.is_empty() {
+                return None;
+            }
+
+            let names = |fields: Vec<(&str, bool)>| {
+                fields
+                    .into_iter()
+                    .map(|(field, _)| field.to_owned())
+                    .collect()
+            };
+            Some(IncompleteNextEpochConfig {
+                address: validator.address,
+                name: validator.name.clone(),
+                populated: names(populated),
+                missing: names(missing),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::test_validator;
+
+    #[test]
+    fn partial_next_epoch_config() {
+        // No staged changes
+        let unchanged = test_validator(0);
+
+        let mut partial = test_validator(10);
+        partial.next_epoch_network_public_key =
+            Some(sui_sdk2::types::Ed25519PublicKey::new([11; 32]));
+
+        let mut complete = test_validator(20);
+        complete.next_epoch_protocol_public_key =
+            Some(sui_sdk2::types::Bls12381PublicKey::new([21; 96]));
+        complete.next_epoch_network_public_key =
+            Some(sui_sdk2::types::Ed25519PublicKey::new([21; 32]));
+        complete.next_epoch_worker_public_key =
+            Some(sui_sdk2::types::Ed25519PublicKey::new([21; 32]));
+        complete.next_epoch_proof_of_possession = Some(vec![21; 48]);
+        complete.next_epoch_net_address = Some(complete.net_address.clone());
+        complete.next_epoch_p2p_address = Some(complete.p2p_address.clone());
+        complete.next_epoch_primary_address = Some(complete.primary_address.clone());
+        complete.next_epoch_worker_address = Some(complete.worker_address.clone());
+
+        let incomplete = incomplete_next_epoch_configs(&[unchanged, partial.clone(), complete]);
+        assert_eq!(
+            incomplete,
+            vec![IncompleteNextEpochConfig {
+                address: partial.address,
+                name: "validator-10".to_owned(),
+                populated: vec!["next_epoch_network_public_key".to_owned()],
+                missing: [
+                    "next_epoch_protocol_public_key",
+                    "next_epoch_worker_public_key",
+                    "next_epoch_proof_of_possession",
+                    "next_epoch_net_address",
+                    "next_epoch_p2p_address",
+                    "next_epoch_primary_address",
+                    "next_epoch_worker_address",
+                ]
+                .map(str::to_owned)
+                .to_vec(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{reader::StateReader, RestService, Result};
+use axum::{extract::State, Json};
+use sui_sdk2::types::Address;
+
+use super::{SystemStateSummary, ValidatorSummary};
+
+pub const GET_SELF_VALIDATOR_PATH: &str = "/system/self";
+
+/// Report whether the node serving the request is itself part of the active validator set.
+pub async fn get_self_validator(State(state): State<RestService>) -> Result<Json<SelfValidator>> {
+    let summary = state
+        .reader
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(SelfValidator::new(
+        &summary,
+        state.validator_address(),
+    )))
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SelfValidator {
+    /// Whether this node's validator address belongs to an active validator. Always `false` for
+    /// fullnodes.
+    pub is_validator: bool,
+    /// This node's entry in the active validator set, if any.
+    pub validator: Option<ValidatorSummary>,
+    /// Whether this node's validator is at risk of being removed from the validator set.
+    pub is_at_risk: bool,
+}
+
+impl SelfValidator {
+    pub fn new(summary: &SystemStateSummary, validator_address: Option<Address>) -> Self {
+        let validator = validator_address.and_then(|address| {
+            summary
+                .active_validators
+                .iter()
+                .find(|validator| validator.address == address)
+                .cloned()
+        });
+        let is_at_risk = validator.as_ref().is_some_and(|validator| {
+            summary
+                .at_risk_validators
+                .iter()
+                .any(|(address, _)| *address == validator.address)
+        });
+
+        Self {
+            is_validator: validator.is_some(),
+            validator,
+            is_at_risk,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::{test_summary, test_validator};
+
+    #[test]
+    fn self_identification() {
+        let mut summary = test_summary(vec![test_validator(1), test_validator(2)]);
+        summary.at_risk_validators = vec![(Address::new([2; 32]), 1)];
+
+        let node = SelfValidator::new(&summary, Some(Address::new([1; 32])));
+        assert!(node.is_validator);
+        assert_eq!(node.validator.unwrap().name, "validator-1");
+        assert!(!node.is_at_risk);
+
+        let node = SelfValidator::new(&summary, Some(Address::new([2; 32])));
+        assert!(node.is_validator);
+        assert!(node.is_at_risk);
+
+        // Fullnodes have no validator address configured
+        let node = SelfValidator::new(&summary, None);
+        assert!(!node.is_validator);
+        assert!(node.validator.is_none());
+        assert!(!node.is_at_risk);
+
+        let node = SelfValidator::new(&summary, Some(Address::new([9; 32])));
+        assert!(!node.is_validator);
+    }
+}
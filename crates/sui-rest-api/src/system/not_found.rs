@@ -0,0 +1,95 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::routes::SYSTEM_PATHS;
+use crate::RestError;
+use axum::{
+    http::{StatusCode, Uri},
+    response::{IntoResponse, Response},
+};
+
+/// Fallback for requests which don't match any route.
+///
+/// Unknown paths under `/system` respond with a problem document listing the valid system paths,
+/// while any other path keeps the plain empty `404 Not Found`.
+pub async fn system_fallback(uri: Uri) -> Response {
+    if uri.path().starts_with("/system/") {
+        RestError::from(UnknownSystemPathError::new(uri.path())).into_response()
+    } else {
+        StatusCode::NOT_FOUND.into_response()
+    }
+}
+
+/// The requested path isn't one served under `/system`.
+#[derive(Debug)]
+pub struct UnknownSystemPathError {
+    path: String,
+}
+
+impl UnknownSystemPathError {
+    pub fn new<T: Into<String>>(path: T) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl std::fmt::Display for UnknownSystemPathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown system path {}, valid paths are: {}",
+            self.path,
+            SYSTEM_PATHS.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for UnknownSystemPathError {}
+
+impl From<UnknownSystemPathError> for RestError {
+    fn from(value: UnknownSystemPathError) -> Self {
+        Self::new(StatusCode::NOT_FOUND, value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn unknown_system_path() {
+        let app = Router::new()
+            .route(crate::system::GET_EPOCH_PATH, get(|| async { "epoch" }))
+            .fallback(system_fallback)
+            .layer(axum::middleware::from_fn(crate::request_id::request_id));
+
+        let request = Request::builder()
+            .uri("/system/foo")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            response.headers()[axum::http::header::CONTENT_TYPE],
+            crate::APPLICATION_PROBLEM_JSON
+        );
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let problem: crate::error::ProblemDetails = serde_json::from_slice(&body).unwrap();
+        assert_eq!(problem.status, 404);
+        assert_eq!(problem.instance.as_deref(), Some("/system/foo"));
+        let detail = problem.detail.unwrap();
+        assert!(detail.contains("/system/foo"));
+        for path in SYSTEM_PATHS {
+            assert!(detail.contains(path), "{path} missing from {detail}");
+        }
+
+        // Other unknown paths keep the plain 404
+        let request = Request::builder().uri("/foo").body(Body::empty()).unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        assert!(body.is_empty());
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use crate::{reader::StateReader, Result};
+use axum::{extract::State, Json};
+use sui_sdk2::types::ObjectId;
+
+use super::SystemStateSummary;
+
+pub const GET_SYSTEM_OBJECT_REFS_PATH: &str = "/system/object-refs";
+
+/// List the ids of all objects referenced by the system state summary, for indexers wanting to
+/// fetch the objects needed to reconstruct the full system state.
+pub async fn get_system_object_refs(
+    State(state): State<StateReader>,
+) -> Result<Json<Vec<ObjectId>>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(object_refs(&summary)))
+}
+
+/// Collect the object ids referenced by `summary`: first the system tables, followed by the
+/// staking pool, operation cap and exchange rates table of each active validator.
+///
+/// Each id is only listed once, even if it is referenced multiple times.
+pub(crate) fn object_refs(summary: &SystemStateSummary) -> Vec<ObjectId> {
+    let tables = [
+        summary.pending_active_validators_id,
+        summary.staking_pool_mappings_id,
+        summary.inactive_pools_id,
+        summary.validator_candidates_id,
+    ];
+    let validators = summary.active_validators.iter().flat_map(|validator| {
+        [
+            validator.staking_pool_id,
+            validator.operation_cap_id,
+            validator.exchange_rates_id,
+        ]
+    });
+
+    let mut seen = HashSet::new();
+    tables
+        .into_iter()
+        .chain(validators)
+        .filter(|id| seen.insert(*id))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::{test_summary, test_validator};
+
+    #[test]
+    fn system_object_refs() {
+        // Seeds are spaced out so that the ids derived from them don't collide
+        let summary = test_summary(vec![test_validator(0), test_validator(10)]);
+
+        let refs = object_refs(&summary);
+        assert_eq!(refs.len(), 4 + 2 * 3);
+        assert_eq!(refs.iter().collect::<HashSet<_>>().len(), refs.len());
+        assert_eq!(refs[0], summary.pending_active_validators_id);
+        assert!(refs.contains(&summary.active_validators[1].exchange_rates_id));
+
+        // A genuinely shared id is only listed once
+        let mut summary = summary;
+        summary.active_validators[1].operation_cap_id =
+            summary.active_validators[0].operation_cap_id;
+        let refs = object_refs(&summary);
+        assert_eq!(refs.len(), 4 + 2 * 3 - 1);
+    }
+}
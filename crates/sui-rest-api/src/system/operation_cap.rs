@@ -0,0 +1,151 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{reader::StateReader, RestError, Result};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use sui_sdk2::types::{Address, ObjectId, Owner};
+use sui_types::sui_system_state::sui_system_state_inner_v1::UnverifiedValidatorOperationCapV1;
+
+use super::{validators::find_validator, ValidatorSummary};
+
+pub const GET_VALIDATOR_OPERATION_CAP_PATH: &str = "/system/validators/:address/operation-cap";
+
+pub async fn get_validator_operation_cap(
+    Path(address): Path<Address>,
+    State(state): State<StateReader>,
+) -> Result<Json<ValidatorOperationCap>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+    let validator = find_validator(&summary, address)?;
+
+    let object = state
+        .inner()
+        .get_object(&validator.operation_cap_id.into())?
+        .ok_or_else(|| {
+            RestError::new(
+                StatusCode::NOT_FOUND,
+                format!("operation cap {} not found", validator.operation_cap_id),
+            )
+        })?;
+
+    ValidatorOperationCap::new(validator, &object).map(Json)
+}
+
+/// The operation cap of a validator, along with the address currently holding it.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ValidatorOperationCap {
+    /// ID of the operation cap object.
+    pub operation_cap_id: ObjectId,
+    /// Current version of the operation cap object.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub version: u64,
+    /// Current owner of the operation cap object.
+    pub owner: Owner,
+    /// Address of the validator which the cap authorizes operations on behalf of.
+    pub authorizer_validator_address: Address,
+    /// Whether the cap has been transferred away from the validator, i.e. it is no longer owned
+    /// by the validator's own address.
+    pub transferred: bool,
+}
+
+impl ValidatorOperationCap {
+    pub fn new(validator: &ValidatorSummary, object: &sui_types::object::Object) -> Result<Self> {
+        let malformed = || {
+            RestError::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!(
+                    "operation cap {} is not a valid operation cap object",
+                    validator.operation_cap_id
+                ),
+            )
+        };
+
+        let cap: UnverifiedValidatorOperationCapV1 = object
+            .data
+            .try_as_move()
+            .ok_or_else(malformed)
+            .and_then(|object| bcs::from_bytes(object.contents()).map_err(|_| malformed()))?;
+        let owner = Owner::from(object.owner().clone());
+        let transferred = owner != Owner::Address(validator.address);
+
+        Ok(Self {
+            operation_cap_id: cap.id.into(),
+            version: object.version().value(),
+            owner,
+            authorizer_validator_address: cap.authorizer_validator_address.into(),
+            transferred,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::test_validator;
+    use sui_types::base_types::{ObjectID, SequenceNumber, SuiAddress};
+    use sui_types::digests::TransactionDigest;
+    use sui_types::object::{MoveObject, Object};
+
+    fn operation_cap_object(validator: &ValidatorSummary, owner: SuiAddress) -> Object {
+        let cap = UnverifiedValidatorOperationCapV1 {
+            id: validator.operation_cap_id.into(),
+            authorizer_validator_address: validator.address.into(),
+        };
+        let type_ =
+            sui_types::parse_sui_struct_tag("0x3::validator_cap::UnverifiedValidatorOperationCap")
+                .unwrap();
+        // SAFETY: `UnverifiedValidatorOperationCap` has `store` and thus public transfer
+        let object = unsafe {
+            MoveObject::new_from_execution_with_limit(
+                type_.into(),
+                true,
+                SequenceNumber::from_u64(4),
+                bcs::to_bytes(&cap).unwrap(),
+                u64::MAX,
+            )
+        }
+        .unwrap();
+
+        Object::new_move(
+            object,
+            sui_types::object::Owner::AddressOwner(owner),
+            TransactionDigest::genesis_marker(),
+        )
+    }
+
+    #[test]
+    fn owner_resolution() {
+        let validator = test_validator(1);
+
+        let object = operation_cap_object(&validator, validator.address.into());
+        let cap = ValidatorOperationCap::new(&validator, &object).unwrap();
+        assert_eq!(
+            cap,
+            ValidatorOperationCap {
+                operation_cap_id: validator.operation_cap_id,
+                version: 4,
+                owner: Owner::Address(validator.address),
+                authorizer_validator_address: validator.address,
+                transferred: false,
+            }
+        );
+
+        // The cap has been handed to an operator
+        let operator = Address::new([9; 32]);
+        let object = operation_cap_object(&validator, operator.into());
+        let cap = ValidatorOperationCap::new(&validator, &object).unwrap();
+        assert_eq!(cap.owner, Owner::Address(operator));
+        assert_eq!(cap.authorizer_validator_address, validator.address);
+        assert!(cap.transferred);
+
+        // Something other than a cap
+        let object = Object::immutable_with_id_for_testing(ObjectID::from_single_byte(2));
+        assert!(ValidatorOperationCap::new(&validator, &object).is_err());
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::{
+    http::{self, header::HeaderName, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+
+pub(crate) const PREFER: HeaderName = HeaderName::from_static("prefer");
+pub(crate) const PREFERENCE_APPLIED: HeaderName = HeaderName::from_static("preference-applied");
+
+/// `Prefer` header, defined in [RFC7240](https://datatracker.ietf.org/doc/html/rfc7240).
+///
+/// Only the `return` preference is understood, any others are ignored.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Prefer {
+    /// Whether the client asked for `return=minimal`, i.e. an acknowledgment without a body.
+    pub return_minimal: bool,
+}
+
+impl Prefer {
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let return_minimal = headers
+            .get_all(PREFER)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .any(|preference| {
+                // Parameters of the preference follow a `;`, and aren't relevant to `return`
+                let preference = preference.split(';').next().unwrap_or_default();
+                let Some((token, value)) = preference.split_once('=') else {
+                    return false;
+                };
+
+                token.trim().eq_ignore_ascii_case("return")
+                    && value
+                        .trim()
+                        .trim_matches('"')
+                        .eq_ignore_ascii_case("minimal")
+            });
+
+        Self { return_minimal }
+    }
+}
+
+#[axum::async_trait]
+impl<S> axum::extract::FromRequestParts<S> for Prefer
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(
+        parts: &mut http::request::Parts,
+        _: &S,
+    ) -> Result<Self, Self::Rejection> {
+        Ok(Self::from_headers(&parts.headers))
+    }
+}
+
+/// The `204 No Content` response honouring `return=minimal`.
+pub(crate) fn minimal_response() -> Response {
+    (
+        StatusCode::NO_CONTENT,
+        [(
+            PREFERENCE_APPLIED,
+            HeaderValue::from_static("return=minimal"),
+        )],
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::Request};
+    use tower::ServiceExt;
+
+    fn prefer(values: &[&'static str]) -> Prefer {
+        let mut headers = HeaderMap::new();
+        for value in values {
+            headers.append(PREFER, HeaderValue::from_static(value));
+        }
+
+        Prefer::from_headers(&headers)
+    }
+
+    #[tokio::test]
+    async fn return_minimal() {
+        assert!(prefer(&["return=minimal"]).return_minimal);
+        assert!(prefer(&["respond-async, Return = \"minimal\"; foo=bar"]).return_minimal);
+        assert!(prefer(&["wait=10", "return=minimal"]).return_minimal);
+        assert!(!prefer(&["return=representation"]).return_minimal);
+        assert!(!prefer(&[]).return_minimal);
+
+        let app = crate::RestService::new_without_version(std::sync::Arc::new(
+            crate::reader::tests::TestReader::new(),
+        ))
+        .into_router();
+
+        let request = Request::builder()
+            .uri("/system")
+            .header(PREFER, "return=minimal")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert_eq!(response.headers()[PREFERENCE_APPLIED], "return=minimal");
+        // The system state is still read, so the acknowledgment describes it like a full response
+        for header in [http::header::ETAG, http::header::CACHE_CONTROL] {
+            assert!(response.headers().contains_key(&header), "{header}");
+        }
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        assert!(body.is_empty());
+
+        let request = Request::builder()
+            .uri("/system")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().get(PREFERENCE_APPLIED).is_none());
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let summary: crate::system::SystemStateSummary = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            summary.epoch,
+            crate::system::tests::test_inner_state().epoch
+        );
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use crate::{reader::StateReader, RestError, Result};
+use axum::{extract::State, http::StatusCode, Json};
+use sui_protocol_config::{Chain, ProtocolConfig, ProtocolVersion};
+
+pub const GET_PROTOCOL_CONFIG_PATH: &str = "/system/protocol/config";
+
+/// Return every parameter and feature flag of the protocol config in effect at the current
+/// protocol version.
+pub async fn get_protocol_config(
+    State(state): State<StateReader>,
+) -> Result<Json<ProtocolConfigParameters>> {
+    let (summary, chain) = state
+        .spawn_read(|state| {
+            let summary = state.get_system_state_summary()?;
+            let chain = state.inner().get_chain_identifier()?.chain();
+            Ok::<_, RestError>((summary, chain))
+        })
+        .await?;
+
+    ProtocolConfigParameters::new(summary.protocol_version, chain).map(Json)
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ProtocolConfigParameters {
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub protocol_version: u64,
+    pub feature_flags: BTreeMap<String, bool>,
+    /// The value of each parameter rendered as a string, like BigInts, e.g. `"131072"`. `None` if
+    /// the parameter isn't set at this protocol version.
+    pub attributes: BTreeMap<String, Option<String>>,
+}
+
+impl ProtocolConfigParameters {
+    /// The protocol config of `chain` at `protocol_version`, failing if this node doesn't support
+    /// the version.
+    pub fn new(protocol_version: u64, chain: Chain) -> Result<Self> {
+        let config = ProtocolConfig::get_for_version_if_supported(
+            ProtocolVersion::new(protocol_version),
+            chain,
+        )
+        .ok_or_else(|| {
+            RestError::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!(
+                    "protocol config for version {protocol_version} is not supported by this node"
+                ),
+            )
+        })?;
+
+        Ok(Self {
+            protocol_version,
+            feature_flags: config.feature_map(),
+            attributes: config
+                .attr_map()
+                .into_iter()
+                .map(|(name, value)| (name, value.map(|value| value.to_string())))
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pinned_version_parameters() {
+        let config = ProtocolConfigParameters::new(1, Chain::Mainnet).unwrap();
+        assert_eq!(config.protocol_version, 1);
+        assert_eq!(
+            config.attributes["max_tx_size_bytes"].as_deref(),
+            Some("131072")
+        );
+        // Parameters introduced in later versions are listed but unset
+        assert_eq!(config.attributes["max_package_dependencies"], None);
+        // All flags are disabled in version 1
+        assert!(!config.feature_flags.is_empty());
+        assert!(config.feature_flags.values().all(|enabled| !enabled));
+
+        let json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["protocol_version"], "1");
+        assert_eq!(json["attributes"]["max_tx_size_bytes"], "131072");
+
+        let response = axum::response::IntoResponse::into_response(
+            ProtocolConfigParameters::new(u64::MAX, Chain::Mainnet).unwrap_err(),
+        );
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{reader::StateReader, Result};
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use sui_sdk2::types::Address;
+
+use super::{validators::find_validator, SystemStateSummary, ValidatorSummary};
+
+pub const GET_VALIDATOR_RATES_PATH: &str = "/system/validators/:address/rates";
+
+pub async fn get_validator_rates(
+    Path(address): Path<Address>,
+    State(state): State<StateReader>,
+) -> Result<Json<ValidatorRates>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+    let validator = find_validator(&summary, address)?;
+
+    Ok(Json(ValidatorRates::new(&summary, validator)))
+}
+
+/// The rates applying to a validator, both in basis points and as percentages.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ValidatorRates {
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub commission_rate_bps: u64,
+    /// `commission_rate_bps` as a percentage, e.g. `"5.00%"`.
+    pub commission_rate_percent: String,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub next_epoch_commission_rate_bps: u64,
+    pub next_epoch_commission_rate_percent: String,
+    /// The rate at which the stake subsidy decays at the end of each period.
+    pub stake_subsidy_decrease_rate_bps: u16,
+    pub stake_subsidy_decrease_rate_percent: String,
+}
+
+impl ValidatorRates {
+    pub fn new(summary: &SystemStateSummary, validator: &ValidatorSummary) -> Self {
+        Self {
+            commission_rate_bps: validator.commission_rate,
+            commission_rate_percent: bps_to_percent(validator.commission_rate),
+            next_epoch_commission_rate_bps: validator.next_epoch_commission_rate,
+            next_epoch_commission_rate_percent: bps_to_percent(
+                validator.next_epoch_commission_rate,
+            ),
+            stake_subsidy_decrease_rate_bps: summary.stake_subsidy_decrease_rate,
+            stake_subsidy_decrease_rate_percent: bps_to_percent(
+                summary.stake_subsidy_decrease_rate.into(),
+            ),
+        }
+    }
+}
+
+pub const GET_VALIDATOR_COMMISSION_CHANGES_PATH: &str = "/system/validators/commission-changes";
+
+/// List the active validators whose commission rate changes at the next epoch, e.g. for alerting
+/// delegators to fee increases.
+pub async fn get_validator_commission_changes(
+    State(state): State<StateReader>,
+) -> Result<Json<Vec<CommissionChange>>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(commission_changes(&summary.active_validators)))
+}
+
+/// A validator's commission rate change taking effect at the next epoch.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CommissionChange {
+    pub address: Address,
+    pub name: String,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub commission_rate_bps: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub next_epoch_commission_rate_bps: u64,
+    /// `next_epoch_commission_rate_bps - commission_rate_bps`, positive for increases.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<i128>")]
+    pub delta_bps: i128,
+    /// `delta_bps` as a signed percentage, e.g. `"+1.50%"` or `"-0.25%"`.
+    pub delta_percent: String,
+}
+
+pub(crate) fn commission_changes(validators: &[ValidatorSummary]) -> Vec<CommissionChange> {
+    validators
+        .iter()
+        .filter(|validator| validator.next_epoch_commission_rate != validator.commission_rate)
+        .map(|validator| {
+            let delta_bps = i128::from(validator.next_epoch_commission_rate)
+                - i128::from(validator.commission_rate);
+
+            CommissionChange {
+                address: validator.address,
+                name: validator.name.clone(),
+                commission_rate_bps: validator.commission_rate,
+                next_epoch_commission_rate_bps: validator.next_epoch_commission_rate,
+                delta_bps,
+                delta_percent: signed_bps_to_percent(delta_bps),
+            }
+        })
+        .collect()
+}
+
+/// Render basis points as a percentage with two decimal places, which is exact as one basis point
+/// is 0.01%.
+pub(crate) fn bps_to_percent(bps: u64) -> String {
+    format!("{}.{:02}%", bps / 100, bps % 100)
+}
+
+/// Render a change in basis points like [`bps_to_percent`], prefixed with its sign.
+fn signed_bps_to_percent(bps: i128) -> String {
+    let sign = if bps < 0 { '-' } else { '+' };
+    let bps = bps.unsigned_abs();
+
+    format!("{sign}{}.{:02}%", bps / 100, bps % 100)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::{test_summary, test_validator, validators_with};
+
+    #[test]
+    fn bps_to_percent_conversion() {
+        assert_eq!(bps_to_percent(0), "0.00%");
+        assert_eq!(bps_to_percent(1), "0.01%");
+        assert_eq!(bps_to_percent(500), "5.00%");
+        assert_eq!(bps_to_percent(1234), "12.34%");
+        assert_eq!(bps_to_percent(10_000), "100.00%");
+        assert_eq!(bps_to_percent(u16::MAX.into()), "655.35%");
+        assert_eq!(bps_to_percent(u64::MAX), "184467440737095516.15%");
+
+        let mut validator = test_validator(1);
+        validator.commission_rate = 500;
+        validator.next_epoch_commission_rate = 0;
+        let rates = ValidatorRates::new(&test_summary(vec![]), &validator);
+        assert_eq!(rates.commission_rate_percent, "5.00%");
+        assert_eq!(rates.next_epoch_commission_rate_percent, "0.00%");
+        assert_eq!(rates.stake_subsidy_decrease_rate_bps, 1000);
+        assert_eq!(rates.stake_subsidy_decrease_rate_percent, "10.00%");
+    }
+
+    #[test]
+    fn changed_commissions() {
+        let validators = validators_with(
+            |validator, (commission_rate, next_epoch_commission_rate)| {
+                validator.commission_rate = commission_rate;
+                validator.next_epoch_commission_rate = next_epoch_commission_rate;
+            },
+            [(500, 650), (500, 475), (500, 500)],
+        );
+
+        let changes = commission_changes(&validators);
+        assert_eq!(
+            changes,
+            [
+                CommissionChange {
+                    address: validators[0].address,
+                    name: validators[0].name.clone(),
+                    commission_rate_bps: 500,
+                    next_epoch_commission_rate_bps: 650,
+                    delta_bps: 150,
+                    delta_percent: "+1.50%".to_owned(),
+                },
+                CommissionChange {
+                    address: validators[1].address,
+                    name: validators[1].name.clone(),
+                    commission_rate_bps: 500,
+                    next_epoch_commission_rate_bps: 475,
+                    delta_bps: -25,
+                    delta_percent: "-0.25%".to_owned(),
+                },
+            ]
+        );
+
+        let json = serde_json::to_value(&changes[1]).unwrap();
+        assert_eq!(json["delta_bps"], "-25");
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    reader::StateReader, types::X_SUI_SYSTEM_STATE_VERSION, RestError, Result, APPLICATION_BCS,
+};
+use axum::{
+    extract::State,
+    http::{header, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+use sui_types::base_types::ObjectID;
+use tap::Pipe;
+
+pub const GET_RAW_SYSTEM_STATE_PATH: &str = "/system/raw";
+
+/// Return the BCS bytes of the raw on-chain inner system state, e.g. `SuiSystemStateInnerV2`,
+/// rather than the summary.
+///
+/// The version of the inner system state, which determines the Move type the bytes decode as, is
+/// returned in the `x-sui-system-state-version` header.
+pub async fn get_raw_system_state(State(state): State<StateReader>) -> Result<Response> {
+    let (version, object) = state
+        .get_system_state_inner_object()?
+        .ok_or_else(|| RestError::new(StatusCode::NOT_FOUND, "system state is not available"))?;
+
+    let contents = object.data.try_as_move().map(|object| object.contents());
+    let bytes = contents.and_then(field_value_bytes).ok_or_else(|| {
+        RestError::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "system state object is malformed",
+        )
+    })?;
+
+    (
+        [
+            (
+                header::CONTENT_TYPE,
+                HeaderValue::from_static(APPLICATION_BCS),
+            ),
+            (
+                header::HeaderName::from_static(X_SUI_SYSTEM_STATE_VERSION),
+                HeaderValue::from(version),
+            ),
+        ],
+        bytes.to_vec(),
+    )
+        .into_response()
+        .pipe(Ok)
+}
+
+/// The BCS bytes of the value of a `sui::dynamic_field::Field<u64, V>`, given the BCS bytes of the
+/// field.
+///
+/// The value directly follows the field's id and `u64` name, which have a fixed size.
+fn field_value_bytes(field: &[u8]) -> Option<&[u8]> {
+    field.get(ObjectID::LENGTH + std::mem::size_of::<u64>()..)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::test_inner_state;
+    use sui_types::dynamic_field::Field;
+    use sui_types::id::UID;
+    use sui_types::sui_system_state::sui_system_state_inner_v2::SuiSystemStateInnerV2;
+
+    #[test]
+    fn raw_field_value() {
+        let value = test_inner_state();
+        let field = Field {
+            id: UID::new(ObjectID::from_single_byte(1)),
+            name: value.system_state_version,
+            value: value.clone(),
+        };
+        let field = bcs::to_bytes(&field).unwrap();
+
+        let bytes = field_value_bytes(&field).unwrap();
+        assert_eq!(bytes, bcs::to_bytes(&value).unwrap());
+        let decoded: SuiSystemStateInnerV2 = bcs::from_bytes(bytes).unwrap();
+        assert_eq!(decoded, value);
+
+        assert!(field_value_bytes(&field[..10]).is_none());
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{reader::StateReader, Result};
+use axum::{extract::State, Json};
+
+use super::SystemStateSummary;
+
+pub const GET_NEXT_RECONFIGURATION_PATH: &str = "/system/next-reconfiguration";
+
+pub async fn get_next_reconfiguration(
+    State(state): State<StateReader>,
+) -> Result<Json<NextReconfiguration>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(NextReconfiguration::new(&summary, now_ms())))
+}
+
+/// When the current epoch is expected to end.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct NextReconfiguration {
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub epoch: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub next_epoch: u64,
+    /// Estimated time of the reconfiguration to `next_epoch`, in milliseconds since the Unix
+    /// epoch.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub estimated_timestamp_ms: u64,
+    /// Whether the estimated time has already passed, which may indicate that reconfiguration is
+    /// stuck.
+    pub overdue: bool,
+}
+
+impl NextReconfiguration {
+    pub fn new(summary: &SystemStateSummary, now_ms: u64) -> Self {
+        let estimated_timestamp_ms = epoch_end_timestamp_ms(summary);
+
+        Self {
+            epoch: summary.epoch,
+            next_epoch: summary.epoch + 1,
+            estimated_timestamp_ms,
+            overdue: estimated_timestamp_ms < now_ms,
+        }
+    }
+}
+
+/// Expected end of the current epoch, in milliseconds since the Unix epoch.
+pub(crate) fn epoch_end_timestamp_ms(summary: &SystemStateSummary) -> u64 {
+    summary
+        .epoch_start_timestamp_ms
+        .saturating_add(summary.epoch_duration_ms)
+}
+
+/// The current wall clock time, in milliseconds since the Unix epoch.
+pub(crate) fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|now| now.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::test_summary;
+
+    #[test]
+    fn next_reconfiguration() {
+        let mut summary = test_summary(vec![]);
+        summary.epoch_start_timestamp_ms = 1_000_000;
+        summary.epoch_duration_ms = 86_400_000;
+
+        let on_time = NextReconfiguration::new(&summary, 2_000_000);
+        assert_eq!(
+            on_time,
+            NextReconfiguration {
+                epoch: summary.epoch,
+                next_epoch: summary.epoch + 1,
+                estimated_timestamp_ms: 87_400_000,
+                overdue: false,
+            }
+        );
+
+        // Exactly at the estimate isn't overdue yet
+        assert!(!NextReconfiguration::new(&summary, 87_400_000).overdue);
+
+        let overdue = NextReconfiguration::new(&summary, 87_400_001);
+        assert_eq!(overdue.estimated_timestamp_ms, 87_400_000);
+        assert!(overdue.overdue);
+    }
+}
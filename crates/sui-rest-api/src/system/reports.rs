@@ -0,0 +1,271 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeSet;
+
+use crate::{reader::StateReader, Result};
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use sui_sdk2::types::Address;
+
+use super::{validators::find_validator, SystemStateSummary};
+
+pub const GET_REPORTED_VALIDATORS_PATH: &str = "/system/validators/reported";
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct ReportedValidatorsQueryParameters {
+    /// Minimum number of distinct reporters a validator needs to be included. Defaults to 1.
+    pub min_reporters: Option<u64>,
+}
+
+pub async fn get_reported_validators(
+    Query(parameters): Query<ReportedValidatorsQueryParameters>,
+    State(state): State<StateReader>,
+) -> Result<Json<Vec<ReportedValidator>>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+    let min_reporters = parameters.min_reporters.unwrap_or(1);
+
+    Ok(Json(reported_validators(&summary, min_reporters)))
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ReportedValidator {
+    /// Address of the reported validator.
+    pub address: Address,
+    /// Number of distinct validators which reported this validator.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub reporter_count: u64,
+    /// The distinct validators which reported this validator.
+    pub reporters: Vec<Address>,
+}
+
+/// Collect the validators which have been reported by at least `min_reporters` distinct other
+/// validators. Self-reports are not counted.
+pub(crate) fn reported_validators(
+    summary: &SystemStateSummary,
+    min_reporters: u64,
+) -> Vec<ReportedValidator> {
+    summary
+        .validator_report_records
+        .iter()
+        .filter_map(|(address, reports)| {
+            let reporters = distinct_reporters(address, reports);
+            let reporter_count = reporters.len() as u64;
+
+            (reporter_count >= min_reporters).then_some(ReportedValidator {
+                address: *address,
+                reporter_count,
+                reporters,
+            })
+        })
+        .collect()
+}
+
+/// The distinct reporters of `address`, ignoring self-reports.
+fn distinct_reporters(address: &Address, reports: &[Address]) -> Vec<Address> {
+    reports
+        .iter()
+        .filter(|reporter| *reporter != address)
+        .copied()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+pub const GET_VALIDATOR_REPUTATION_PATH: &str = "/system/validators/:address/reputation";
+
+pub async fn get_validator_reputation(
+    Path(address): Path<Address>,
+    State(state): State<StateReader>,
+) -> Result<Json<ValidatorReputation>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+    find_validator(&summary, address)?;
+
+    Ok(Json(ValidatorReputation::new(&summary, address)))
+}
+
+/// A reputation proxy for an active validator, derived from the reports filed against it by the
+/// other validators.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ValidatorReputation {
+    /// Number of distinct validators which reported this validator.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub reported_by_count: u64,
+    /// The distinct validators which reported this validator.
+    pub reported_by: Vec<Address>,
+    /// Combined voting power of the reporters which are active validators, as a fraction of the
+    /// total voting power of the active validator set.
+    pub report_fraction: f64,
+}
+
+impl ValidatorReputation {
+    pub fn new(summary: &SystemStateSummary, address: Address) -> Self {
+        let reported_by = summary
+            .validator_report_records
+            .iter()
+            .find(|(reported, _)| *reported == address)
+            .map(|(_, reports)| distinct_reporters(&address, reports))
+            .unwrap_or_default();
+
+        let voting_power = |addresses: &[Address]| {
+            summary
+                .active_validators
+                .iter()
+                .filter(|validator| addresses.contains(&validator.address))
+                .map(|validator| validator.voting_power as f64)
+                .sum::<f64>()
+        };
+        let total_voting_power = summary
+            .active_validators
+            .iter()
+            .map(|validator| validator.voting_power as f64)
+            .sum::<f64>();
+        let report_fraction = if total_voting_power == 0.0 {
+            0.0
+        } else {
+            voting_power(&reported_by) / total_voting_power
+        };
+
+        Self {
+            reported_by_count: reported_by.len() as u64,
+            reported_by,
+            report_fraction,
+        }
+    }
+}
+
+pub const GET_REPORT_GRAPH_PATH: &str = "/system/reports/graph";
+
+/// Return the validator reports as the edges of a graph, e.g. for visualization tools.
+pub async fn get_report_graph(State(state): State<StateReader>) -> Result<Json<ReportGraph>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(ReportGraph::new(&summary)))
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ReportGraph {
+    /// Number of distinct validators which either reported or were reported.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub node_count: u64,
+    /// Every report as a `(reporter, reported)` pair, as recorded on-chain.
+    pub edges: Vec<(Address, Address)>,
+}
+
+impl ReportGraph {
+    pub fn new(summary: &SystemStateSummary) -> Self {
+        let edges = summary
+            .validator_report_records
+            .iter()
+            .flat_map(|(reported, reports)| reports.iter().map(|reporter| (*reporter, *reported)))
+            .collect::<Vec<_>>();
+        let node_count = edges
+            .iter()
+            .flat_map(|(reporter, reported)| [reporter, reported])
+            .collect::<BTreeSet<_>>()
+            .len() as u64;
+
+        Self { node_count, edges }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::{test_summary, test_validator, validators_with};
+
+    #[test]
+    fn reporter_threshold() {
+        let validators = (0..4).map(test_validator).collect::<Vec<_>>();
+        let [a, b, c, d] = [0, 1, 2, 3].map(|i| validators[i].address);
+        let mut summary = test_summary(validators);
+        summary.validator_report_records = vec![
+            // `a` has two distinct reporters, the duplicate and self-report are ignored
+            (a, vec![b, c, b, a]),
+            (d, vec![a]),
+        ];
+
+        let reported = reported_validators(&summary, 1);
+        assert_eq!(reported.len(), 2);
+        assert_eq!(reported[0].address, a);
+        assert_eq!(reported[0].reporter_count, 2);
+        assert_eq!(reported[0].reporters, vec![b, c]);
+        assert_eq!(reported[1].address, d);
+
+        let reported = reported_validators(&summary, 2);
+        assert_eq!(reported.len(), 1);
+        assert_eq!(reported[0].address, a);
+
+        assert!(reported_validators(&summary, 3).is_empty());
+    }
+
+    #[test]
+    fn reputation() {
+        let validators = validators_with(
+            |validator, voting_power| validator.voting_power = voting_power,
+            [1000, 2000, 3000, 4000],
+        );
+        let [a, b, c, d] = [0, 1, 2, 3].map(|i| validators[i].address);
+        // A reporter which has since left the active set carries no voting power
+        let former = Address::new([99; 32]);
+        let mut summary = test_summary(validators);
+        summary.validator_report_records = vec![(a, vec![b, d, former, a, b])];
+
+        let reputation = ValidatorReputation::new(&summary, a);
+        assert_eq!(reputation.reported_by_count, 3);
+        assert_eq!(reputation.reported_by, vec![b, d, former]);
+        // (2000 + 4000) / 10000
+        assert!((reputation.report_fraction - 0.6).abs() < 1e-9);
+
+        let reputation = ValidatorReputation::new(&summary, c);
+        assert_eq!(
+            reputation,
+            ValidatorReputation {
+                reported_by_count: 0,
+                reported_by: vec![],
+                report_fraction: 0.0,
+            }
+        );
+    }
+
+    #[test]
+    fn report_edges() {
+        let validators = (0..4).map(|i| test_validator(i * 10)).collect::<Vec<_>>();
+        let [a, b, c] = [0, 1, 2].map(|i| validators[i].address);
+        let mut summary = test_summary(validators);
+        summary.validator_report_records = vec![(a, vec![b, c]), (b, vec![a]), (c, vec![b])];
+
+        let graph = ReportGraph::new(&summary);
+        let total_reports = summary
+            .validator_report_records
+            .iter()
+            .map(|(_, reports)| reports.len())
+            .sum::<usize>();
+        assert_eq!(graph.edges.len(), total_reports);
+        assert_eq!(graph.edges, vec![(b, a), (c, a), (a, b), (b, c)]);
+        // The fourth validator neither reported nor was reported
+        assert_eq!(graph.node_count, 3);
+
+        let empty = ReportGraph::new(&test_summary(vec![]));
+        assert_eq!(empty.node_count, 0);
+        assert!(empty.edges.is_empty());
+    }
+
+    #[test]
+    fn empty_validator_set() {
+        let summary = test_summary(vec![]);
+        assert!(reported_validators(&summary, 0).is_empty());
+        assert!(reported_validators(&summary, 1).is_empty());
+    }
+}
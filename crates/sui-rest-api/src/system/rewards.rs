@@ -0,0 +1,277 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{reader::StateReader, Result};
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use sui_sdk2::types::{Address, EpochId};
+
+use super::{
+    exchange_rates::{pool_results, require_exchange_rates, PoolResult},
+    validators::find_validator,
+    SystemStateSummary, ValidatorSummary,
+};
+
+pub const GET_VALIDATOR_ESTIMATED_REWARDS_PATH: &str =
+    "/system/validators/:address/estimated-rewards";
+
+/// Number of past epochs of exchange rate history used for the estimate.
+const REWARD_HISTORY_EPOCHS: u64 = 7;
+
+/// Estimate the next-epoch reward rate of a validator's staking pool.
+///
+/// Failing to read the pool's exchange rates fails the request, rather than estimating from an
+/// incomplete history.
+pub async fn get_validator_estimated_rewards(
+    Path(address): Path<Address>,
+    State(state): State<StateReader>,
+) -> Result<Json<EstimatedRewards>> {
+    state
+        .spawn_read(move |state| {
+            let summary = state.get_system_state_summary()?;
+            let validator = find_validator(&summary, address)?;
+
+            let exchange_rates = state.get_pool_exchange_rates(
+                validator.exchange_rates_id,
+                reward_history(validator, summary.epoch),
+            )?;
+
+            Ok(Json(EstimatedRewards::new(
+                validator,
+                summary.epoch,
+                &exchange_rates,
+            )))
+        })
+        .await
+}
+
+pub const GET_VALIDATORS_ESTIMATED_REWARDS_PATH: &str = "/system/validators/estimated-rewards";
+
+/// Estimate the next-epoch reward rate of every active validator's staking pool.
+///
+/// A validator whose exchange rates can't be read is reported with an `error` rather than failing
+/// the whole response.
+pub async fn get_validators_estimated_rewards(
+    State(state): State<StateReader>,
+) -> Result<Json<Vec<PoolResult<EstimatedRewards>>>> {
+    state
+        .spawn_read(|state| {
+            let summary = state.get_system_state_summary()?;
+
+            Ok(Json(pool_results(
+                &summary.active_validators,
+                |validator| {
+                    let exchange_rates = state.get_pool_exchange_rates(
+                        validator.exchange_rates_id,
+                        reward_history(validator, summary.epoch),
+                    )?;
+                    let exchange_rates = require_exchange_rates(validator, exchange_rates)?;
+
+                    Ok(EstimatedRewards::new(
+                        validator,
+                        summary.epoch,
+                        &exchange_rates,
+                    ))
+                },
+            )))
+        })
+        .await
+}
+
+/// Epochs of `validator`'s exchange rate history which estimates are based on.
+fn reward_history(
+    validator: &ValidatorSummary,
+    current_epoch: EpochId,
+) -> std::ops::RangeInclusive<EpochId> {
+    let first_epoch = current_epoch
+        .saturating_sub(REWARD_HISTORY_EPOCHS)
+        .max(validator.staking_pool_activation_epoch.unwrap_or(0));
+
+    first_epoch..=current_epoch
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EstimatedRewards {
+    /// Estimated reward for the next epoch per staked SUI, e.g. `0.0002` for 0.02%.
+    pub estimated_reward_rate: f64,
+    /// Number of past epochs of exchange rate history the estimate is based on. `0` if there
+    /// wasn't enough history and the estimate is instead derived from the pool's accumulated
+    /// rewards.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub based_on_epochs: u64,
+}
+
+impl EstimatedRewards {
+    /// Estimate the next-epoch reward rate of `validator`'s staking pool.
+    ///
+    /// `exchange_rates` are the pool's exchange rates in pool tokens per SUI, ordered by epoch.
+    /// The reward earned during an epoch is derived from the change in exchange rate between it
+    /// and the following epoch, and the estimate is the mean over all such pairs. If there are
+    /// none, the rewards accumulated by the pool are averaged over its lifetime instead.
+    ///
+    /// Stake which is pending to join the pool is assumed to dilute the rewards of the existing
+    /// stake.
+    pub fn new(
+        validator: &ValidatorSummary,
+        current_epoch: EpochId,
+        exchange_rates: &[(EpochId, f64)],
+    ) -> Self {
+        let epoch_rates = exchange_rates
+            .windows(2)
+            .filter(|pair| pair[0].0 + 1 == pair[1].0 && pair[1].1 > 0.0)
+            .map(|pair| pair[0].1 / pair[1].1 - 1.0)
+            .collect::<Vec<_>>();
+
+        let (reward_rate, based_on_epochs) = if epoch_rates.is_empty() {
+            (lifetime_reward_rate(validator, current_epoch), 0)
+        } else {
+            (
+                epoch_rates.iter().sum::<f64>() / epoch_rates.len() as f64,
+                epoch_rates.len() as u64,
+            )
+        };
+
+        let stake = validator.staking_pool_sui_balance as f64;
+        let next_epoch_stake = stake + validator.pending_stake as f64;
+        let dilution = if next_epoch_stake == 0.0 {
+            1.0
+        } else {
+            stake / next_epoch_stake
+        };
+
+        Self {
+            estimated_reward_rate: reward_rate * dilution,
+            based_on_epochs,
+        }
+    }
+}
+
+/// Average per-epoch reward rate of the pool since it was activated, based on the rewards it has
+/// accumulated relative to the stake backing its pool tokens.
+fn lifetime_reward_rate(validator: &ValidatorSummary, current_epoch: EpochId) -> f64 {
+    let Some(activation_epoch) = validator.staking_pool_activation_epoch else {
+        return 0.0;
+    };
+    let epochs_active = current_epoch.saturating_sub(activation_epoch);
+    let principal = validator
+        .staking_pool_sui_balance
+        .saturating_sub(validator.rewards_pool);
+
+    if epochs_active == 0 || principal == 0 || validator.pool_token_balance == 0 {
+        return 0.0;
+    }
+
+    validator.rewards_pool as f64 / principal as f64 / epochs_active as f64
+}
+
+pub const GET_REWARDS_PARAMETERS_PATH: &str = "/system/rewards-parameters";
+
+pub async fn get_rewards_parameters(
+    State(state): State<StateReader>,
+) -> Result<Json<RewardsParameters>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(RewardsParameters::new(&summary)))
+}
+
+/// The inputs which determine the rewards distributed at the end of the epoch.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RewardsParameters {
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub reference_gas_price: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub storage_fund_total_object_storage_rebates: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub storage_fund_non_refundable_balance: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub stake_subsidy_current_distribution_amount: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub total_stake: u64,
+}
+
+impl RewardsParameters {
+    pub fn new(summary: &SystemStateSummary) -> Self {
+        Self {
+            reference_gas_price: summary.reference_gas_price,
+            storage_fund_total_object_storage_rebates: summary
+                .storage_fund_total_object_storage_rebates,
+            storage_fund_non_refundable_balance: summary.storage_fund_non_refundable_balance,
+            stake_subsidy_current_distribution_amount: summary
+                .stake_subsidy_current_distribution_amount,
+            total_stake: summary.total_stake,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::{test_summary, test_validator};
+
+    fn synthetic_pool() -> ValidatorSummary {
+        let mut validator = test_validator(1);
+        validator.staking_pool_activation_epoch = Some(0);
+        validator.staking_pool_sui_balance = 1_100_000;
+        validator.rewards_pool = 100_000;
+        validator.pool_token_balance = 1_000_000;
+        validator
+    }
+
+    #[test]
+    fn reward_rate_from_exchange_rates() {
+        let validator = synthetic_pool();
+        // 1% then 2% rewards, with a gap before epoch 6 which is ignored
+        let exchange_rates = [(2, 1.0), (3, 1.0 / 1.01), (4, 1.0 / 1.01 / 1.02), (6, 0.5)];
+
+        let estimate = EstimatedRewards::new(&validator, 6, &exchange_rates);
+        assert_eq!(estimate.based_on_epochs, 2);
+        assert!((estimate.estimated_reward_rate - 0.015).abs() < 1e-12);
+
+        // Doubling the stake next epoch halves the rewards per SUI
+        let mut validator = validator;
+        validator.pending_stake = validator.staking_pool_sui_balance;
+        let estimate = EstimatedRewards::new(&validator, 6, &exchange_rates);
+        assert!((estimate.estimated_reward_rate - 0.0075).abs() < 1e-12);
+    }
+
+    #[test]
+    fn thin_history() {
+        let validator = synthetic_pool();
+
+        // 100_000 of rewards on 1_000_000 of principal over 10 epochs
+        let estimate = EstimatedRewards::new(&validator, 10, &[(10, 0.9)]);
+        assert_eq!(estimate.based_on_epochs, 0);
+        assert!((estimate.estimated_reward_rate - 0.01).abs() < 1e-12);
+
+        // A pool activated this epoch has no history at all
+        let estimate = EstimatedRewards::new(&validator, 0, &[]);
+        assert_eq!(estimate.estimated_reward_rate, 0.0);
+    }
+
+    #[test]
+    fn rewards_parameters() {
+        let mut summary = test_summary(vec![test_validator(1)]);
+        summary.reference_gas_price = 750;
+        summary.storage_fund_total_object_storage_rebates = 1;
+        summary.storage_fund_non_refundable_balance = 2;
+        summary.stake_subsidy_current_distribution_amount = 3;
+        summary.total_stake = 4;
+
+        assert_eq!(
+            RewardsParameters::new(&summary),
+            RewardsParameters {
+                reference_gas_price: 750,
+                storage_fund_total_object_storage_rebates: 1,
+                storage_fund_non_refundable_balance: 2,
+                stake_subsidy_current_distribution_amount: 3,
+                total_stake: 4,
+            }
+        );
+    }
+}
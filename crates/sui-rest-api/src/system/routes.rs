@@ -0,0 +1,100 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    accept::{override_accept, AcceptFormat},
+    RestService,
+};
+use axum::{
+    middleware::map_request_with_state,
+    routing::{get, post, MethodRouter},
+};
+
+/// Declare every route served under `/system` once, generating both the table the router is built
+/// from and the listing of paths used by unknown path errors, so that the two can't drift apart.
+macro_rules! system_routes {
+    ($($path:ident => $route:expr,)*) => {
+        /// Every path served under `/system`, as listed in unknown path errors.
+        pub(super) const SYSTEM_PATHS: &[&str] = &[$(super::$path),*];
+
+        /// Every route served under `/system`, each path along with its handler.
+        pub fn routes() -> Vec<(&'static str, MethodRouter<RestService>)> {
+            vec![$((super::$path, $route)),*]
+        }
+    };
+}
+
+system_routes! {
+    GET_SYSTEM_STATE_SUMMARY_PATH => get(super::get_system_state_summary),
+    GET_SYSTEM_STATE_SUMMARY_JSON_PATH => system_state_summary_as(AcceptFormat::Json),
+    GET_SYSTEM_STATE_SUMMARY_BCS_PATH => system_state_summary_as(AcceptFormat::Bcs),
+    GET_COMMISSION_STATS_PATH => get(super::get_commission_stats),
+    GET_REPORTED_VALIDATORS_PATH => get(super::get_reported_validators),
+    GET_PENDING_VALIDATORS_PATH => get(super::get_pending_validators),
+    GET_STAKE_SUBSIDY_STATUS_PATH => get(super::get_stake_subsidy_status),
+    GET_VALIDATOR_PATH => get(super::get_validator),
+    GET_VALIDATOR_STAKING_POOL_PATH => get(super::get_validator_staking_pool),
+    VERIFY_VALIDATOR_PROOF_OF_POSSESSION_PATH => get(super::verify_validator_proof_of_possession),
+    GET_INACTIVE_POOLS_PATH => get(super::get_inactive_pools),
+    GET_TOTAL_STAKE_PATH => get(super::get_total_stake),
+    GET_SYSTEM_OBJECT_REFS_PATH => get(super::get_system_object_refs),
+    GET_VALIDATOR_ESTIMATED_REWARDS_PATH => get(super::get_validator_estimated_rewards),
+    POST_VALIDATORS_BATCH_PATH => post(super::get_validators_batch),
+    GET_SELF_VALIDATOR_PATH => get(super::get_self_validator),
+    GET_GAS_PRICE_DISTRIBUTION_PATH => get(super::get_gas_price_distribution),
+    GET_VALIDATOR_TIERS_PATH => get(super::get_validator_tiers),
+    GET_NEXT_RECONFIGURATION_PATH => get(super::get_next_reconfiguration),
+    GET_VALIDATOR_RATES_PATH => get(super::get_validator_rates),
+    GET_VALIDATORS_BY_TENURE_PATH => get(super::get_validators_by_tenure),
+    GET_SYSTEM_TABLE_SIZES_PATH => get(super::get_system_table_sizes),
+    GET_RAW_SYSTEM_STATE_PATH => get(super::get_raw_system_state),
+    GET_VALIDATOR_SET_LIMITS_PATH => get(super::get_validator_set_limits),
+    GET_VALIDATOR_OPERATION_CAP_PATH => get(super::get_validator_operation_cap),
+    GET_VALIDATORS_COUNT_PATH => get(super::get_validators_count),
+    GET_EPOCH_PATH => get(super::get_epoch),
+    GET_INCOMPLETE_NEXT_EPOCH_VALIDATORS_PATH => get(super::get_incomplete_next_epoch_validators),
+    GET_STAKE_SUBSIDY_RUNWAY_PATH => get(super::get_stake_subsidy_runway),
+    GET_GAS_PRICE_CHANGES_PATH => get(super::get_gas_price_changes),
+    GET_STAKING_POOL_VALIDATOR_PATH => get(super::get_staking_pool_validator),
+    GET_DECENTRALIZATION_PATH => get(super::get_decentralization),
+    GET_VALIDATORS_PATH => get(super::get_validators),
+    GET_VALIDATOR_CHURN_PATH => get(super::get_validator_churn),
+    GET_VALIDATORS_BY_STAKE_GROWTH_PATH => get(super::get_validators_by_stake_growth),
+    GET_BOOTSTRAP_PATH => get(super::get_bootstrap),
+    GET_VALIDATOR_EXCHANGE_RATES_PATH => get(super::get_validator_exchange_rates),
+    GET_VALIDATOR_REPUTATION_PATH => get(super::get_validator_reputation),
+    GET_REJOINED_VALIDATORS_PATH => get(super::get_rejoined_validators),
+    GET_PENDING_REMOVALS_PATH => get(super::get_pending_removals),
+    GET_STAKE_SUBSIDY_ACTUAL_PATH => get(super::get_stake_subsidy_actual),
+    GET_VALIDATOR_COMPARISON_PATH => get(super::get_validator_comparison),
+    GET_REWARDS_PARAMETERS_PATH => get(super::get_rewards_parameters),
+    GET_VALIDATORS_EXCHANGE_RATES_PATH => get(super::get_validators_exchange_rates),
+    GET_VALIDATORS_ESTIMATED_REWARDS_PATH => get(super::get_validators_estimated_rewards),
+    GET_VALIDATOR_ELIGIBILITY_PATH => get(super::get_validator_eligibility),
+    GET_SYSTEM_INFO_PATH => get(super::get_system_info),
+    GET_PENDING_STAKE_PATH => get(super::get_pending_stake),
+    GET_VALIDATOR_CHANGES_PATH => get(super::get_validator_changes),
+    GET_VALIDATOR_NETWORK_INDEX_PATH => get(super::get_validator_network_index),
+    GET_EPOCH_PROGRESS_PATH => get(super::get_epoch_progress),
+    GET_DECENTRALIZATION_WARNINGS_PATH => get(super::get_decentralization_warnings),
+    GET_VALIDATOR_ADDRESSES_PATH => get(super::get_validator_addresses),
+    GET_STAKE_SUBSIDY_BALANCE_CHECK_PATH => get(super::get_stake_subsidy_balance_check),
+    GET_SYSTEM_CHECKSUM_PATH => get(super::get_system_checksum),
+    GET_VALIDATOR_COMMISSION_CHANGES_PATH => get(super::get_validator_commission_changes),
+    GET_VALIDATORS_BY_POOL_BALANCE_PATH => get(super::get_validators_by_pool_balance),
+    GET_PROTOCOL_CONFIG_PATH => get(super::get_protocol_config),
+    GET_SYSTEM_WS_PATH => get(super::get_system_ws),
+    GET_VALIDATOR_ADDRESS_VALIDATION_PATH => get(super::get_validator_address_validation),
+    GET_REPORT_GRAPH_PATH => get(super::get_report_graph),
+    GET_STAKE_RECONCILIATION_PATH => get(super::get_stake_reconciliation),
+    GET_VALIDATOR_SET_SIZE_TREND_PATH => get(super::get_validator_set_size_trend),
+    GET_VALIDATOR_STAKE_SHARES_PATH => get(super::get_validator_stake_shares),
+    GET_VALIDATOR_CONSENSUS_ADDRESSES_PATH => get(super::get_validator_consensus_addresses),
+    GET_CHAIN_SAFETY_PATH => get(super::get_chain_safety),
+    GET_VALIDATOR_CANDIDATES_PATH => get(super::get_validator_candidates),
+}
+
+/// The system state summary, always rendered in `format` regardless of the `Accept` header.
+fn system_state_summary_as(format: AcceptFormat) -> MethodRouter<RestService> {
+    get(super::get_system_state_summary).layer(map_request_with_state(format, override_accept))
+}
@@ -0,0 +1,348 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{reader::StateReader, Result};
+use axum::{extract::State, Json};
+
+use super::SystemStateSummary;
+
+pub const GET_STAKE_SUBSIDY_STATUS_PATH: &str = "/system/stake-subsidy/status";
+
+pub async fn get_stake_subsidy_status(
+    State(state): State<StateReader>,
+) -> Result<Json<StakeSubsidyStatus>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(StakeSubsidyStatus::new(&summary)))
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct StakeSubsidyStatus {
+    /// The amount of stake subsidy to be drawn down per epoch.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub current_distribution_amount: u64,
+    /// Number of distributions that have occurred so far.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub distribution_counter: u64,
+    /// Number of distributions to occur before the distribution amount decays.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub period_length: u64,
+    /// The rate at which the distribution amount decays at the end of each period, in basis
+    /// points.
+    pub decrease_rate_bps: u16,
+    /// Number of distributions remaining until the distribution amount next decays. `None` if the
+    /// period length is zero.
+    #[serde_as(as = "Option<sui_types::sui_serde::BigInt<u64>>")]
+    pub epochs_until_next_decay: Option<u64>,
+}
+
+impl StakeSubsidyStatus {
+    pub fn new(summary: &SystemStateSummary) -> Self {
+        Self {
+            current_distribution_amount: summary.stake_subsidy_current_distribution_amount,
+            distribution_counter: summary.stake_subsidy_distribution_counter,
+            period_length: summary.stake_subsidy_period_length,
+            decrease_rate_bps: summary.stake_subsidy_decrease_rate,
+            epochs_until_next_decay: epochs_until_next_decay(
+                summary.stake_subsidy_distribution_counter,
+                summary.stake_subsidy_period_length,
+            ),
+        }
+    }
+}
+
+pub const GET_STAKE_SUBSIDY_ACTUAL_PATH: &str = "/system/stake-subsidy/actual";
+
+pub async fn get_stake_subsidy_actual(
+    State(state): State<StateReader>,
+) -> Result<Json<StakeSubsidyActual>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(StakeSubsidyActual::new(&summary)))
+}
+
+/// The stake subsidy distributions which actually happened, compared to the number of epochs
+/// since subsidies started.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct StakeSubsidyActual {
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub epoch: u64,
+    /// Number of distributions that have occurred so far.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub distribution_counter: u64,
+    /// Number of epochs since subsidies started in which no distribution occurred, for example
+    /// because the epoch ended in safe mode.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub epochs_skipped: u64,
+}
+
+impl StakeSubsidyActual {
+    pub fn new(summary: &SystemStateSummary) -> Self {
+        let epochs_skipped = summary
+            .epoch
+            .saturating_sub(summary.stake_subsidy_start_epoch)
+            .saturating_sub(summary.stake_subsidy_distribution_counter);
+
+        Self {
+            epoch: summary.epoch,
+            distribution_counter: summary.stake_subsidy_distribution_counter,
+            epochs_skipped,
+        }
+    }
+}
+
+pub const GET_STAKE_SUBSIDY_RUNWAY_PATH: &str = "/system/stake-subsidy/runway";
+
+pub async fn get_stake_subsidy_runway(
+    State(state): State<StateReader>,
+) -> Result<Json<StakeSubsidyRunway>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(StakeSubsidyRunway::new(&summary)))
+}
+
+/// How long the remaining stake subsidy balance is expected to last.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct StakeSubsidyRunway {
+    /// Balance of SUI left to be distributed as stake subsidies.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub balance: u64,
+    /// The amount of stake subsidy to be drawn down per epoch.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub current_distribution_amount: u64,
+    /// Number of epochs until the balance is exhausted, following the decay schedule of the
+    /// distribution amount. `None` if the distribution amount is zero and the balance will never
+    /// be exhausted.
+    #[serde_as(as = "Option<sui_types::sui_serde::BigInt<u64>>")]
+    pub estimated_epochs_remaining: Option<u64>,
+}
+
+impl StakeSubsidyRunway {
+    pub fn new(summary: &SystemStateSummary) -> Self {
+        Self {
+            balance: summary.stake_subsidy_balance,
+            current_distribution_amount: summary.stake_subsidy_current_distribution_amount,
+            estimated_epochs_remaining: epochs_remaining(
+                summary.stake_subsidy_balance,
+                summary.stake_subsidy_current_distribution_amount,
+                summary.stake_subsidy_distribution_counter,
+                summary.stake_subsidy_period_length,
+                summary.stake_subsidy_decrease_rate,
+            ),
+        }
+    }
+}
+
+pub const GET_STAKE_SUBSIDY_BALANCE_CHECK_PATH: &str = "/system/stake-subsidy/balance-check";
+
+/// Check whether the stake subsidy balance covers the next distribution, flagging when the
+/// subsidy pool is nearly exhausted.
+pub async fn get_stake_subsidy_balance_check(
+    State(state): State<StateReader>,
+) -> Result<Json<StakeSubsidyBalanceCheck>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(StakeSubsidyBalanceCheck::new(&summary)))
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct StakeSubsidyBalanceCheck {
+    /// Balance of SUI left to be distributed as stake subsidies.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub balance: u64,
+    /// The amount of stake subsidy to be drawn down per epoch.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub current_distribution_amount: u64,
+    /// Whether the balance covers the full distribution amount at the next epoch change.
+    pub can_distribute_next_epoch: bool,
+}
+
+impl StakeSubsidyBalanceCheck {
+    pub fn new(summary: &SystemStateSummary) -> Self {
+        Self {
+            balance: summary.stake_subsidy_balance,
+            current_distribution_amount: summary.stake_subsidy_current_distribution_amount,
+            can_distribute_next_epoch: summary.stake_subsidy_balance
+                >= summary.stake_subsidy_current_distribution_amount,
+        }
+    }
+}
+
+const BASIS_POINT_DENOMINATOR: u128 = 10_000;
+
+/// The number of distributions it takes to exhaust `balance`.
+///
+/// This mirrors `sui_system::stake_subsidy::advance_epoch`: each distribution draws down
+/// `amount`, or whatever is left of the balance, and the amount decreases by `decrease_rate_bps`
+/// every `period_length` distributions. Rather than stepping through every epoch this works a
+/// period at a time, which terminates quickly as the amount either decays geometrically or stops
+/// changing.
+fn epochs_remaining(
+    mut balance: u64,
+    mut amount: u64,
+    distribution_counter: u64,
+    period_length: u64,
+    decrease_rate_bps: u16,
+) -> Option<u64> {
+    let mut epochs: u64 = 0;
+    let mut epochs_in_period = epochs_until_next_decay(distribution_counter, period_length);
+
+    loop {
+        if balance == 0 {
+            return Some(epochs);
+        }
+        if amount == 0 {
+            return None;
+        }
+
+        let decrease =
+            (amount as u128 * decrease_rate_bps as u128 / BASIS_POINT_DENOMINATOR) as u64;
+        let period_epochs = match epochs_in_period {
+            Some(period_epochs) if decrease != 0 => period_epochs,
+            // The amount never changes again
+            _ => return Some(epochs.saturating_add(balance.div_ceil(amount))),
+        };
+
+        let period_total = amount as u128 * period_epochs as u128;
+        if balance as u128 <= period_total {
+            return Some(epochs.saturating_add(balance.div_ceil(amount)));
+        }
+
+        balance -= period_total as u64;
+        epochs = epochs.saturating_add(period_epochs);
+        amount -= decrease;
+        epochs_in_period = Some(period_length);
+    }
+}
+
+/// The distribution amount decays each time the distribution counter is incremented to a multiple
+/// of the period length.
+fn epochs_until_next_decay(distribution_counter: u64, period_length: u64) -> Option<u64> {
+    (period_length != 0).then(|| period_length - distribution_counter % period_length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::test_summary;
+
+    #[test]
+    fn next_decay() {
+        // At a period boundary the decay has just happened, so a full period remains
+        assert_eq!(epochs_until_next_decay(0, 30), Some(30));
+        assert_eq!(epochs_until_next_decay(60, 30), Some(30));
+
+        // Mid-period
+        assert_eq!(epochs_until_next_decay(10, 30), Some(20));
+        assert_eq!(epochs_until_next_decay(59, 30), Some(1));
+
+        assert_eq!(epochs_until_next_decay(10, 0), None);
+    }
+
+    /// Step through every distribution, as the system does at each epoch change.
+    fn simulate_epochs_remaining(
+        mut balance: u64,
+        mut amount: u64,
+        mut distribution_counter: u64,
+        period_length: u64,
+        decrease_rate_bps: u16,
+    ) -> u64 {
+        let mut epochs = 0;
+        while balance > 0 {
+            balance -= amount.min(balance);
+            epochs += 1;
+            distribution_counter += 1;
+            if distribution_counter % period_length == 0 {
+                amount -= amount * decrease_rate_bps as u64 / 10_000;
+            }
+        }
+        epochs
+    }
+
+    #[test]
+    fn skipped_distributions() {
+        let mut summary = test_summary(vec![]);
+        summary.epoch = 100;
+        summary.stake_subsidy_start_epoch = 20;
+        summary.stake_subsidy_distribution_counter = 75;
+
+        assert_eq!(
+            StakeSubsidyActual::new(&summary),
+            StakeSubsidyActual {
+                epoch: 100,
+                distribution_counter: 75,
+                epochs_skipped: 5,
+            }
+        );
+
+        // Before subsidies start, or with the counter ahead, nothing has been skipped
+        summary.stake_subsidy_start_epoch = 120;
+        assert_eq!(StakeSubsidyActual::new(&summary).epochs_skipped, 0);
+        summary.stake_subsidy_start_epoch = 0;
+        summary.stake_subsidy_distribution_counter = 101;
+        assert_eq!(StakeSubsidyActual::new(&summary).epochs_skipped, 0);
+    }
+
+    #[test]
+    fn balance_check() {
+        let mut summary = test_summary(vec![]);
+        summary.stake_subsidy_balance = 1_000;
+        summary.stake_subsidy_current_distribution_amount = 1_000;
+        assert_eq!(
+            StakeSubsidyBalanceCheck::new(&summary),
+            StakeSubsidyBalanceCheck {
+                balance: 1_000,
+                current_distribution_amount: 1_000,
+                can_distribute_next_epoch: true,
+            }
+        );
+
+        summary.stake_subsidy_balance = 999;
+        assert!(!StakeSubsidyBalanceCheck::new(&summary).can_distribute_next_epoch);
+    }
+
+    #[test]
+    fn decaying_runway() {
+        // 3 epochs at 1000 then the amount decays by 25% every 4 epochs: 1000, 750, 563, ...
+        let runway = epochs_remaining(10_000, 1_000, 3, 4, 2_500);
+        assert_eq!(runway, Some(21));
+        // Naively dividing the balance by the current amount underestimates the runway
+        assert_ne!(runway, Some(10_000 / 1_000));
+
+        for (balance, amount, counter, period_length, decrease_rate_bps) in [
+            (10_000, 1_000, 3, 4, 2_500),
+            (1_000, 100, 0, 2, 5_000),
+            (1_000_000, 100, 0, 10, 1_000),
+            (999, 10, 7, 30, 0),
+            (1, 1_000, 0, 30, 1_000),
+        ] {
+            assert_eq!(
+                epochs_remaining(balance, amount, counter, period_length, decrease_rate_bps),
+                Some(simulate_epochs_remaining(
+                    balance,
+                    amount,
+                    counter,
+                    period_length,
+                    decrease_rate_bps
+                )),
+            );
+        }
+
+        assert_eq!(epochs_remaining(0, 1_000, 0, 30, 1_000), Some(0));
+        assert_eq!(epochs_remaining(1_000, 0, 0, 30, 1_000), None);
+        // Without a period the amount never decays
+        assert_eq!(epochs_remaining(1_000, 300, 0, 0, 1_000), Some(4));
+    }
+}
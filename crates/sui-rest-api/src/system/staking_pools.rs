@@ -0,0 +1,89 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{reader::StateReader, RestError, Result};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use sui_sdk2::types::ObjectId;
+
+use super::{SystemStateSummary, ValidatorSummary};
+
+pub const GET_STAKING_POOL_VALIDATOR_PATH: &str = "/system/staking-pools/:pool_id";
+
+/// Resolve the validator owning a staking pool.
+///
+/// Active validators are checked first, followed by the pending validators referenced by the
+/// `staking_pool_mappings_id` table, and finally the validators which owned now inactive pools.
+pub async fn get_staking_pool_validator(
+    Path(pool_id): Path<ObjectId>,
+    State(state): State<StateReader>,
+) -> Result<Json<ValidatorSummary>> {
+    state
+        .spawn_read(move |state| read_staking_pool_validator(state, pool_id))
+        .await
+        .map(Json)
+}
+
+fn read_staking_pool_validator(state: &StateReader, pool_id: ObjectId) -> Result<ValidatorSummary> {
+    let summary = state.get_system_state_summary()?;
+
+    if let Some(validator) = find_validator_by_staking_pool(&summary, pool_id) {
+        return Ok(validator.clone());
+    }
+
+    if let Some(address) =
+        state.get_staking_pool_validator_address(summary.staking_pool_mappings_id, pool_id)?
+    {
+        if let Some(validator) = state
+            .get_pending_active_validators()?
+            .into_iter()
+            .find(|validator| validator.address == address)
+        {
+            return Ok(validator);
+        }
+    }
+
+    state
+        .get_inactive_pool_validator(summary.inactive_pools_id, pool_id)?
+        .ok_or_else(|| {
+            RestError::new(
+                StatusCode::NOT_FOUND,
+                format!("no validator found for staking pool {pool_id}"),
+            )
+        })
+}
+
+/// Look up an active validator by the id of its staking pool.
+pub(crate) fn find_validator_by_staking_pool(
+    summary: &SystemStateSummary,
+    staking_pool_id: ObjectId,
+) -> Option<&ValidatorSummary> {
+    summary
+        .active_validators
+        .iter()
+        .find(|validator| validator.staking_pool_id == staking_pool_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::{test_summary, test_validator};
+
+    #[test]
+    fn active_pool_lookup() {
+        let summary = test_summary((0..3).map(|i| test_validator(i * 10)).collect());
+
+        let pool_id = summary.active_validators[1].staking_pool_id;
+        let validator = find_validator_by_staking_pool(&summary, pool_id).unwrap();
+        assert_eq!(validator.name, "validator-10");
+        assert_eq!(validator.staking_pool_id, ObjectId::new([12; 32]));
+
+        // Other ids of the validator don't match
+        let operation_cap_id = summary.active_validators[1].operation_cap_id;
+        assert!(find_validator_by_staking_pool(&summary, operation_cap_id).is_none());
+        assert!(find_validator_by_staking_pool(&summary, ObjectId::new([0xff; 32])).is_none());
+    }
+}
@@ -0,0 +1,645 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{reader::StateReader, Result};
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use sui_sdk2::types::Address;
+
+use super::{SystemStateSummary, ValidatorSummary};
+
+pub const GET_COMMISSION_STATS_PATH: &str = "/system/validators/commission-stats";
+
+pub async fn get_commission_stats(
+    State(state): State<StateReader>,
+) -> Result<Json<CommissionStats>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(CommissionStats::new(&summary.active_validators)))
+}
+
+/// Distribution statistics of the commission rates of the active validator set.
+///
+/// All rates are expressed in basis points.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CommissionStats {
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub min: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub max: u64,
+    pub mean: f64,
+    pub median: f64,
+    /// Mean commission rate weighted by each validator's voting power.
+    pub stake_weighted_mean: f64,
+}
+
+impl CommissionStats {
+    pub fn new(validators: &[ValidatorSummary]) -> Self {
+        let mut rates = validators
+            .iter()
+            .map(|validator| validator.commission_rate)
+            .collect::<Vec<_>>();
+        rates.sort_unstable();
+
+        let min = rates.first().copied().unwrap_or(0);
+        let max = rates.last().copied().unwrap_or(0);
+
+        let mean = if rates.is_empty() {
+            0.0
+        } else {
+            rates.iter().map(|rate| *rate as f64).sum::<f64>() / rates.len() as f64
+        };
+
+        let median = match rates.len() {
+            0 => 0.0,
+            len if len % 2 == 0 => (rates[len / 2 - 1] as f64 + rates[len / 2] as f64) / 2.0,
+            len => rates[len / 2] as f64,
+        };
+
+        let total_voting_power = validators
+            .iter()
+            .map(|validator| validator.voting_power as f64)
+            .sum::<f64>();
+        let stake_weighted_mean = if total_voting_power == 0.0 {
+            0.0
+        } else {
+            validators
+                .iter()
+                .map(|validator| validator.commission_rate as f64 * validator.voting_power as f64)
+                .sum::<f64>()
+                / total_voting_power
+        };
+
+        Self {
+            min,
+            max,
+            mean,
+            median,
+            stake_weighted_mean,
+        }
+    }
+}
+
+pub const GET_GAS_PRICE_DISTRIBUTION_PATH: &str = "/system/gas-price/distribution";
+
+pub async fn get_gas_price_distribution(
+    State(state): State<StateReader>,
+) -> Result<Json<GasPriceDistribution>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(GasPriceDistribution::new(&summary.active_validators)))
+}
+
+/// Stake-weighted distribution of the gas prices quoted by the active validator set.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GasPriceDistribution {
+    pub gas_price: Percentiles,
+    pub next_epoch_gas_price: Percentiles,
+}
+
+impl GasPriceDistribution {
+    pub fn new(validators: &[ValidatorSummary]) -> Self {
+        let gas_prices = validators
+            .iter()
+            .map(|validator| (validator.gas_price, validator.voting_power))
+            .collect();
+        let next_epoch_gas_prices = validators
+            .iter()
+            .map(|validator| (validator.next_epoch_gas_price, validator.voting_power))
+            .collect();
+
+        Self {
+            gas_price: Percentiles::new(gas_prices),
+            next_epoch_gas_price: Percentiles::new(next_epoch_gas_prices),
+        }
+    }
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Percentiles {
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub p10: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub p25: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub p50: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub p75: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub p90: u64,
+}
+
+impl Percentiles {
+    /// Compute the weighted percentiles of `(value, weight)` pairs.
+    ///
+    /// Each percentile is the smallest value for which the combined weight of all values less than
+    /// or equal to it reaches that percentage of the total weight. If every weight is zero all
+    /// values are weighted equally.
+    pub fn new(mut values: Vec<(u64, u64)>) -> Self {
+        values.sort_unstable();
+
+        if values.iter().all(|(_, weight)| *weight == 0) {
+            values.iter_mut().for_each(|(_, weight)| *weight = 1);
+        }
+        let total_weight = values
+            .iter()
+            .map(|(_, weight)| *weight as u128)
+            .sum::<u128>();
+
+        let percentile = |percent: u128| {
+            let mut cumulative_weight = 0;
+            values
+                .iter()
+                .find(|(_, weight)| {
+                    cumulative_weight += *weight as u128;
+                    cumulative_weight * 100 >= total_weight * percent
+                })
+                .map(|(value, _)| *value)
+                .unwrap_or(0)
+        };
+
+        Self {
+            p10: percentile(10),
+            p25: percentile(25),
+            p50: percentile(50),
+            p75: percentile(75),
+            p90: percentile(90),
+        }
+    }
+}
+
+pub const GET_GAS_PRICE_CHANGES_PATH: &str = "/system/validators/gas-price-changes";
+
+pub async fn get_gas_price_changes(
+    State(state): State<StateReader>,
+) -> Result<Json<Vec<GasPriceChange>>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(gas_price_changes(&summary.active_validators)))
+}
+
+/// A validator whose gas price quote for the next epoch differs from its current one.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GasPriceChange {
+    pub address: Address,
+    pub name: String,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub gas_price: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub next_epoch_gas_price: u64,
+    /// Absolute difference between the current and next epoch gas price.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub delta: u64,
+    pub direction: GasPriceDirection,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GasPriceDirection {
+    Increase,
+    Decrease,
+}
+
+/// Collect the validators which are repricing at the end of the epoch.
+pub(crate) fn gas_price_changes(validators: &[ValidatorSummary]) -> Vec<GasPriceChange> {
+    validators
+        .iter()
+        .filter(|validator| validator.next_epoch_gas_price != validator.gas_price)
+        .map(|validator| {
+            let direction = if validator.next_epoch_gas_price > validator.gas_price {
+                GasPriceDirection::Increase
+            } else {
+                GasPriceDirection::Decrease
+            };
+
+            GasPriceChange {
+                address: validator.address,
+                name: validator.name.clone(),
+                gas_price: validator.gas_price,
+                next_epoch_gas_price: validator.next_epoch_gas_price,
+                delta: validator.gas_price.abs_diff(validator.next_epoch_gas_price),
+                direction,
+            }
+        })
+        .collect()
+}
+
+pub const GET_VALIDATORS_BY_STAKE_GROWTH_PATH: &str = "/system/validators/growth";
+
+pub async fn get_validators_by_stake_growth(
+    State(state): State<StateReader>,
+) -> Result<Json<Vec<StakeGrowth>>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(stake_growth(&summary.active_validators)))
+}
+
+/// How much the stake of a validator is set to change at the end of the epoch.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct StakeGrowth {
+    pub address: Address,
+    pub name: String,
+    /// Stake currently in the validator's pool, including stake pending activation.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub stake: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub next_epoch_stake: u64,
+    /// Difference between the next epoch and current stake, negative if the validator is losing
+    /// delegation.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<i128>")]
+    pub delta: i128,
+}
+
+/// Rank validators from the largest gain in stake to the largest loss.
+pub(crate) fn stake_growth(validators: &[ValidatorSummary]) -> Vec<StakeGrowth> {
+    let mut growth = validators
+        .iter()
+        .map(|validator| {
+            let stake = validator
+                .staking_pool_sui_balance
+                .saturating_add(validator.pending_stake);
+
+            StakeGrowth {
+                address: validator.address,
+                name: validator.name.clone(),
+                stake,
+                next_epoch_stake: validator.next_epoch_stake,
+                delta: validator.next_epoch_stake as i128 - stake as i128,
+            }
+        })
+        .collect::<Vec<_>>();
+    growth.sort_by(|a, b| b.delta.cmp(&a.delta));
+
+    growth
+}
+
+pub const GET_VALIDATORS_BY_POOL_BALANCE_PATH: &str = "/system/validators/by-pool-balance";
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct PoolBalanceRankingQueryParameters {
+    /// Order of the ranking by pool balance. Defaults to `desc`.
+    #[serde(default)]
+    pub order: SortOrder,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    #[default]
+    Desc,
+}
+
+/// Rank validators by the SUI balance of their staking pool, e.g. for TVL leaderboards.
+pub async fn get_validators_by_pool_balance(
+    Query(parameters): Query<PoolBalanceRankingQueryParameters>,
+    State(state): State<StateReader>,
+) -> Result<Json<Vec<PoolBalanceRank>>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(pool_balance_ranking(
+        &summary.active_validators,
+        parameters.order,
+    )))
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PoolBalanceRank {
+    pub address: Address,
+    pub name: String,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub staking_pool_sui_balance: u64,
+    /// Share of the total staked across the active validators held by this validator and every
+    /// validator ranked before it, between 0 and 1.
+    pub cumulative_share: f64,
+}
+
+pub(crate) fn pool_balance_ranking(
+    validators: &[ValidatorSummary],
+    order: SortOrder,
+) -> Vec<PoolBalanceRank> {
+    let mut validators = validators.iter().collect::<Vec<_>>();
+    match order {
+        SortOrder::Asc => validators.sort_by_key(|validator| validator.staking_pool_sui_balance),
+        SortOrder::Desc => validators
+            .sort_by_key(|validator| std::cmp::Reverse(validator.staking_pool_sui_balance)),
+    }
+
+    let total = validators
+        .iter()
+        .map(|validator| validator.staking_pool_sui_balance as u128)
+        .sum::<u128>();
+
+    // Accumulate exactly so that the last validator's share is exactly 1
+    let mut cumulative = 0u128;
+    validators
+        .into_iter()
+        .map(|validator| {
+            cumulative += validator.staking_pool_sui_balance as u128;
+
+            PoolBalanceRank {
+                address: validator.address,
+                name: validator.name.clone(),
+                staking_pool_sui_balance: validator.staking_pool_sui_balance,
+                cumulative_share: if total == 0 {
+                    0.0
+                } else {
+                    cumulative as f64 / total as f64
+                },
+            }
+        })
+        .collect()
+}
+
+pub const GET_VALIDATOR_STAKE_SHARES_PATH: &str = "/system/validators/shares";
+
+/// List each active validator's share of the total stake of the network, in the order of the
+/// active validator set.
+pub async fn get_validator_stake_shares(
+    State(state): State<StateReader>,
+) -> Result<Json<Vec<StakeShare>>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(stake_shares(&summary)))
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct StakeShare {
+    pub address: Address,
+    pub name: String,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub staking_pool_sui_balance: u64,
+    /// The validator's pool balance over the `total_stake` of the system state, between 0 and 1.
+    /// The shares of all active validators sum to approximately 1.
+    pub stake_share: f64,
+}
+
+pub(crate) fn stake_shares(summary: &SystemStateSummary) -> Vec<StakeShare> {
+    summary
+        .active_validators
+        .iter()
+        .map(|validator| StakeShare {
+            address: validator.address,
+            name: validator.name.clone(),
+            staking_pool_sui_balance: validator.staking_pool_sui_balance,
+            stake_share: if summary.total_stake == 0 {
+                0.0
+            } else {
+                validator.staking_pool_sui_balance as f64 / summary.total_stake as f64
+            },
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::{test_summary, test_validator, validators_with};
+
+    #[test]
+    fn commission_stats() {
+        let validators = [(100, 1000), (200, 3000), (400, 2000), (1000, 4000)]
+            .into_iter()
+            .enumerate()
+            .map(|(i, (commission_rate, voting_power))| {
+                let mut validator = test_validator(i as u8);
+                validator.commission_rate = commission_rate;
+                validator.voting_power = voting_power;
+                validator
+            })
+            .collect::<Vec<_>>();
+
+        let stats = CommissionStats::new(&validators);
+
+        assert_eq!(stats.min, 100);
+        assert_eq!(stats.max, 1000);
+        assert_eq!(stats.mean, 425.0);
+        assert_eq!(stats.median, 300.0);
+        // (100 * 1000 + 200 * 3000 + 400 * 2000 + 1000 * 4000) / 10000
+        assert_eq!(stats.stake_weighted_mean, 550.0);
+
+        let stats = CommissionStats::new(&validators[..3]);
+        assert_eq!(stats.median, 200.0);
+    }
+
+    #[test]
+    fn gas_price_percentiles() {
+        // Gas prices 100..=1000 with voting power increasing alongside
+        let validators = (1..=10u64)
+            .map(|i| {
+                let mut validator = test_validator(i as u8);
+                validator.gas_price = i * 100;
+                validator.next_epoch_gas_price = 1000;
+                validator.voting_power = i;
+                validator
+            })
+            .collect::<Vec<_>>();
+
+        // Total weight is 55, cumulative weights are 1, 3, 6, 10, 15, 21, 28, 36, 45, 55
+        let distribution = GasPriceDistribution::new(&validators);
+        assert_eq!(
+            distribution.gas_price,
+            Percentiles {
+                p10: 300,
+                p25: 500,
+                p50: 700,
+                p75: 900,
+                p90: 1000,
+            }
+        );
+        assert_eq!(distribution.next_epoch_gas_price.p10, 1000);
+        assert_eq!(distribution.next_epoch_gas_price.p90, 1000);
+
+        // Without any voting power every validator counts equally
+        let unweighted = Percentiles::new((1..=10).map(|i| (i * 100, 0)).collect());
+        assert_eq!(unweighted.p10, 100);
+        assert_eq!(unweighted.p50, 500);
+        assert_eq!(unweighted.p90, 900);
+    }
+
+    #[test]
+    fn gas_price_change_filter() {
+        let validators = validators_with(
+            |validator, (gas_price, next_epoch_gas_price)| {
+                validator.gas_price = gas_price;
+                validator.next_epoch_gas_price = next_epoch_gas_price;
+            },
+            [(1000, 1000), (1000, 1200), (800, 800), (900, 750)],
+        );
+
+        let changes = gas_price_changes(&validators);
+        assert_eq!(
+            changes,
+            vec![
+                GasPriceChange {
+                    address: validators[1].address,
+                    name: "validator-10".to_owned(),
+                    gas_price: 1000,
+                    next_epoch_gas_price: 1200,
+                    delta: 200,
+                    direction: GasPriceDirection::Increase,
+                },
+                GasPriceChange {
+                    address: validators[3].address,
+                    name: "validator-30".to_owned(),
+                    gas_price: 900,
+                    next_epoch_gas_price: 750,
+                    delta: 150,
+                    direction: GasPriceDirection::Decrease,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn stake_growth_ranking() {
+        // (staking pool balance, pending stake, next epoch stake)
+        let validators = validators_with(
+            |validator, (balance, pending_stake, next_epoch_stake)| {
+                validator.staking_pool_sui_balance = balance;
+                validator.pending_stake = pending_stake;
+                validator.next_epoch_stake = next_epoch_stake;
+            },
+            [
+                (1000, 0, 900),
+                (1000, 200, 1500),
+                (500, 0, 500),
+                (2000, 100, 1600),
+            ],
+        );
+
+        let growth = stake_growth(&validators);
+        assert_eq!(
+            growth
+                .iter()
+                .map(|growth| (growth.name.as_str(), growth.delta))
+                .collect::<Vec<_>>(),
+            vec![
+                ("validator-10", 300),
+                ("validator-20", 0),
+                ("validator-0", -100),
+                ("validator-30", -500),
+            ]
+        );
+        assert_eq!(growth[0].stake, 1200);
+        assert_eq!(growth[0].next_epoch_stake, 1500);
+
+        // Negative deltas keep their sign when serialized
+        let json = serde_json::to_value(&growth[3]).unwrap();
+        assert_eq!(json["delta"], "-500");
+    }
+
+    #[test]
+    fn empty_validator_set() {
+        let stats = CommissionStats::new(&[]);
+        assert_eq!(
+            stats,
+            CommissionStats {
+                min: 0,
+                max: 0,
+                mean: 0.0,
+                median: 0.0,
+                stake_weighted_mean: 0.0,
+            }
+        );
+
+        // Validators with no voting power shouldn't produce a NaN weighted mean either
+        let stats = CommissionStats::new(&[test_validator(1)]);
+        assert_eq!(stats.stake_weighted_mean, 0.0);
+        assert!(serde_json::to_string(&stats).is_ok());
+
+        let distribution = GasPriceDistribution::new(&[]);
+        assert_eq!(distribution.gas_price, Percentiles::new(vec![]));
+        assert_eq!(distribution.gas_price.p50, 0);
+    }
+
+    #[test]
+    fn pool_balance_leaderboard() {
+        let validators = validators_with(
+            |validator, balance| validator.staking_pool_sui_balance = balance,
+            [300, 100, 400, 200],
+        );
+
+        let ranking = pool_balance_ranking(&validators, SortOrder::Desc);
+        assert_eq!(
+            ranking
+                .iter()
+                .map(|rank| rank.staking_pool_sui_balance)
+                .collect::<Vec<_>>(),
+            [400, 300, 200, 100]
+        );
+        assert_eq!(ranking[0].address, validators[2].address);
+        assert_eq!(
+            ranking
+                .iter()
+                .map(|rank| rank.cumulative_share)
+                .collect::<Vec<_>>(),
+            [0.4, 0.7, 0.9, 1.0]
+        );
+
+        let ranking = pool_balance_ranking(&validators, SortOrder::Asc);
+        assert_eq!(ranking[0].staking_pool_sui_balance, 100);
+        assert_eq!(ranking.last().unwrap().cumulative_share, 1.0);
+
+        // Without any stake nothing is ranked as holding a share
+        let empty = validators
+            .into_iter()
+            .map(|mut validator| {
+                validator.staking_pool_sui_balance = 0;
+                validator
+            })
+            .collect::<Vec<_>>();
+        assert!(pool_balance_ranking(&empty, SortOrder::Desc)
+            .iter()
+            .all(|rank| rank.cumulative_share == 0.0));
+    }
+
+    #[test]
+    fn validator_stake_shares() {
+        let validators = validators_with(
+            |validator, balance| validator.staking_pool_sui_balance = balance,
+            [500, 250, 150, 100],
+        );
+        let mut summary = test_summary(validators);
+        summary.total_stake = 1_000;
+
+        let shares = stake_shares(&summary);
+        assert_eq!(
+            shares
+                .iter()
+                .map(|share| share.stake_share)
+                .collect::<Vec<_>>(),
+            [0.5, 0.25, 0.15, 0.1]
+        );
+        assert_eq!(shares[1].address, summary.active_validators[1].address);
+        assert_eq!(shares[1].staking_pool_sui_balance, 250);
+        let total = shares.iter().map(|share| share.stake_share).sum::<f64>();
+        assert!((total - 1.0).abs() < 1e-9, "{total}");
+
+        // A network without stake doesn't divide by zero
+        summary.total_stake = 0;
+        assert!(stake_shares(&summary)
+            .iter()
+            .all(|share| share.stake_share == 0.0));
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{accept::APPLICATION_NDJSON, Result, APPLICATION_JSON};
+use axum::{
+    body::{Bytes, StreamBody},
+    http::header,
+    response::{IntoResponse, Response},
+};
+
+use super::SystemStateSummary;
+
+/// How `active_validators` renders in a summary with no active validators.
+const EMPTY_ACTIVE_VALIDATORS: &str = "\"active_validators\":[]";
+
+/// Render a summary as JSON, streaming the active validators into the response body one chunk per
+/// validator rather than as a single contiguous payload.
+///
+/// The output is byte-for-byte identical to the buffered `Json(summary)`. Each validator is only
+/// serialized as its chunk is streamed, so the body is never held in memory as a whole. The
+/// `Content-Length` is computed up front by a pass which serializes every validator without
+/// keeping the output, which also surfaces any serialization error before the body starts.
+pub(crate) fn json_response(mut summary: SystemStateSummary) -> Result<Response> {
+    let validators = std::mem::take(&mut summary.active_validators);
+
+    // Everything other than the active validators is small, so is serialized eagerly and split
+    // around the (now empty) validator list.
+    let skeleton = serde_json::to_string(&summary).map_err(anyhow::Error::from)?;
+    let split = skeleton
+        .find(EMPTY_ACTIVE_VALIDATORS)
+        .map(|start| start + EMPTY_ACTIVE_VALIDATORS.len() - 1)
+        .ok_or_else(|| anyhow::anyhow!("active_validators missing from serialized summary"))?;
+    let (prefix, suffix) = skeleton.split_at(split);
+
+    // Starting from the commas separating the validators
+    let mut validators_length = ByteCount(validators.len().saturating_sub(1));
+    for validator in &validators {
+        serde_json::to_writer(&mut validators_length, validator).map_err(anyhow::Error::from)?;
+    }
+    let content_length = prefix.len() + suffix.len() + validators_length.0;
+
+    let prefix = Bytes::copy_from_slice(prefix.as_bytes());
+    let suffix = Bytes::copy_from_slice(suffix.as_bytes());
+    let validators = validators.into_iter().enumerate().map(|(i, validator)| {
+        let mut buf = if i == 0 { Vec::new() } else { vec![b','] };
+        serde_json::to_writer(&mut buf, &validator)?;
+        Ok::<_, serde_json::Error>(Bytes::from(buf))
+    });
+    let chunks = std::iter::once(Ok(prefix))
+        .chain(validators)
+        .chain(std::iter::once(Ok(suffix)));
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, APPLICATION_JSON.to_owned()),
+            (header::CONTENT_LENGTH, content_length.to_string()),
+        ],
+        StreamBody::new(futures::stream::iter(chunks)),
+    )
+        .into_response())
+}
+
+/// A writer which only counts the bytes written to it, starting from its initial value.
+struct ByteCount(usize);
+
+impl std::io::Write for ByteCount {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Render a list as newline-delimited JSON, streaming one item per line into the response body.
+pub(crate) fn ndjson_response<T>(items: Vec<T>) -> Response
+where
+    T: serde::Serialize + Send + 'static,
+{
+    let lines = items.into_iter().map(|item| {
+        let mut line = serde_json::to_vec(&item)?;
+        line.push(b'\n');
+        Ok::<_, serde_json::Error>(Bytes::from(line))
+    });
+
+    (
+        [(header::CONTENT_TYPE, APPLICATION_NDJSON)],
+        StreamBody::new(futures::stream::iter(lines)),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::{
+        tests::{test_summary, test_validator},
+        ValidatorSummary,
+    };
+
+    #[tokio::test]
+    async fn streamed_matches_buffered() {
+        for validators in [
+            vec![],
+            vec![test_validator(1)],
+            (0..5).map(|i| test_validator(i * 10)).collect(),
+        ] {
+            let mut summary = test_summary(validators);
+            summary.at_risk_validators = summary
+                .active_validators
+                .iter()
+                .map(|validator| (validator.address, 2))
+                .collect();
+
+            let buffered = serde_json::to_vec(&summary).unwrap();
+            let response = json_response(summary).unwrap();
+            assert_eq!(
+                response.headers()[header::CONTENT_LENGTH],
+                buffered.len().to_string()
+            );
+
+            let streamed = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            assert_eq!(streamed.as_ref(), buffered.as_slice());
+        }
+    }
+
+    #[tokio::test]
+    async fn ndjson_lines() {
+        let validators = (0..3).map(|i| test_validator(i * 10)).collect::<Vec<_>>();
+
+        let response = ndjson_response(validators.clone());
+        assert_eq!(response.headers()[header::CONTENT_TYPE], APPLICATION_NDJSON);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body = std::str::from_utf8(&body).unwrap();
+        assert!(body.ends_with('\n'));
+
+        let lines = body
+            .lines()
+            .map(|line| serde_json::from_str::<ValidatorSummary>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(lines.len(), validators.len());
+        for (line, validator) in lines.iter().zip(&validators) {
+            assert_eq!(line.address, validator.address);
+        }
+    }
+}
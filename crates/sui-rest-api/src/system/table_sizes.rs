@@ -0,0 +1,104 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{reader::StateReader, Result};
+use axum::{extract::State, Json};
+use sui_sdk2::types::{Address, ObjectId};
+
+use super::SystemStateSummary;
+
+pub const GET_SYSTEM_TABLE_SIZES_PATH: &str = "/system/table-sizes";
+
+pub async fn get_system_table_sizes(
+    State(state): State<StateReader>,
+) -> Result<Json<SystemTableSizes>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(SystemTableSizes::new(&summary)))
+}
+
+/// Number of entries in each of the tables referenced by the system state.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SystemTableSizes {
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub pending_active_validators: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub staking_pool_mappings: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub inactive_pools: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub validator_candidates: u64,
+    /// Size of the exchange rates table of each active validator.
+    pub exchange_rates: Vec<ExchangeRatesTableSize>,
+    /// Combined size of all of the active validators' exchange rates tables.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub total_exchange_rates: u64,
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ExchangeRatesTableSize {
+    pub address: Address,
+    pub exchange_rates_id: ObjectId,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub size: u64,
+}
+
+impl SystemTableSizes {
+    pub fn new(summary: &SystemStateSummary) -> Self {
+        let exchange_rates = summary
+            .active_validators
+            .iter()
+            .map(|validator| ExchangeRatesTableSize {
+                address: validator.address,
+                exchange_rates_id: validator.exchange_rates_id,
+                size: validator.exchange_rates_size,
+            })
+            .collect::<Vec<_>>();
+        let total_exchange_rates = exchange_rates.iter().map(|table| table.size).sum();
+
+        Self {
+            pending_active_validators: summary.pending_active_validators_size,
+            staking_pool_mappings: summary.staking_pool_mappings_size,
+            inactive_pools: summary.inactive_pools_size,
+            validator_candidates: summary.validator_candidates_size,
+            exchange_rates,
+            total_exchange_rates,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::{test_summary, validators_with};
+
+    #[test]
+    fn table_sizes() {
+        let validators = validators_with(
+            |validator, size| validator.exchange_rates_size = size,
+            [3, 12],
+        );
+        let mut summary = test_summary(validators);
+        summary.pending_active_validators_size = 1;
+        summary.staking_pool_mappings_size = 2;
+        summary.inactive_pools_size = 4;
+        summary.validator_candidates_size = 8;
+
+        let sizes = SystemTableSizes::new(&summary);
+        assert_eq!(sizes.pending_active_validators, 1);
+        assert_eq!(sizes.staking_pool_mappings, 2);
+        assert_eq!(sizes.inactive_pools, 4);
+        assert_eq!(sizes.validator_candidates, 8);
+        assert_eq!(sizes.exchange_rates.len(), 2);
+        for (table, validator) in sizes.exchange_rates.iter().zip(&summary.active_validators) {
+            assert_eq!(table.address, validator.address);
+            assert_eq!(table.exchange_rates_id, validator.exchange_rates_id);
+            assert_eq!(table.size, validator.exchange_rates_size);
+        }
+        assert_eq!(sizes.total_exchange_rates, 15);
+    }
+}
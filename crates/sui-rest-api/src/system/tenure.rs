@@ -0,0 +1,162 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{reader::StateReader, Result};
+use axum::{extract::State, Json};
+use sui_sdk2::types::Address;
+
+use super::{SystemStateSummary, ValidatorSummary};
+
+pub const GET_VALIDATORS_BY_TENURE_PATH: &str = "/system/validators/by-tenure";
+
+pub async fn get_validators_by_tenure(
+    State(state): State<StateReader>,
+) -> Result<Json<Vec<ValidatorTenure>>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(validators_by_tenure(&summary)))
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ValidatorTenure {
+    #[serde(flatten)]
+    pub validator: ValidatorSummary,
+    /// Number of epochs since the validator's staking pool was activated. `0` if it hasn't been
+    /// activated.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub epochs_active: u64,
+}
+
+/// The active validators ordered by the epoch their staking pool was activated, oldest first.
+/// Validators without an activation epoch are treated as the newest.
+pub(crate) fn validators_by_tenure(summary: &SystemStateSummary) -> Vec<ValidatorTenure> {
+    let mut validators = summary
+        .active_validators
+        .iter()
+        .map(|validator| ValidatorTenure {
+            validator: validator.clone(),
+            epochs_active: validator
+                .staking_pool_activation_epoch
+                .map(|epoch| summary.epoch.saturating_sub(epoch))
+                .unwrap_or(0),
+        })
+        .collect::<Vec<_>>();
+
+    validators.sort_by_key(|tenure| {
+        let activation_epoch = tenure.validator.staking_pool_activation_epoch;
+        (activation_epoch.is_none(), activation_epoch)
+    });
+
+    validators
+}
+
+pub const GET_REJOINED_VALIDATORS_PATH: &str = "/system/validators/rejoined";
+
+pub async fn get_rejoined_validators(
+    State(state): State<StateReader>,
+) -> Result<Json<Vec<RejoinedValidator>>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(rejoined_validators(&summary.active_validators)))
+}
+
+/// An active validator whose staking pool was deactivated at some point and has since been
+/// reactivated.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RejoinedValidator {
+    pub address: Address,
+    pub name: String,
+    /// Epoch at which the validator's staking pool was deactivated.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub deactivation_epoch: u64,
+    /// Epoch at which the validator's staking pool was most recently activated.
+    #[serde_as(as = "Option<sui_types::sui_serde::BigInt<u64>>")]
+    pub activation_epoch: Option<u64>,
+}
+
+/// Collect the active validators which still carry a staking pool deactivation epoch.
+pub(crate) fn rejoined_validators(validators: &[ValidatorSummary]) -> Vec<RejoinedValidator> {
+    validators
+        .iter()
+        .filter_map(|validator| {
+            Some(RejoinedValidator {
+                address: validator.address,
+                name: validator.name.clone(),
+                deactivation_epoch: validator.staking_pool_deactivation_epoch?,
+                activation_epoch: validator.staking_pool_activation_epoch,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::{test_summary, validators_with};
+
+    #[test]
+    fn ordered_by_tenure() {
+        let validators = validators_with(
+            |validator, activation_epoch| {
+                validator.staking_pool_activation_epoch = activation_epoch
+            },
+            [Some(7), None, Some(0), Some(7), Some(10)],
+        );
+        let summary = test_summary(validators);
+        assert_eq!(summary.epoch, 10);
+
+        let by_tenure = validators_by_tenure(&summary);
+        let names = by_tenure
+            .iter()
+            .map(|tenure| tenure.validator.name.as_str())
+            .collect::<Vec<_>>();
+        // Ties keep the order of the active validator set
+        assert_eq!(
+            names,
+            [
+                "validator-20",
+                "validator-0",
+                "validator-30",
+                "validator-40",
+                "validator-10"
+            ]
+        );
+
+        let epochs_active = by_tenure
+            .iter()
+            .map(|tenure| tenure.epochs_active)
+            .collect::<Vec<_>>();
+        assert_eq!(epochs_active, [10, 3, 3, 0, 0]);
+
+        let json = serde_json::to_value(&by_tenure[0]).unwrap();
+        assert_eq!(json["epochs_active"], serde_json::Value::from("10"));
+        assert_eq!(json["name"], serde_json::Value::from("validator-20"));
+    }
+
+    #[test]
+    fn rejoined() {
+        let validators = validators_with(
+            |validator, (activation_epoch, deactivation_epoch)| {
+                validator.staking_pool_activation_epoch = activation_epoch;
+                validator.staking_pool_deactivation_epoch = deactivation_epoch;
+            },
+            [(Some(0), None), (Some(8), Some(5)), (None, None)],
+        );
+
+        assert_eq!(
+            rejoined_validators(&validators),
+            vec![RejoinedValidator {
+                address: validators[1].address,
+                name: "validator-10".to_owned(),
+                deactivation_epoch: 5,
+                activation_epoch: Some(8),
+            }]
+        );
+    }
+}
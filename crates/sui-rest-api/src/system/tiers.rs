@@ -0,0 +1,225 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{reader::StateReader, Result};
+use axum::{extract::State, Json};
+use sui_sdk2::types::Address;
+
+use super::SystemStateSummary;
+
+pub const GET_VALIDATOR_TIERS_PATH: &str = "/system/validators/tiers";
+
+pub async fn get_validator_tiers(State(state): State<StateReader>) -> Result<Json<ValidatorTiers>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(ValidatorTiers::new(&summary)))
+}
+
+/// The active validators partitioned by their stake relative to the system's stake thresholds.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ValidatorTiers {
+    /// Validators with at least `validator_low_stake_threshold` stake.
+    pub healthy: StakeTier,
+    /// Validators with less than `validator_low_stake_threshold` but at least
+    /// `validator_very_low_stake_threshold` stake. These validators will be removed if they remain
+    /// in this tier for longer than the grace period.
+    pub low: StakeTier,
+    /// Validators with less than `validator_very_low_stake_threshold` stake, which will be removed
+    /// at the end of the epoch.
+    pub very_low: StakeTier,
+    /// Minimum stake required for a new validator to join the validator set, for reference.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub min_validator_joining_stake: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub validator_low_stake_threshold: u64,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub validator_very_low_stake_threshold: u64,
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct StakeTier {
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub count: u64,
+    pub validators: Vec<Address>,
+}
+
+impl StakeTier {
+    fn push(&mut self, address: Address) {
+        self.count += 1;
+        self.validators.push(address);
+    }
+}
+
+impl ValidatorTiers {
+    pub fn new(summary: &SystemStateSummary) -> Self {
+        let mut healthy = StakeTier::default();
+        let mut low = StakeTier::default();
+        let mut very_low = StakeTier::default();
+
+        for validator in &summary.active_validators {
+            match StakeTierKind::new(validator.staking_pool_sui_balance, summary) {
+                StakeTierKind::Healthy => healthy.push(validator.address),
+                StakeTierKind::Low => low.push(validator.address),
+                StakeTierKind::VeryLow => very_low.push(validator.address),
+            }
+        }
+
+        Self {
+            healthy,
+            low,
+            very_low,
+            min_validator_joining_stake: summary.min_validator_joining_stake,
+            validator_low_stake_threshold: summary.validator_low_stake_threshold,
+            validator_very_low_stake_threshold: summary.validator_very_low_stake_threshold,
+        }
+    }
+}
+
+pub const GET_VALIDATOR_ELIGIBILITY_PATH: &str = "/system/validators/eligibility";
+
+pub async fn get_validator_eligibility(
+    State(state): State<StateReader>,
+) -> Result<Json<Vec<ValidatorEligibility>>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(validator_eligibility(&summary)))
+}
+
+/// Whether an active validator keeps enough stake to remain in the validator set once the epoch
+/// ends, based on its stake for the next epoch.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ValidatorEligibility {
+    pub address: Address,
+    pub name: String,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub next_epoch_stake: u64,
+    pub tier: StakeTierKind,
+    /// Whether `next_epoch_stake` is at least `validator_very_low_stake_threshold`, i.e. the
+    /// validator won't be removed by the next epoch transition. Validators in the `low` tier
+    /// survive, but are removed if they stay there for longer than the grace period.
+    pub meets_next_epoch_minimum: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StakeTierKind {
+    Healthy,
+    Low,
+    VeryLow,
+}
+
+impl StakeTierKind {
+    fn new(stake: u64, summary: &SystemStateSummary) -> Self {
+        if stake >= summary.validator_low_stake_threshold {
+            Self::Healthy
+        } else if stake >= summary.validator_very_low_stake_threshold {
+            Self::Low
+        } else {
+            Self::VeryLow
+        }
+    }
+}
+
+pub(crate) fn validator_eligibility(summary: &SystemStateSummary) -> Vec<ValidatorEligibility> {
+    summary
+        .active_validators
+        .iter()
+        .map(|validator| {
+            let tier = StakeTierKind::new(validator.next_epoch_stake, summary);
+
+            ValidatorEligibility {
+                address: validator.address,
+                name: validator.name.clone(),
+                next_epoch_stake: validator.next_epoch_stake,
+                tier,
+                meets_next_epoch_minimum: tier != StakeTierKind::VeryLow,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::{test_summary, validators_with};
+
+    #[test]
+    fn tier_boundaries() {
+        let summary = test_summary(vec![]);
+        let low_threshold = summary.validator_low_stake_threshold;
+        let very_low_threshold = summary.validator_very_low_stake_threshold;
+
+        let stakes = [
+            summary.min_validator_joining_stake,
+            low_threshold,
+            low_threshold - 1,
+            very_low_threshold,
+            very_low_threshold - 1,
+            0,
+        ];
+        let validators = validators_with(
+            |validator, stake| validator.staking_pool_sui_balance = stake,
+            stakes,
+        );
+        let addresses = validators
+            .iter()
+            .map(|validator| validator.address)
+            .collect::<Vec<_>>();
+
+        let tiers = ValidatorTiers::new(&test_summary(validators));
+        assert_eq!(tiers.healthy.count, 2);
+        assert_eq!(tiers.healthy.validators, addresses[0..2]);
+        assert_eq!(tiers.low.count, 2);
+        assert_eq!(tiers.low.validators, addresses[2..4]);
+        assert_eq!(tiers.very_low.count, 2);
+        assert_eq!(tiers.very_low.validators, addresses[4..6]);
+
+        let tiers = ValidatorTiers::new(&test_summary(vec![]));
+        assert_eq!(tiers.healthy, StakeTier::default());
+        assert_eq!(tiers.very_low.count, 0);
+    }
+
+    #[test]
+    fn next_epoch_eligibility() {
+        let summary = test_summary(vec![]);
+        let low_threshold = summary.validator_low_stake_threshold;
+        let very_low_threshold = summary.validator_very_low_stake_threshold;
+
+        let stakes = [
+            low_threshold,
+            low_threshold - 1,
+            very_low_threshold,
+            very_low_threshold - 1,
+        ];
+        let validators = validators_with(
+            |validator, next_epoch_stake| {
+                // Only the stake for the next epoch is taken into account
+                validator.staking_pool_sui_balance = low_threshold;
+                validator.next_epoch_stake = next_epoch_stake;
+            },
+            stakes,
+        );
+
+        let eligibility = validator_eligibility(&test_summary(validators));
+        assert_eq!(
+            eligibility
+                .iter()
+                .map(|validator| (validator.tier, validator.meets_next_epoch_minimum))
+                .collect::<Vec<_>>(),
+            vec![
+                (StakeTierKind::Healthy, true),
+                (StakeTierKind::Low, true),
+                (StakeTierKind::Low, true),
+                (StakeTierKind::VeryLow, false),
+            ]
+        );
+        assert_eq!(eligibility[3].next_epoch_stake, very_low_threshold - 1);
+    }
+}
@@ -0,0 +1,38 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use axum::http::{header::HeaderName, HeaderValue};
+
+/// `Server-Timing` header, defined in the [Server Timing](https://www.w3.org/TR/server-timing/)
+/// specification.
+pub(crate) const SERVER_TIMING: HeaderName = HeaderName::from_static("server-timing");
+
+/// `Server-Timing` header value breaking the time spent on a request out into named phases, with
+/// durations in fractional milliseconds.
+pub(crate) fn server_timing(phases: &[(&str, Duration)]) -> HeaderValue {
+    let value = phases
+        .iter()
+        .map(|(name, duration)| format!("{name};dur={:.3}", duration.as_secs_f64() * 1000.0))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    HeaderValue::from_str(&value).expect("server timing metrics are valid header values")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn server_timing_metrics() {
+        let value = server_timing(&[
+            ("state_read", Duration::from_micros(1500)),
+            ("serialize", Duration::from_millis(12)),
+        ]);
+        assert_eq!(value, "state_read;dur=1.500, serialize;dur=12.000");
+
+        assert_eq!(server_timing(&[]), "");
+    }
+}
@@ -0,0 +1,218 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{reader::StateReader, Result};
+use axum::{extract::State, Json};
+use sui_types::gas_coin::MIST_PER_SUI;
+
+use super::{SystemStateSummary, ValidatorSummary};
+
+pub const GET_TOTAL_STAKE_PATH: &str = "/system/total-stake";
+
+pub async fn get_total_stake(State(state): State<StateReader>) -> Result<Json<TotalStake>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(TotalStake::new(summary.total_stake)))
+}
+
+/// Total amount of stake in the active validator set.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TotalStake {
+    /// Total stake in MIST.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub mist: u64,
+    /// Total stake in SUI, as an exact decimal string, e.g. `"1.5"`.
+    pub sui: String,
+}
+
+impl TotalStake {
+    pub fn new(mist: u64) -> Self {
+        Self {
+            mist,
+            sui: mist_to_sui(mist),
+        }
+    }
+}
+
+pub const GET_PENDING_STAKE_PATH: &str = "/system/pending-stake";
+
+pub async fn get_pending_stake(State(state): State<StateReader>) -> Result<Json<PendingStake>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(PendingStake::new(&summary.active_validators)))
+}
+
+/// Stake flowing into and out of the active validators' staking pools at the end of the epoch.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PendingStake {
+    /// Total stake pending to join the staking pools, in MIST.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub total_pending_stake: u64,
+    /// Total stake pending to be withdrawn from the staking pools, in MIST.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub total_pending_withdraw: u64,
+}
+
+impl PendingStake {
+    pub fn new(validators: &[ValidatorSummary]) -> Self {
+        validators.iter().fold(
+            Self {
+                total_pending_stake: 0,
+                total_pending_withdraw: 0,
+            },
+            |total, validator| Self {
+                total_pending_stake: total
+                    .total_pending_stake
+                    .saturating_add(validator.pending_stake),
+                total_pending_withdraw: total
+                    .total_pending_withdraw
+                    .saturating_add(validator.pending_total_sui_withdraw),
+            },
+        )
+    }
+}
+
+pub const GET_STAKE_RECONCILIATION_PATH: &str = "/system/stake-reconciliation";
+
+/// Compare the total stake reported by the system state against the balances of the active
+/// validators' staking pools, for consistency auditing.
+pub async fn get_stake_reconciliation(
+    State(state): State<StateReader>,
+) -> Result<Json<StakeReconciliation>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(StakeReconciliation::new(&summary)))
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct StakeReconciliation {
+    /// The `total_stake` of the system state, in MIST.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub reported_total_stake: u64,
+    /// Sum of the SUI balances of the active validators' staking pools, in MIST.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub sum_of_pool_balances: u64,
+    /// `reported_total_stake - sum_of_pool_balances`, which should normally be zero or small.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<i128>")]
+    pub difference: i128,
+}
+
+impl StakeReconciliation {
+    pub fn new(summary: &SystemStateSummary) -> Self {
+        let sum_of_pool_balances = summary
+            .active_validators
+            .iter()
+            .fold(0u64, |sum, validator| {
+                sum.saturating_add(validator.staking_pool_sui_balance)
+            });
+
+        Self {
+            reported_total_stake: summary.total_stake,
+            sum_of_pool_balances,
+            difference: i128::from(summary.total_stake) - i128::from(sum_of_pool_balances),
+        }
+    }
+}
+
+/// Format an amount of MIST as a decimal amount of SUI.
+///
+/// As a SUI is exactly 10^9 MIST the result is exact, with trailing zeros of the fractional part
+/// omitted.
+pub(crate) fn mist_to_sui(mist: u64) -> String {
+    let whole = mist / MIST_PER_SUI;
+    let fraction = mist % MIST_PER_SUI;
+
+    if fraction == 0 {
+        whole.to_string()
+    } else {
+        let fraction = format!("{fraction:09}");
+        format!("{whole}.{}", fraction.trim_end_matches('0'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::{test_summary, validators_with};
+
+    #[test]
+    fn mist_to_sui_conversion() {
+        assert_eq!(mist_to_sui(0), "0");
+        assert_eq!(mist_to_sui(MIST_PER_SUI), "1");
+        assert_eq!(mist_to_sui(1), "0.000000001");
+        assert_eq!(mist_to_sui(1_500_000_000), "1.5");
+        assert_eq!(
+            mist_to_sui(8_123_456_789_012_345_678),
+            "8123456789.012345678"
+        );
+        assert_eq!(mist_to_sui(u64::MAX), "18446744073.709551615");
+
+        let stake = TotalStake::new(2_050_000_000);
+        assert_eq!(stake.sui, "2.05");
+        let json = serde_json::to_value(&stake).unwrap();
+        assert_eq!(json["mist"], serde_json::Value::from("2050000000"));
+    }
+
+    #[test]
+    fn pending_stake_totals() {
+        let validators = validators_with(
+            |validator, (pending_stake, pending_total_sui_withdraw)| {
+                validator.pending_stake = pending_stake;
+                validator.pending_total_sui_withdraw = pending_total_sui_withdraw;
+            },
+            [(100, 0), (2_000, 300), (0, 4_000)],
+        );
+
+        assert_eq!(
+            PendingStake::new(&validators),
+            PendingStake {
+                total_pending_stake: 2_100,
+                total_pending_withdraw: 4_300,
+            }
+        );
+        assert_eq!(
+            PendingStake::new(&[]),
+            PendingStake {
+                total_pending_stake: 0,
+                total_pending_withdraw: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn stake_discrepancy() {
+        let validators = validators_with(
+            |validator, balance| validator.staking_pool_sui_balance = balance,
+            [1_000, 2_000, 3_000],
+        );
+        let mut summary = test_summary(validators);
+
+        summary.total_stake = 6_000;
+        assert_eq!(StakeReconciliation::new(&summary).difference, 0);
+
+        summary.total_stake = 5_750;
+        let reconciliation = StakeReconciliation::new(&summary);
+        assert_eq!(
+            reconciliation,
+            StakeReconciliation {
+                reported_total_stake: 5_750,
+                sum_of_pool_balances: 6_000,
+                difference: -250,
+            }
+        );
+        let json = serde_json::to_value(&reconciliation).unwrap();
+        assert_eq!(json["difference"], "-250");
+
+        summary.total_stake = 6_100;
+        assert_eq!(StakeReconciliation::new(&summary).difference, 100);
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::{
+    http::{uri::PathAndQuery, Request, Uri},
+    Router,
+};
+
+/// Make the system routes of `router` tolerant of trailing slashes, so that for example
+/// `/system/` resolves to the same handler as `/system`.
+///
+/// Middleware added with [`Router::layer`] only runs once a request has been routed, so the path
+/// is normalized by an outer router which forwards everything to `router`.
+pub(crate) fn tolerate_trailing_slash(router: Router) -> Router {
+    Router::new()
+        .fallback_service(router)
+        .layer(axum::middleware::map_request(trim_trailing_slash))
+}
+
+async fn trim_trailing_slash<B>(mut request: Request<B>) -> Request<B> {
+    let path = request.uri().path();
+    if !(path.starts_with("/system/") && path.ends_with('/')) {
+        return request;
+    }
+
+    let path = path.trim_end_matches('/');
+    let path_and_query = match request.uri().query() {
+        Some(query) => format!("{path}?{query}"),
+        None => path.to_owned(),
+    };
+
+    let mut parts = request.uri().clone().into_parts();
+    parts.path_and_query = path_and_query.parse::<PathAndQuery>().ok();
+    if let Ok(uri) = Uri::from_parts(parts) {
+        *request.uri_mut() = uri;
+    }
+
+    request
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, extract::RawQuery, http::StatusCode, routing::get};
+    use tower::ServiceExt;
+
+    fn app() -> Router {
+        let router = Router::new()
+            .route(
+                crate::system::GET_SYSTEM_STATE_SUMMARY_PATH,
+                get(|RawQuery(query): RawQuery| async move { format!("system {query:?}") }),
+            )
+            .route(crate::system::GET_EPOCH_PATH, get(|| async { "epoch" }));
+
+        tolerate_trailing_slash(router)
+    }
+
+    async fn get_body(uri: &str) -> (StatusCode, String) {
+        let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+        let response = app().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn trailing_slash() {
+        let system = get_body("/system").await;
+        assert_eq!(system, (StatusCode::OK, "system None".to_owned()));
+        assert_eq!(get_body("/system/").await, system);
+
+        assert_eq!(
+            get_body("/system/epoch/").await,
+            get_body("/system/epoch").await
+        );
+
+        // The query string is preserved
+        assert_eq!(
+            get_body("/system/?naming=camel").await,
+            get_body("/system?naming=camel").await
+        );
+        assert_eq!(
+            get_body("/system/?naming=camel").await.1,
+            "system Some(\"naming=camel\")"
+        );
+
+        // Paths outside of the system routes are left alone
+        assert_eq!(get_body("/other/").await.0, StatusCode::NOT_FOUND);
+    }
+}
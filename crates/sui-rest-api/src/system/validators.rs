@@ -0,0 +1,845 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use crate::{
+    accept::{Accept, APPLICATION_NDJSON},
+    reader::StateReader,
+    RestError, Result, APPLICATION_JSON,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sui_sdk2::types::{Address, ObjectId};
+
+use super::{stream, SystemStateSummary, ValidatorSummary};
+
+pub const GET_VALIDATORS_PATH: &str = "/system/validators";
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct ValidatorsQueryParameters {
+    /// Only include validators with at least this much voting power.
+    pub min_voting_power: Option<u64>,
+    /// Only include validators with at most this much voting power.
+    pub max_voting_power: Option<u64>,
+    /// Only include validators with a commission rate of at most this many basis points.
+    pub max_commission_bps: Option<u64>,
+    /// Only include validators with (`true`) or without (`false`) an image URL. URLs consisting
+    /// only of whitespace count as missing.
+    pub has_image: Option<bool>,
+}
+
+impl ValidatorsQueryParameters {
+    /// Select the validators matching every filter, keeping their order.
+    fn filter(&self, validators: Vec<ValidatorSummary>) -> Result<Vec<ValidatorSummary>> {
+        let min_voting_power = self.min_voting_power.unwrap_or(u64::MIN);
+        let max_voting_power = self.max_voting_power.unwrap_or(u64::MAX);
+        if min_voting_power > max_voting_power {
+            return Err(RestError::new(
+                StatusCode::BAD_REQUEST,
+                format!(
+                    "min_voting_power {min_voting_power} is greater than max_voting_power \
+                     {max_voting_power}"
+                ),
+            ));
+        }
+
+        let max_commission_bps = self.max_commission_bps.unwrap_or(u64::MAX);
+
+        Ok(validators
+            .into_iter()
+            .filter(|validator| {
+                (min_voting_power..=max_voting_power).contains(&validator.voting_power)
+                    && validator.commission_rate <= max_commission_bps
+                    && self.has_image.map_or(true, |has_image| {
+                        !validator.image_url.trim().is_empty() == has_image
+                    })
+            })
+            .collect())
+    }
+}
+
+/// List the active validators.
+///
+/// Requested with `Accept: application/x-ndjson` the validators are streamed as newline-delimited
+/// JSON, one validator per line, rather than as a single JSON array.
+pub async fn get_validators(
+    accept: Accept,
+    Query(parameters): Query<ValidatorsQueryParameters>,
+    State(state): State<StateReader>,
+) -> Result<Response> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+    let validators = parameters.filter(summary.active_validators)?;
+
+    let ndjson = accept
+        .0
+        .iter()
+        .find_map(|mime| match mime.essence_str() {
+            APPLICATION_NDJSON => Some(true),
+            APPLICATION_JSON => Some(false),
+            _ => None,
+        })
+        .unwrap_or(false);
+
+    let response = if ndjson {
+        stream::ndjson_response(validators)
+    } else {
+        Json(validators).into_response()
+    };
+
+    Ok(response)
+}
+
+pub const GET_VALIDATOR_PATH: &str = "/system/validators/:address";
+
+pub async fn get_validator(
+    Path(address): Path<Address>,
+    State(state): State<StateReader>,
+) -> Result<Json<ValidatorWithRisk>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+    let validator = find_validator(&summary, address)?.clone();
+
+    Ok(Json(ValidatorWithRisk::new(&summary, validator)))
+}
+
+/// A validator along with whether it's at risk of being removed from the active set, so that a
+/// single fetch reveals its risk status.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ValidatorWithRisk {
+    #[serde(flatten)]
+    pub validator: ValidatorSummary,
+    /// Number of epochs for which the validator has been below the low stake threshold, `None` if
+    /// it isn't at risk.
+    #[serde_as(as = "Option<sui_types::sui_serde::BigInt<u64>>")]
+    pub at_risk_epochs: Option<u64>,
+}
+
+impl ValidatorWithRisk {
+    pub fn new(summary: &SystemStateSummary, validator: ValidatorSummary) -> Self {
+        let at_risk_epochs = summary
+            .at_risk_validators
+            .iter()
+            .find(|(address, _)| *address == validator.address)
+            .map(|(_, epochs)| *epochs);
+
+        Self {
+            validator,
+            at_risk_epochs,
+        }
+    }
+}
+
+pub const GET_VALIDATOR_STAKING_POOL_PATH: &str = "/system/validators/:address/staking-pool";
+
+pub async fn get_validator_staking_pool(
+    Path(address): Path<Address>,
+    State(state): State<StateReader>,
+) -> Result<Json<StakingPoolSummary>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+    let validator = find_validator(&summary, address)?;
+
+    Ok(Json(validator.into()))
+}
+
+pub const VERIFY_VALIDATOR_PROOF_OF_POSSESSION_PATH: &str =
+    "/system/validators/:address/verify-pop";
+
+pub async fn verify_validator_proof_of_possession(
+    Path(address): Path<Address>,
+    State(state): State<StateReader>,
+) -> Result<Json<ProofOfPossessionVerification>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+    let validator = find_validator(&summary, address)?;
+    let valid = verify_proof_of_possession(validator)?;
+
+    Ok(Json(ProofOfPossessionVerification { valid }))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ProofOfPossessionVerification {
+    /// Whether the validator's proof of possession is valid for its protocol public key and
+    /// address.
+    pub valid: bool,
+}
+
+/// Check the BLS proof of possession of `validator` against its protocol public key.
+///
+/// Fails if either the public key or the proof itself can't be parsed.
+fn verify_proof_of_possession(validator: &ValidatorSummary) -> Result<bool> {
+    use fastcrypto::traits::ToFromBytes;
+    use sui_types::crypto::{AuthorityPublicKey, AuthoritySignature};
+
+    let public_key = AuthorityPublicKey::from_bytes(validator.protocol_public_key.inner())
+        .map_err(|e| {
+            RestError::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("unable to parse protocol public key: {e}"),
+            )
+        })?;
+    let proof_of_possession = AuthoritySignature::from_bytes(&validator.proof_of_possession_bytes)
+        .map_err(|e| {
+            RestError::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("unable to parse proof of possession: {e}"),
+            )
+        })?;
+
+    Ok(sui_types::crypto::verify_proof_of_possession(
+        &proof_of_possession,
+        &public_key,
+        validator.address.into(),
+    )
+    .is_ok())
+}
+
+/// Look up an active validator by its address.
+pub(crate) fn find_validator(
+    summary: &SystemStateSummary,
+    address: Address,
+) -> Result<&ValidatorSummary, ValidatorNotFoundError> {
+    summary
+        .active_validators
+        .iter()
+        .find(|validator| validator.address == address)
+        .ok_or(ValidatorNotFoundError::new(address))
+}
+
+/// The staking pool of a validator.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct StakingPoolSummary {
+    /// ID of the staking pool object.
+    pub staking_pool_id: ObjectId,
+    /// The epoch at which this pool became active.
+    #[serde_as(as = "Option<sui_types::sui_serde::BigInt<u64>>")]
+    pub staking_pool_activation_epoch: Option<u64>,
+    /// The epoch at which this staking pool ceased to be active. `None` = {pre-active, active},
+    #[serde_as(as = "Option<sui_types::sui_serde::BigInt<u64>>")]
+    pub staking_pool_deactivation_epoch: Option<u64>,
+    /// The total number of SUI tokens in this pool.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub staking_pool_sui_balance: u64,
+    /// The epoch stake rewards will be added here at the end of each epoch.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub rewards_pool: u64,
+    /// Total number of pool tokens issued by the pool.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub pool_token_balance: u64,
+    /// Pending stake amount for this epoch.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub pending_stake: u64,
+    /// Pending stake withdrawn during the current epoch, emptied at epoch boundaries.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub pending_total_sui_withdraw: u64,
+    /// Pending pool token withdrawn during the current epoch, emptied at epoch boundaries.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub pending_pool_token_withdraw: u64,
+    /// ID of the exchange rate table object.
+    pub exchange_rates_id: ObjectId,
+    /// Number of exchange rates in the table.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub exchange_rates_size: u64,
+}
+
+impl From<&ValidatorSummary> for StakingPoolSummary {
+    fn from(value: &ValidatorSummary) -> Self {
+        Self {
+            staking_pool_id: value.staking_pool_id,
+            staking_pool_activation_epoch: value.staking_pool_activation_epoch,
+            staking_pool_deactivation_epoch: value.staking_pool_deactivation_epoch,
+            staking_pool_sui_balance: value.staking_pool_sui_balance,
+            rewards_pool: value.rewards_pool,
+            pool_token_balance: value.pool_token_balance,
+            pending_stake: value.pending_stake,
+            pending_total_sui_withdraw: value.pending_total_sui_withdraw,
+            pending_pool_token_withdraw: value.pending_pool_token_withdraw,
+            exchange_rates_id: value.exchange_rates_id,
+            exchange_rates_size: value.exchange_rates_size,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ValidatorNotFoundError {
+    address: Address,
+}
+
+impl ValidatorNotFoundError {
+    pub fn new(address: Address) -> Self {
+        Self { address }
+    }
+}
+
+impl std::fmt::Display for ValidatorNotFoundError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Validator {} not found", self.address)
+    }
+}
+
+impl std::error::Error for ValidatorNotFoundError {}
+
+impl From<ValidatorNotFoundError> for crate::RestError {
+    fn from(value: ValidatorNotFoundError) -> Self {
+        Self::new(axum::http::StatusCode::NOT_FOUND, value.to_string())
+    }
+}
+
+pub const GET_PENDING_VALIDATORS_PATH: &str = "/system/validators/pending";
+
+pub async fn get_pending_validators(
+    State(state): State<StateReader>,
+) -> Result<Json<PendingValidators>> {
+    state
+        .spawn_read(|state| {
+            let summary = state.get_system_state_summary()?;
+            let pending = state.get_pending_active_validators();
+
+            Ok(Json(PendingValidators::new(
+                summary.pending_active_validators_size,
+                pending,
+            )))
+        })
+        .await
+}
+
+/// The validators which will join the active validator set at the end of the current epoch.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PendingValidators {
+    /// Number of new validators that will join at the end of the epoch.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub pending_active_validators_size: u64,
+    /// The joining validators, `None` if the pending validator table couldn't be read.
+    pub validators: Option<Vec<PendingValidator>>,
+    /// Explanation of why `validators` is missing, if it is.
+    pub note: Option<String>,
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PendingValidator {
+    pub address: Address,
+    pub name: String,
+    /// The stake the validator will start the next epoch with.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub next_epoch_stake: u64,
+}
+
+impl PendingValidators {
+    pub fn new<E: std::fmt::Display>(
+        pending_active_validators_size: u64,
+        validators: Result<Vec<ValidatorSummary>, E>,
+    ) -> Self {
+        match validators {
+            Ok(validators) => Self {
+                pending_active_validators_size,
+                validators: Some(
+                    validators
+                        .into_iter()
+                        .map(|validator| PendingValidator {
+                            address: validator.address,
+                            name: validator.name,
+                            next_epoch_stake: validator.next_epoch_stake,
+                        })
+                        .collect(),
+                ),
+                note: None,
+            },
+            Err(e) => Self {
+                pending_active_validators_size,
+                validators: None,
+                note: Some(format!("unable to read pending validator table: {e}")),
+            },
+        }
+    }
+}
+
+pub const GET_PENDING_REMOVALS_PATH: &str = "/system/pending-removals";
+
+/// Resolve the validators which will leave the active validator set at the end of the current
+/// epoch.
+pub async fn get_pending_removals(
+    State(state): State<StateReader>,
+) -> Result<Json<Vec<PendingRemoval>>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    pending_removals(&summary).map(Json)
+}
+
+/// A validator which will leave the active validator set at the end of the epoch.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PendingRemoval {
+    /// Index of the validator in the active validator set, as listed in `pending_removals`.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    pub index: u64,
+    pub address: Address,
+    pub name: String,
+}
+
+/// Resolve the `pending_removals` indices of `summary` into the active validators they refer to.
+pub(crate) fn pending_removals(summary: &SystemStateSummary) -> Result<Vec<PendingRemoval>> {
+    summary
+        .pending_removals
+        .iter()
+        .map(|index| {
+            let validator = usize::try_from(*index)
+                .ok()
+                .and_then(|i| summary.active_validators.get(i))
+                .ok_or_else(|| {
+                    RestError::new(
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        format!(
+                            "pending removal index {index} is out of range of the {} active \
+                             validators",
+                            summary.active_validators.len()
+                        ),
+                    )
+                })?;
+
+            Ok(PendingRemoval {
+                index: *index,
+                address: validator.address,
+                name: validator.name.clone(),
+            })
+        })
+        .collect()
+}
+
+pub const GET_VALIDATOR_COMPARISON_PATH: &str = "/system/validators/compare";
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct ValidatorComparisonQueryParameters {
+    pub a: Address,
+    pub b: Address,
+}
+
+/// Compare two active validators side by side.
+pub async fn get_validator_comparison(
+    Query(parameters): Query<ValidatorComparisonQueryParameters>,
+    State(state): State<StateReader>,
+) -> Result<Json<ValidatorComparison>> {
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+    let a = find_validator(&summary, parameters.a)?;
+    let b = find_validator(&summary, parameters.b)?;
+
+    Ok(Json(ValidatorComparison::new(a.clone(), b.clone())))
+}
+
+/// Two validators along with how the first, `a`, compares to the second, `b`.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ValidatorComparison {
+    pub a: ValidatorSummary,
+    pub b: ValidatorSummary,
+    pub commission_rate: Comparison,
+    pub gas_price: Comparison,
+    /// Compares the SUI balance of the validators' staking pools.
+    pub stake: Comparison,
+    pub voting_power: Comparison,
+}
+
+/// How a value of one validator compares to that of another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Comparison {
+    Higher,
+    Lower,
+    Equal,
+}
+
+impl Comparison {
+    fn new(a: u64, b: u64) -> Self {
+        match a.cmp(&b) {
+            std::cmp::Ordering::Greater => Self::Higher,
+            std::cmp::Ordering::Less => Self::Lower,
+            std::cmp::Ordering::Equal => Self::Equal,
+        }
+    }
+}
+
+impl ValidatorComparison {
+    pub fn new(a: ValidatorSummary, b: ValidatorSummary) -> Self {
+        Self {
+            commission_rate: Comparison::new(a.commission_rate, b.commission_rate),
+            gas_price: Comparison::new(a.gas_price, b.gas_price),
+            stake: Comparison::new(a.staking_pool_sui_balance, b.staking_pool_sui_balance),
+            voting_power: Comparison::new(a.voting_power, b.voting_power),
+            a,
+            b,
+        }
+    }
+}
+
+pub const POST_VALIDATORS_BATCH_PATH: &str = "/system/validators/batch";
+
+/// Maximum number of addresses which can be looked up in a single batch request.
+const MAX_VALIDATORS_BATCH_SIZE: usize = 50;
+
+/// Look up multiple active validators at once.
+///
+/// Addresses which don't belong to an active validator map to `null`.
+pub async fn get_validators_batch(
+    State(state): State<StateReader>,
+    Json(addresses): Json<Vec<Address>>,
+) -> Result<Json<BTreeMap<Address, Option<ValidatorSummary>>>> {
+    if addresses.len() > MAX_VALIDATORS_BATCH_SIZE {
+        return Err(RestError::new(
+            StatusCode::BAD_REQUEST,
+            format!(
+                "batch of {} addresses exceeds the maximum of {MAX_VALIDATORS_BATCH_SIZE}",
+                addresses.len()
+            ),
+        ));
+    }
+
+    let summary = state
+        .spawn_read(StateReader::get_system_state_summary)
+        .await?;
+
+    Ok(Json(validators_batch(&summary, &addresses)))
+}
+
+pub(crate) fn validators_batch(
+    summary: &SystemStateSummary,
+    addresses: &[Address],
+) -> BTreeMap<Address, Option<ValidatorSummary>> {
+    addresses
+        .iter()
+        .map(|address| {
+            let validator = find_validator(summary, *address).ok().cloned();
+            (*address, validator)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::{test_summary, test_validator, validators_with};
+
+    #[test]
+    fn at_risk_annotation() {
+        let mut summary = test_summary(vec![test_validator(1), test_validator(10)]);
+        summary.at_risk_validators = vec![(summary.active_validators[1].address, 3)];
+
+        let healthy = ValidatorWithRisk::new(&summary, summary.active_validators[0].clone());
+        assert_eq!(healthy.at_risk_epochs, None);
+
+        let at_risk = ValidatorWithRisk::new(&summary, summary.active_validators[1].clone());
+        assert_eq!(at_risk.at_risk_epochs, Some(3));
+
+        // The validator's own fields are kept at the top level
+        let json = serde_json::to_value(&at_risk).unwrap();
+        assert_eq!(json["at_risk_epochs"], "3");
+        assert_eq!(json["name"], "validator-10");
+        assert!(serde_json::to_value(&healthy).unwrap()["at_risk_epochs"].is_null());
+    }
+
+    #[test]
+    fn proof_of_possession() {
+        use fastcrypto::traits::{KeyPair, ToFromBytes};
+        use sui_types::crypto::generate_proof_of_possession;
+
+        let keypair = sui_types::crypto::random_committee_key_pairs_of_size(1)
+            .pop()
+            .unwrap();
+        let mut validator = test_validator(1);
+        validator.protocol_public_key =
+            sui_sdk2::types::Bls12381PublicKey::from_bytes(keypair.public().as_bytes()).unwrap();
+
+        validator.proof_of_possession_bytes =
+            generate_proof_of_possession(&keypair, validator.address.into())
+                .as_bytes()
+                .to_vec();
+        assert!(verify_proof_of_possession(&validator).unwrap());
+
+        // A well-formed proof which was generated for a different address
+        validator.proof_of_possession_bytes =
+            generate_proof_of_possession(&keypair, Address::new([2; 32]).into())
+                .as_bytes()
+                .to_vec();
+        assert!(!verify_proof_of_possession(&validator).unwrap());
+
+        validator.proof_of_possession_bytes = vec![0; 3];
+        assert!(verify_proof_of_possession(&validator).is_err());
+    }
+
+    #[test]
+    fn empty_validator_set() {
+        use axum::response::IntoResponse;
+
+        let summary = test_summary(vec![]);
+        let error = find_validator(&summary, Address::new([1; 32])).unwrap_err();
+        assert_eq!(
+            crate::RestError::from(error).into_response().status(),
+            axum::http::StatusCode::NOT_FOUND
+        );
+
+        let pending = PendingValidators::new(0, Ok::<_, String>(vec![]));
+        assert_eq!(pending.validators, Some(vec![]));
+        assert_eq!(pending.note, None);
+    }
+
+    #[test]
+    fn batch_lookup() {
+        let summary = test_summary(vec![test_validator(1), test_validator(2)]);
+        let unknown = Address::new([9; 32]);
+        let addresses = [Address::new([2; 32]), unknown, Address::new([1; 32])];
+
+        let batch = validators_batch(&summary, &addresses);
+        assert_eq!(batch.len(), 3);
+        assert_eq!(batch[&addresses[0]].as_ref().unwrap().name, "validator-2");
+        assert_eq!(batch[&addresses[2]].as_ref().unwrap().name, "validator-1");
+        assert!(batch[&unknown].is_none());
+
+        let json = serde_json::to_value(&batch).unwrap();
+        assert!(json[unknown.to_string()].is_null());
+    }
+
+    #[test]
+    fn staking_pool() {
+        let mut validator = test_validator(3);
+        validator.staking_pool_sui_balance = 1_000;
+        validator.rewards_pool = 10;
+        validator.pool_token_balance = 990;
+        validator.pending_stake = 5;
+        validator.exchange_rates_size = 4;
+        let summary = test_summary(vec![test_validator(1), validator]);
+
+        let validator = find_validator(&summary, Address::new([3; 32])).unwrap();
+        let pool = StakingPoolSummary::from(validator);
+        assert_eq!(pool.staking_pool_id, validator.staking_pool_id);
+        assert_eq!(
+            pool.staking_pool_activation_epoch,
+            validator.staking_pool_activation_epoch
+        );
+        assert_eq!(
+            pool.staking_pool_deactivation_epoch,
+            validator.staking_pool_deactivation_epoch
+        );
+        assert_eq!(pool.staking_pool_sui_balance, 1_000);
+        assert_eq!(pool.rewards_pool, 10);
+        assert_eq!(pool.pool_token_balance, 990);
+        assert_eq!(pool.pending_stake, 5);
+        assert_eq!(
+            pool.pending_total_sui_withdraw,
+            validator.pending_total_sui_withdraw
+        );
+        assert_eq!(
+            pool.pending_pool_token_withdraw,
+            validator.pending_pool_token_withdraw
+        );
+        assert_eq!(pool.exchange_rates_id, validator.exchange_rates_id);
+        assert_eq!(pool.exchange_rates_size, 4);
+
+        assert!(find_validator(&summary, Address::new([9; 32])).is_err());
+    }
+
+    #[test]
+    fn pending_validators() {
+        let mut validator = test_validator(7);
+        validator.next_epoch_stake = 30_000;
+
+        let pending = PendingValidators::new::<String>(1, Ok(vec![validator.clone()]));
+        assert_eq!(pending.pending_active_validators_size, 1);
+        assert_eq!(
+            pending.validators,
+            Some(vec![PendingValidator {
+                address: validator.address,
+                name: validator.name,
+                next_epoch_stake: 30_000,
+            }])
+        );
+        assert!(pending.note.is_none());
+
+        let pending = PendingValidators::new(2, Err("table missing"));
+        assert_eq!(pending.pending_active_validators_size, 2);
+        assert!(pending.validators.is_none());
+        assert!(pending.note.unwrap().contains("table missing"));
+    }
+
+    #[tokio::test]
+    async fn pending_removal_indices() {
+        let mut summary = test_summary((0..4).map(|i| test_validator(i * 10)).collect());
+        summary.pending_removals = vec![3, 1];
+
+        let removals = pending_removals(&summary).unwrap();
+        assert_eq!(
+            removals,
+            vec![
+                PendingRemoval {
+                    index: 3,
+                    address: summary.active_validators[3].address,
+                    name: "validator-30".to_owned(),
+                },
+                PendingRemoval {
+                    index: 1,
+                    address: summary.active_validators[1].address,
+                    name: "validator-10".to_owned(),
+                },
+            ]
+        );
+
+        // A corrupt index is reported rather than skipped
+        summary.pending_removals = vec![1, 4];
+        let response = pending_removals(&summary).unwrap_err().into_response();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let problem: crate::ProblemDetails = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            problem.detail.as_deref(),
+            Some("pending removal index 4 is out of range of the 4 active validators")
+        );
+    }
+
+    #[test]
+    fn comparison() {
+        let mut a = test_validator(10);
+        a.commission_rate = 500;
+        a.staking_pool_sui_balance = 1_000;
+        a.voting_power = 100;
+        let mut b = test_validator(20);
+        b.commission_rate = 200;
+        b.staking_pool_sui_balance = 5_000;
+        b.voting_power = 400;
+
+        let comparison = ValidatorComparison::new(a.clone(), b.clone());
+        assert_eq!(comparison.a.address, a.address);
+        assert_eq!(comparison.b.address, b.address);
+        assert_eq!(comparison.commission_rate, Comparison::Higher);
+        assert_eq!(comparison.gas_price, Comparison::Equal);
+        assert_eq!(comparison.stake, Comparison::Lower);
+        assert_eq!(comparison.voting_power, Comparison::Lower);
+
+        let json = serde_json::to_value(ValidatorComparison::new(b, a)).unwrap();
+        assert_eq!(json["commission_rate"], "lower");
+        assert_eq!(json["stake"], "higher");
+
+        // Unknown validators can't be compared
+        let summary = test_summary(vec![test_validator(10)]);
+        assert!(find_validator(&summary, Address::new([20; 32])).is_err());
+    }
+
+    #[test]
+    fn voting_power_range() {
+        let validators = validators_with(
+            |validator, voting_power| validator.voting_power = voting_power,
+            [100, 500, 1000, 2000],
+        );
+        let voting_power = |parameters: ValidatorsQueryParameters| {
+            parameters
+                .filter(validators.clone())
+                .unwrap()
+                .iter()
+                .map(|validator| validator.voting_power)
+                .collect::<Vec<_>>()
+        };
+
+        // Both bounds are inclusive
+        let bounded = ValidatorsQueryParameters {
+            min_voting_power: Some(500),
+            max_voting_power: Some(1000),
+            ..Default::default()
+        };
+        assert_eq!(voting_power(bounded), [500, 1000]);
+
+        let open_lower_bound = ValidatorsQueryParameters {
+            max_voting_power: Some(500),
+            ..Default::default()
+        };
+        assert_eq!(voting_power(open_lower_bound), [100, 500]);
+
+        assert_eq!(
+            voting_power(ValidatorsQueryParameters::default()),
+            [100, 500, 1000, 2000]
+        );
+
+        let invalid = ValidatorsQueryParameters {
+            min_voting_power: Some(1000),
+            max_voting_power: Some(500),
+            ..Default::default()
+        };
+        let response = invalid.filter(validators).unwrap_err().into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn commission_threshold() {
+        let validators = validators_with(
+            |validator, (commission_rate, voting_power)| {
+                validator.commission_rate = commission_rate;
+                validator.voting_power = voting_power;
+            },
+            [(100, 1000), (500, 2000), (501, 3000), (1000, 4000)],
+        );
+        let commission_rates = |parameters: ValidatorsQueryParameters| {
+            parameters
+                .filter(validators.clone())
+                .unwrap()
+                .iter()
+                .map(|validator| validator.commission_rate)
+                .collect::<Vec<_>>()
+        };
+
+        // The threshold itself is included
+        let cheap = ValidatorsQueryParameters {
+            max_commission_bps: Some(500),
+            ..Default::default()
+        };
+        assert_eq!(commission_rates(cheap), [100, 500]);
+
+        // Combined with the voting power filters
+        let cheap_and_large = ValidatorsQueryParameters {
+            min_voting_power: Some(2000),
+            max_commission_bps: Some(500),
+            ..Default::default()
+        };
+        assert_eq!(commission_rates(cheap_and_large), [500]);
+
+        let none = ValidatorsQueryParameters {
+            max_commission_bps: Some(0),
+            ..Default::default()
+        };
+        assert!(commission_rates(none).is_empty());
+    }
+
+    #[test]
+    fn image_url_presence() {
+        let validators = validators_with(
+            |validator, image_url| validator.image_url = image_url.to_owned(),
+            ["https://example.com/logo.png", "", " \t\n"],
+        );
+        let names = |has_image: Option<bool>| {
+            ValidatorsQueryParameters {
+                has_image,
+                ..Default::default()
+            }
+            .filter(validators.clone())
+            .unwrap()
+            .into_iter()
+            .map(|validator| validator.name)
+            .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names(Some(true)), ["validator-0"]);
+        assert_eq!(names(Some(false)), ["validator-10", "validator-20"]);
+        assert_eq!(names(None).len(), 3);
+    }
+}
@@ -0,0 +1,157 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use crate::{reader::StateReader, Result};
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        State,
+    },
+    response::Response,
+};
+
+use super::epoch::{read_system_info, SystemInfo};
+
+pub const GET_SYSTEM_WS_PATH: &str = "/system/ws";
+
+/// How often the system state is checked for an epoch change.
+const EPOCH_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How often the server pings an otherwise idle connection, so that proxies don't drop it.
+const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Upgrade to a websocket which pushes a [`SystemInfo`] text frame on each epoch change, starting
+/// with a snapshot of the current epoch, for clients whose proxies don't support streaming
+/// responses well.
+pub async fn get_system_ws(ws: WebSocketUpgrade, State(state): State<StateReader>) -> Response {
+    ws.on_upgrade(move |socket| {
+        push_system_info(
+            socket,
+            move || read_system_info(&state),
+            EPOCH_POLL_INTERVAL,
+        )
+    })
+}
+
+/// Send the output of `read` over `socket` whenever its epoch changes, until the client
+/// disconnects.
+///
+/// Pings from the client are answered automatically, and the connection is kept alive by pinging
+/// the client every [`KEEPALIVE_INTERVAL`].
+pub(crate) async fn push_system_info<F>(mut socket: WebSocket, mut read: F, poll_interval: Duration)
+where
+    F: FnMut() -> Result<SystemInfo>,
+{
+    let mut last_epoch = None;
+    let mut poll = tokio::time::interval(poll_interval);
+    let mut keepalive = tokio::time::interval(KEEPALIVE_INTERVAL);
+    // The first tick completes immediately, but there's no need to ping a fresh connection
+    keepalive.tick().await;
+
+    loop {
+        tokio::select! {
+            _ = poll.tick() => {
+                let info = match read() {
+                    Ok(info) => info,
+                    Err(e) => {
+                        tracing::debug!("unable to read system info for websocket: {e:?}");
+                        continue;
+                    }
+                };
+                if last_epoch == Some(info.epoch) {
+                    continue;
+                }
+                last_epoch = Some(info.epoch);
+
+                let text = serde_json::to_string(&info).expect("system info is serializable");
+                if socket.send(Message::Text(text)).await.is_err() {
+                    return;
+                }
+            }
+            _ = keepalive.tick() => {
+                if socket.send(Message::Ping(Vec::new())).await.is_err() {
+                    return;
+                }
+            }
+            message = socket.recv() => match message {
+                Some(Ok(Message::Close(_))) => {
+                    // Completes the closing handshake by echoing the close frame
+                    let _ = socket.close().await;
+                    return;
+                }
+                Some(Ok(_)) => {}
+                Some(Err(_)) | None => return,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::tests::{test_summary, test_validator};
+    use axum::{routing::get, Router};
+    use sui_types::digests::{ChainIdentifier, CheckpointDigest};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    #[tokio::test]
+    async fn initial_snapshot_frame() {
+        let info = SystemInfo::new(
+            &test_summary(vec![test_validator(1)]),
+            ChainIdentifier::from(CheckpointDigest::new([0xab; 32])),
+        );
+
+        let snapshot = info.clone();
+        let app = Router::new().route(
+            GET_SYSTEM_WS_PATH,
+            get(|ws: WebSocketUpgrade| async move {
+                ws.on_upgrade(move |socket| {
+                    push_system_info(
+                        socket,
+                        move || Ok(snapshot.clone()),
+                        Duration::from_secs(60),
+                    )
+                })
+            }),
+        );
+        let server = axum::Server::bind(&([127, 0, 0, 1], 0).into()).serve(app.into_make_service());
+        let address = server.local_addr();
+        tokio::spawn(server);
+
+        let mut stream = tokio::net::TcpStream::connect(address).await.unwrap();
+        stream
+            .write_all(
+                b"GET /system/ws HTTP/1.1\r\n\
+                Host: localhost\r\n\
+                Connection: Upgrade\r\n\
+                Upgrade: websocket\r\n\
+                Sec-WebSocket-Version: 13\r\n\
+                Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\n\r\n",
+            )
+            .await
+            .unwrap();
+
+        // Read the handshake response a byte at a time to leave the first frame unread
+        let mut handshake = Vec::new();
+        while !handshake.ends_with(b"\r\n\r\n") {
+            handshake.push(stream.read_u8().await.unwrap());
+        }
+        let handshake = String::from_utf8(handshake).unwrap().to_ascii_lowercase();
+        assert!(handshake.starts_with("http/1.1 101"), "{handshake}");
+        assert!(handshake.contains("sec-websocket-accept: s3pplmbitxaq9kyggzzhzrbk+xoo="));
+
+        // A final, unmasked text frame
+        assert_eq!(stream.read_u8().await.unwrap(), 0x81);
+        let len = match stream.read_u8().await.unwrap() {
+            126 => stream.read_u16().await.unwrap() as usize,
+            len => len as usize,
+        };
+        let mut payload = vec![0; len];
+        stream.read_exact(&mut payload).await.unwrap();
+
+        let received: SystemInfo = serde_json::from_slice(&payload).unwrap();
+        assert_eq!(received, info);
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use axum::{
+    extract::State,
+    http::{HeaderMap, Request, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::{types::X_REQUEST_TIMEOUT_MS, RestError};
+
+/// Per-request timeout, which clients can tighten or relax for each call with the
+/// `x-request-timeout-ms` header.
+#[derive(Clone, Debug)]
+pub struct RequestTimeoutConfig {
+    /// Timeout of requests which don't set a deadline of their own.
+    pub default: Duration,
+    /// Longest deadline a client can set, longer ones are shortened to this.
+    pub max: Duration,
+}
+
+impl Default for RequestTimeoutConfig {
+    fn default() -> Self {
+        Self {
+            default: Duration::from_secs(30),
+            max: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RequestTimeoutConfig {
+    /// The timeout of a request with `headers`, or an error if its deadline is malformed.
+    fn timeout(&self, headers: &HeaderMap) -> Result<Duration, RestError> {
+        let Some(value) = headers.get(X_REQUEST_TIMEOUT_MS) else {
+            return Ok(self.default);
+        };
+
+        let millis = value
+            .to_str()
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .ok_or_else(|| {
+                RestError::new(
+                    StatusCode::BAD_REQUEST,
+                    format!("{X_REQUEST_TIMEOUT_MS} must be a number of milliseconds"),
+                )
+            })?;
+
+        Ok(Duration::from_millis(millis).min(self.max))
+    }
+}
+
+/// Respond with `504 Gateway Timeout` to requests which aren't handled within their timeout.
+///
+/// The deadline can only fire while the handler is waiting, so handlers reading through
+/// [`StateReader`](crate::reader::StateReader) make their synchronous reads on the blocking thread
+/// pool with [`spawn_read`](crate::reader::StateReader::spawn_read).
+pub(crate) async fn request_timeout<B>(
+    State(config): State<RequestTimeoutConfig>,
+    request: Request<B>,
+    next: Next<B>,
+) -> Response {
+    let timeout = match config.timeout(request.headers()) {
+        Ok(timeout) => timeout,
+        Err(e) => return e.into_response(),
+    };
+
+    match tokio::time::timeout(timeout, next.run(request)).await {
+        Ok(response) => response,
+        Err(_) => RestError::new(
+            StatusCode::GATEWAY_TIMEOUT,
+            format!("request timed out after {}ms", timeout.as_millis()),
+        )
+        .into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::reader::tests::TestReader;
+
+    async fn status(timeout_ms: Option<&str>) -> StatusCode {
+        // Each read of the state blocks its thread for 50ms, like a slow database would
+        let reader = TestReader::new().slow(Duration::from_millis(50));
+        let mut service = crate::RestService::new_without_version(std::sync::Arc::new(reader));
+        service.with_request_timeout(RequestTimeoutConfig {
+            default: Duration::from_millis(20),
+            max: Duration::from_secs(10),
+        });
+        let app = service.into_router();
+
+        let mut request = Request::builder().uri("/system");
+        if let Some(timeout_ms) = timeout_ms {
+            request = request.header(X_REQUEST_TIMEOUT_MS, timeout_ms);
+        }
+        let request = request.body(Body::empty()).unwrap();
+
+        app.oneshot(request).await.unwrap().status()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn deadline_from_header() {
+        // A tight deadline times out on the slow reader
+        assert_eq!(status(Some("10")).await, StatusCode::GATEWAY_TIMEOUT);
+        // As does the default
+        assert_eq!(status(None).await, StatusCode::GATEWAY_TIMEOUT);
+        // But a relaxed one gives it time to respond
+        assert_eq!(status(Some("5000")).await, StatusCode::OK);
+        // As does one beyond the server max, which is shortened to the max
+        assert_eq!(status(Some("3600000")).await, StatusCode::OK);
+
+        assert_eq!(status(Some("soon")).await, StatusCode::BAD_REQUEST);
+
+        let config = RequestTimeoutConfig::default();
+        let mut headers = HeaderMap::new();
+        headers.insert(X_REQUEST_TIMEOUT_MS, "3600000".parse().unwrap());
+        assert_eq!(config.timeout(&headers).unwrap(), config.max);
+    }
+}
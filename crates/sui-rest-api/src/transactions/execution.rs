@@ -14,7 +14,7 @@ use tap::Pipe;
 
 use crate::response::Bcs;
 use crate::Result;
-use crate::{accept::AcceptFormat, response::ResponseContent};
+use crate::{accept::AcceptJsonOrBcs, response::ResponseContent};
 
 pub const POST_EXECUTE_TRANSACTION_PATH: &str = "/transactions";
 
@@ -45,7 +45,7 @@ pub async fn execute_transaction(
     State(state): State<Arc<dyn TransactionExecutor>>,
     Query(parameters): Query<ExecuteTransactionQueryParameters>,
     client_address: Option<axum::extract::ConnectInfo<SocketAddr>>,
-    accept: AcceptFormat,
+    accept: AcceptJsonOrBcs,
     Bcs(transaction): Bcs<SignedTransaction>,
 ) -> Result<ResponseContent<TransactionExecutionResponse>> {
     let request = sui_types::quorum_driver_types::ExecuteTransactionRequestV3 {
@@ -128,8 +128,8 @@ pub async fn execute_transaction(
     };
 
     match accept {
-        AcceptFormat::Json => ResponseContent::Json(response),
-        AcceptFormat::Bcs => ResponseContent::Bcs(response),
+        AcceptJsonOrBcs::Json => ResponseContent::Json(response),
+        AcceptJsonOrBcs::Bcs => ResponseContent::Bcs(response),
     }
     .pipe(Ok)
 }
@@ -34,3 +34,18 @@ pub const X_SUI_CURSOR: &str = "x-sui-cursor";
 
 /// Current timestamp of the chain - represented as number of milliseconds from the Unix epoch
 pub const X_SUI_TIMESTAMP_MS: &str = "x-sui-timestamp-ms";
+
+/// Identifier of a request, used to correlate logs across services. Echoed back if provided by
+/// the client, otherwise generated.
+pub const X_REQUEST_ID: &str = "x-request-id";
+
+/// Set to `true` when the response is serving stale system state, e.g. from a previous epoch.
+pub const X_EPOCH_STALE: &str = "x-epoch-stale";
+
+/// Version of the inner system state object, determining the Move type its contents decode as,
+/// e.g. `2` for `SuiSystemStateInnerV2`.
+pub const X_SUI_SYSTEM_STATE_VERSION: &str = "x-sui-system-state-version";
+
+/// Deadline of a request in milliseconds, overriding the default per-request timeout of the
+/// service up to its maximum.
+pub const X_REQUEST_TIMEOUT_MS: &str = "x-request-timeout-ms";
@@ -9,7 +9,7 @@ use move_core_types::{language_storage::ModuleId, resolver::ModuleResolver};
 use simulacrum::Simulacrum;
 use std::num::NonZeroUsize;
 use sui_config::genesis;
-use sui_protocol_config::ProtocolVersion;
+use sui_protocol_config::{Chain, ProtocolConfig, ProtocolVersion};
 use sui_swarm_config::genesis_config::AccountConfig;
 use sui_swarm_config::network_config_builder::ConfigBuilder;
 use sui_types::storage::{ReadStore, RestStateReader};
@@ -291,6 +291,16 @@ impl SimulatorStore for PersistedStore {
             ))
     }
 
+    fn dynamic_fields(&self, parent: ObjectID) -> Box<dyn Iterator<Item = Object> + '_> {
+        Box::new(
+            self.read_write
+                .live_objects
+                .unbounded_iter()
+                .flat_map(|(id, version)| self.get_object_at_version(&id, version))
+                .filter(move |object| simulacrum::store::is_dynamic_field_of(object, parent)),
+        )
+    }
+
     fn insert_checkpoint(&mut self, checkpoint: VerifiedCheckpoint) {
         self.read_write
             .checkpoint_digest_to_sequence_number
@@ -729,6 +739,48 @@ impl RestStateReader for PersistedStoreInnerReadOnlyWrapper {
             .digest())
         .into())
     }
+
+    fn get_dynamic_fields(
+        &self,
+        parent: ObjectID,
+        cursor: Option<ObjectID>,
+        limit: usize,
+    ) -> sui_types::storage::error::Result<
+        Vec<(ObjectID, sui_types::dynamic_field::DynamicFieldInfo)>,
+    > {
+        use sui_types::sui_system_state::SuiSystemStateTrait;
+
+        self.sync();
+
+        // Dynamic fields are described using the Move layouts of the current protocol version,
+        // like the simulator itself does
+        let protocol_version = sui_types::sui_system_state::get_sui_system_state(self)
+            .map_err(sui_types::storage::error::Error::custom)?
+            .protocol_version();
+        let protocol_config =
+            ProtocolConfig::get_for_version(ProtocolVersion::new(protocol_version), Chain::Unknown);
+        let executor = sui_execution::executor(&protocol_config, true, None)
+            .map_err(sui_types::storage::error::Error::custom)?;
+        let mut resolver = executor.type_layout_resolver(Box::new(self));
+
+        let fields = self
+            .inner
+            .live_objects
+            .unbounded_iter()
+            .flat_map(|(id, version)| self.get_object_by_key(&id, version).ok().flatten())
+            .filter(|object| simulacrum::store::is_dynamic_field_of(object, parent));
+
+        simulacrum::dynamic_fields_page(self, fields, cursor, limit, resolver.as_mut())
+    }
+}
+
+impl BackingPackageStore for PersistedStoreInnerReadOnlyWrapper {
+    fn get_package_object(
+        &self,
+        package_id: &ObjectID,
+    ) -> sui_types::error::SuiResult<Option<PackageObject>> {
+        load_package_object_from_object_store(self, package_id)
+    }
 }
 
 impl PersistedStoreInnerReadOnlyWrapper {
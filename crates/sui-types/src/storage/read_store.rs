@@ -3,12 +3,13 @@
 
 use super::error::Result;
 use super::ObjectStore;
-use crate::base_types::EpochId;
+use crate::base_types::{EpochId, ObjectID};
 use crate::committee::Committee;
 use crate::digests::{
     ChainIdentifier, CheckpointContentsDigest, CheckpointDigest, TransactionDigest,
     TransactionEventsDigest,
 };
+use crate::dynamic_field::DynamicFieldInfo;
 use crate::effects::{TransactionEffects, TransactionEvents};
 use crate::full_checkpoint_content::CheckpointData;
 use crate::messages_checkpoint::{
@@ -654,4 +655,14 @@ pub trait RestStateReader: ObjectStore + ReadStore + Send + Sync {
     fn get_lowest_available_checkpoint_objects(&self) -> Result<CheckpointSequenceNumber>;
 
     fn get_chain_identifier(&self) -> Result<ChainIdentifier>;
+
+    /// Page through the dynamic fields owned by `parent`, ordered by field object id.
+    ///
+    /// If `cursor` is provided the page starts with the field immediately after it.
+    fn get_dynamic_fields(
+        &self,
+        parent: ObjectID,
+        cursor: Option<ObjectID>,
+        limit: usize,
+    ) -> Result<Vec<(ObjectID, DynamicFieldInfo)>>;
 }